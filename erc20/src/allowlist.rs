@@ -0,0 +1,27 @@
+//! Implementation of the allowlist.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::URef;
+
+use crate::{balances, constants::ALLOWLIST_KEY_NAME, detail, Address};
+
+/// Returns the allowlist dictionary, or `None` if the token was installed without allowlist mode.
+#[inline]
+pub(crate) fn allowlist_uref() -> Option<URef> {
+    detail::get_optional_uref(ALLOWLIST_KEY_NAME)
+}
+
+/// Writes allowlist membership of a specified address into a dictionary.
+pub(crate) fn write_allowlisted_to(allowlist_uref: URef, address: Address, allowlisted: bool) {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    storage::dictionary_put(allowlist_uref, &dictionary_item_key, allowlisted);
+}
+
+/// Reads allowlist membership of a specified address.
+///
+/// If a given address was never allowlisted, then `false` is returned.
+pub(crate) fn read_allowlisted_from(allowlist_uref: URef, address: Address) -> bool {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    storage::dictionary_get(allowlist_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
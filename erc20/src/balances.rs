@@ -4,11 +4,11 @@ use alloc::string::String;
 use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{bytesrepr::ToBytes, URef, U256};
 
-use crate::{constants::BALANCES_KEY_NAME, detail, error::Error, Address};
+use crate::{constants::BALANCES_KEY_NAME, detail, error::Error, holders::Holders, Address};
 
 /// Creates a dictionary item key for a dictionary item.
 #[inline]
-fn make_dictionary_item_key(owner: Address) -> String {
+pub(crate) fn make_dictionary_item_key(owner: Address) -> String {
     let preimage = owner.to_bytes().unwrap_or_revert();
     // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
     // hashing (which will effectively hash a hash) we'll use base64. Preimage is about 33 bytes for
@@ -24,9 +24,20 @@ pub(crate) fn get_balances_uref() -> URef {
 }
 
 /// Writes token balance of a specified account into a dictionary.
-pub(crate) fn write_balance_to(balances_uref: URef, address: Address, amount: U256) {
+///
+/// If holder tracking is enabled, the account is also added to or removed from the holder list
+/// depending on whether it still holds any tokens.
+pub(crate) fn write_balance_to(
+    balances_uref: URef,
+    holders: Option<Holders>,
+    address: Address,
+    amount: U256,
+) {
     let dictionary_item_key = make_dictionary_item_key(address);
     storage::dictionary_put(balances_uref, &dictionary_item_key, amount);
+    if let Some(holders) = holders {
+        holders.update(address, !amount.is_zero());
+    }
 }
 
 /// Reads token balance of a specified account.
@@ -46,6 +57,7 @@ pub(crate) fn read_balance_from(balances_uref: URef, address: Address) -> U256 {
 /// sender.
 pub(crate) fn transfer_balance(
     balances_uref: URef,
+    holders: Option<Holders>,
     sender: Address,
     recipient: Address,
     amount: U256,
@@ -68,8 +80,8 @@ pub(crate) fn transfer_balance(
             .ok_or(Error::Overflow)?
     };
 
-    write_balance_to(balances_uref, sender, new_sender_balance);
-    write_balance_to(balances_uref, recipient, new_recipient_balance);
+    write_balance_to(balances_uref, holders, sender, new_sender_balance);
+    write_balance_to(balances_uref, holders, recipient, new_recipient_balance);
 
     Ok(())
 }
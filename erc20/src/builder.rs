@@ -0,0 +1,540 @@
+//! Builder used to install an ERC20 contract with optional features enabled.
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{contracts::NamedKeys, EntryPoints, Key, U256};
+use once_cell::unsync::OnceCell;
+
+use crate::{
+    allowlist, balances, burn,
+    constants::{
+        ADMIN_ROLE, ALLOWANCES_KEY_NAME, ALLOWLIST_KEY_NAME, BALANCES_KEY_NAME,
+        BURN_BASIS_POINTS_KEY_NAME, BURN_EXEMPT_KEY_NAME, DECIMALS_KEY_NAME,
+        EMISSION_SCHEDULE_KEY_NAME, ERC20_TOKEN_CONTRACT_KEY_NAME, FEE_EXEMPT_KEY_NAME,
+        FORCE_TRANSFERS_KEY_NAME, FORCE_TRANSFER_COUNT_KEY_NAME, FROZEN_KEY_NAME, HOLDERS_KEY_NAME,
+        HOLDER_COUNT_KEY_NAME, HOLDER_POSITIONS_KEY_NAME, LAST_EMISSION_KEY_NAME,
+        MAX_BURN_BASIS_POINTS_KEY_NAME, MAX_SUPPLY_KEY_NAME, NAME_KEY_NAME, ROLES_KEY_NAME,
+        SYMBOL_KEY_NAME, TOTAL_BURNED_KEY_NAME, TOTAL_SHARES_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
+        TRANSFER_FEE_KEY_NAME,
+    },
+    detail, entry_points, fees, holders::Holders, metadata, roles, Address, EmissionSchedule, Error,
+    TransferFee, ERC20,
+};
+
+/// Names of the keys the token keeps for itself, including those of optional features, which are
+/// enabled by the mere presence of their keys.
+const RESERVED_KEY_NAMES: &[&str] = &[
+    NAME_KEY_NAME,
+    SYMBOL_KEY_NAME,
+    DECIMALS_KEY_NAME,
+    ERC20_TOKEN_CONTRACT_KEY_NAME,
+    BALANCES_KEY_NAME,
+    ALLOWANCES_KEY_NAME,
+    TOTAL_SUPPLY_KEY_NAME,
+    ROLES_KEY_NAME,
+    FROZEN_KEY_NAME,
+    ALLOWLIST_KEY_NAME,
+    FORCE_TRANSFERS_KEY_NAME,
+    FORCE_TRANSFER_COUNT_KEY_NAME,
+    TRANSFER_FEE_KEY_NAME,
+    FEE_EXEMPT_KEY_NAME,
+    HOLDERS_KEY_NAME,
+    HOLDER_POSITIONS_KEY_NAME,
+    HOLDER_COUNT_KEY_NAME,
+    MAX_SUPPLY_KEY_NAME,
+    EMISSION_SCHEDULE_KEY_NAME,
+    LAST_EMISSION_KEY_NAME,
+    BURN_BASIS_POINTS_KEY_NAME,
+    MAX_BURN_BASIS_POINTS_KEY_NAME,
+    BURN_EXEMPT_KEY_NAME,
+    TOTAL_BURNED_KEY_NAME,
+    TOTAL_SHARES_KEY_NAME,
+];
+
+/// Configures and installs an [`ERC20`] contract.
+///
+/// Created through [`ERC20::builder`].
+pub struct ERC20Builder {
+    name: String,
+    symbol: String,
+    decimals: u8,
+    initial_supply: U256,
+    contract_key_name: String,
+    entry_points: EntryPoints,
+    allowlist: Option<Vec<Address>>,
+    transfer_fee: Option<(TransferFee, Vec<Address>)>,
+    holder_tracking: bool,
+    initial_owner: Option<Address>,
+    genesis_distribution: Option<Vec<(Address, U256)>>,
+    metadata_validation: bool,
+    max_supply: Option<U256>,
+    emission_schedule: Option<EmissionSchedule>,
+    transfer_burn: Option<(u32, u32, Vec<Address>)>,
+    rebasing: bool,
+    extra_named_keys: NamedKeys,
+}
+
+impl ERC20Builder {
+    pub(crate) fn new(name: String, symbol: String, decimals: u8, initial_supply: U256) -> Self {
+        Self {
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+            contract_key_name: ERC20_TOKEN_CONTRACT_KEY_NAME.to_string(),
+            entry_points: entry_points::default(),
+            allowlist: None,
+            transfer_fee: None,
+            holder_tracking: false,
+            initial_owner: None,
+            genesis_distribution: None,
+            metadata_validation: true,
+            max_supply: None,
+            emission_schedule: None,
+            transfer_burn: None,
+            rebasing: false,
+            extra_named_keys: NamedKeys::new(),
+        }
+    }
+
+    /// Sets the name of the named key under which the contract hash is stored.
+    ///
+    /// Defaults to [`ERC20_TOKEN_CONTRACT_KEY_NAME`].
+    pub fn with_contract_key_name(mut self, contract_key_name: &str) -> Self {
+        self.contract_key_name = contract_key_name.to_string();
+        self
+    }
+
+    /// Sets the entry points of the installed contract.
+    ///
+    /// Defaults to [`entry_points::default`].
+    ///
+    /// # Warning
+    ///
+    /// Entry points of optional features should be added on top of the default set of ERC20 entry
+    /// points. Leaving any of them out might lead to problems with integrators such as wallets,
+    /// and exchanges.
+    pub fn with_entry_points(mut self, entry_points: EntryPoints) -> Self {
+        self.entry_points = entry_points;
+        self
+    }
+
+    /// Adds named keys to the installed contract, for contracts which keep their own state next to
+    /// the token's.
+    ///
+    /// Keys named like any of the token's own keys, as listed in [`constants`](crate::constants),
+    /// make [`ERC20Builder::install`] fail with [`Error::ReservedKeyName`], since some of them would
+    /// otherwise enable optional features which were not asked for.
+    pub fn with_named_keys(mut self, named_keys: NamedKeys) -> Self {
+        self.extra_named_keys.extend(named_keys);
+        self
+    }
+
+    /// Sets the address which receives the initial supply and becomes the first admin.
+    ///
+    /// Defaults to whoever calls [`ERC20Builder::install`], i.e. the installing account or
+    /// contract.
+    pub fn with_initial_owner(mut self, initial_owner: Address) -> Self {
+        self.initial_owner = Some(initial_owner);
+        self
+    }
+
+    /// Splits the initial supply between the given addresses instead of crediting all of it to the
+    /// initial owner, who still becomes the first admin.
+    ///
+    /// The amounts must add up to the initial supply, otherwise [`ERC20Builder::install`] fails
+    /// with [`Error::InvalidDistribution`]. An address listed more than once receives the sum of
+    /// its amounts.
+    pub fn with_genesis_distribution(mut self, distribution: Vec<(Address, U256)>) -> Self {
+        self.genesis_distribution = Some(distribution);
+        self
+    }
+
+    /// Skips validation of the name, symbol and decimals, for tokens which really need values
+    /// outside of the ranges accepted by default.
+    ///
+    /// By default [`ERC20Builder::install`] fails with [`Error::InvalidName`],
+    /// [`Error::InvalidSymbol`] or [`Error::InvalidDecimals`] unless the name is at most
+    /// [`MAX_NAME_LENGTH`](crate::constants::MAX_NAME_LENGTH) printable ASCII characters, the
+    /// symbol at most [`MAX_SYMBOL_LENGTH`](crate::constants::MAX_SYMBOL_LENGTH) ASCII letters and
+    /// digits, and the decimals at most [`MAX_DECIMALS`](crate::constants::MAX_DECIMALS).
+    pub fn without_metadata_validation(mut self) -> Self {
+        self.metadata_validation = false;
+        self
+    }
+
+    /// Enables allowlist mode, in which tokens can only be held by allowlisted addresses.
+    ///
+    /// The initial owner and the genesis distribution recipients are always allowlisted, together
+    /// with the given `addresses`.
+    pub fn with_allowlist(mut self, addresses: Vec<Address>) -> Self {
+        self.allowlist = Some(addresses);
+        self
+    }
+
+    /// Enables fees, charged on every transfer unless the sender or the recipient is one of the
+    /// `exempt` addresses.
+    pub fn with_transfer_fee(mut self, transfer_fee: TransferFee, exempt: Vec<Address>) -> Self {
+        self.transfer_fee = Some((transfer_fee, exempt));
+        self
+    }
+
+    /// Enables burn-on-transfer, which burns `basis_points` of every transfer unless the sender or
+    /// the recipient is one of the `exempt` addresses.
+    ///
+    /// Admins can change the rate later, but never above `max_basis_points`. Burned tokens are
+    /// counted by [`ERC20::total_burned`].
+    pub fn with_transfer_burn(
+        mut self,
+        basis_points: u32,
+        max_basis_points: u32,
+        exempt: Vec<Address>,
+    ) -> Self {
+        self.transfer_burn = Some((basis_points, max_basis_points, exempt));
+        self
+    }
+
+    /// Enables holder tracking, which keeps a list of addresses holding a non-zero balance that
+    /// can be enumerated through [`ERC20::holder_count`] and [`ERC20::holder_at`].
+    ///
+    /// Every balance update which adds or removes a holder costs a few extra dictionary writes.
+    pub fn with_holder_tracking(mut self) -> Self {
+        self.holder_tracking = true;
+        self
+    }
+
+    /// Caps the total supply, so that minting above `max_supply` fails with
+    /// [`Error::SupplyCapExceeded`].
+    pub fn with_max_supply(mut self, max_supply: U256) -> Self {
+        self.max_supply = Some(max_supply);
+        self
+    }
+
+    /// Enables emission of new tokens according to `emission_schedule`, minted whenever anyone
+    /// calls [`ERC20::emit`].
+    ///
+    /// Tokens accrue from the start of the first period of the schedule.
+    pub fn with_emission_schedule(mut self, emission_schedule: EmissionSchedule) -> Self {
+        self.emission_schedule = Some(emission_schedule);
+        self
+    }
+
+    /// Enables rebasing mode, in which balances are stored as shares of the total supply, so that
+    /// admins can change every balance at once through [`ERC20::rebase`].
+    ///
+    /// The initial supply is issued one share per token.
+    pub fn with_rebasing(mut self) -> Self {
+        self.rebasing = true;
+        self
+    }
+
+    /// Installs the ERC20 contract.
+    ///
+    /// This should be called from within `fn call()` of your contract.
+    pub fn install(self) -> Result<ERC20, Error> {
+        if self.metadata_validation {
+            metadata::validate_name(&self.name)?;
+            metadata::validate_symbol(&self.symbol)?;
+            metadata::validate_decimals(self.decimals)?;
+        }
+
+        if self
+            .extra_named_keys
+            .keys()
+            .any(|name| RESERVED_KEY_NAMES.contains(&name.as_str()))
+        {
+            return Err(Error::ReservedKeyName);
+        }
+
+        if let Some((transfer_fee, _exempt)) = &self.transfer_fee {
+            transfer_fee.validate()?;
+        }
+
+        if let Some((basis_points, max_basis_points, _exempt)) = &self.transfer_burn {
+            burn::validate_burn_basis_points(*basis_points, *max_basis_points)?;
+        }
+
+        if let Some(emission_schedule) = &self.emission_schedule {
+            emission_schedule.validate()?;
+        }
+
+        if let Some(max_supply) = self.max_supply {
+            if self.initial_supply > max_supply {
+                return Err(Error::SupplyCapExceeded);
+            }
+        }
+
+        if let Some(distribution) = &self.genesis_distribution {
+            let mut distributed = U256::zero();
+            for (_address, amount) in distribution {
+                distributed = distributed.checked_add(*amount).ok_or(Error::Overflow)?;
+            }
+            if distributed != self.initial_supply {
+                return Err(Error::InvalidDistribution);
+            }
+        }
+
+        let balances_uref = storage::new_dictionary(BALANCES_KEY_NAME).unwrap_or_revert();
+        let allowances_uref = storage::new_dictionary(ALLOWANCES_KEY_NAME).unwrap_or_revert();
+        let roles_uref = storage::new_dictionary(ROLES_KEY_NAME).unwrap_or_revert();
+        let frozen_uref = storage::new_dictionary(FROZEN_KEY_NAME).unwrap_or_revert();
+        let force_transfers_uref =
+            storage::new_dictionary(FORCE_TRANSFERS_KEY_NAME).unwrap_or_revert();
+        let force_transfer_count_uref = storage::new_uref(0u64).into_read_write();
+        // We need to hold on a RW access rights because tokens can be minted or burned.
+        let total_supply_uref = storage::new_uref(self.initial_supply).into_read_write();
+
+        let mut named_keys = self.extra_named_keys;
+
+        let name_key = {
+            let name_uref = storage::new_uref(self.name).into_read();
+            Key::from(name_uref)
+        };
+
+        let symbol_key = {
+            let symbol_uref = storage::new_uref(self.symbol).into_read();
+            Key::from(symbol_uref)
+        };
+
+        let decimals_key = {
+            let decimals_uref = storage::new_uref(self.decimals).into_read();
+            Key::from(decimals_uref)
+        };
+
+        let total_supply_key = Key::from(total_supply_uref);
+
+        // Either an account, or a contract.
+        let caller = detail::get_caller_address()?;
+        let initial_owner = self.initial_owner.unwrap_or(caller);
+        let genesis_distribution = match self.genesis_distribution {
+            Some(distribution) => distribution,
+            None => vec![(initial_owner, self.initial_supply)],
+        };
+
+        let holders = if self.holder_tracking {
+            let holders = Holders {
+                holders_uref: storage::new_dictionary(HOLDERS_KEY_NAME).unwrap_or_revert(),
+                holder_positions_uref: storage::new_dictionary(HOLDER_POSITIONS_KEY_NAME)
+                    .unwrap_or_revert(),
+                holder_count_uref: storage::new_uref(0u64).into_read_write(),
+            };
+
+            runtime::remove_key(HOLDERS_KEY_NAME);
+            runtime::remove_key(HOLDER_POSITIONS_KEY_NAME);
+
+            named_keys.insert(HOLDERS_KEY_NAME.to_string(), Key::from(holders.holders_uref));
+            named_keys.insert(
+                HOLDER_POSITIONS_KEY_NAME.to_string(),
+                Key::from(holders.holder_positions_uref),
+            );
+            named_keys.insert(
+                HOLDER_COUNT_KEY_NAME.to_string(),
+                Key::from(holders.holder_count_uref),
+            );
+            Some(holders)
+        } else {
+            None
+        };
+
+        let balances_dictionary_key = {
+            // Sets up initial balances, all of them written before the contract is locked.
+            for (address, amount) in &genesis_distribution {
+                let balance = balances::read_balance_from(balances_uref, *address);
+                balances::write_balance_to(balances_uref, holders, *address, balance + *amount);
+            }
+
+            runtime::remove_key(BALANCES_KEY_NAME);
+
+            Key::from(balances_uref)
+        };
+
+        let allowances_dictionary_key = {
+            runtime::remove_key(ALLOWANCES_KEY_NAME);
+
+            Key::from(allowances_uref)
+        };
+
+        let roles_dictionary_key = {
+            // The initial owner becomes the first admin of the token.
+            roles::write_role_to(roles_uref, ADMIN_ROLE, initial_owner, true);
+
+            runtime::remove_key(ROLES_KEY_NAME);
+
+            Key::from(roles_uref)
+        };
+
+        let frozen_dictionary_key = {
+            runtime::remove_key(FROZEN_KEY_NAME);
+
+            Key::from(frozen_uref)
+        };
+
+        let force_transfers_dictionary_key = {
+            runtime::remove_key(FORCE_TRANSFERS_KEY_NAME);
+
+            Key::from(force_transfers_uref)
+        };
+
+        let force_transfer_count_key = Key::from(force_transfer_count_uref);
+
+        let allowlist_uref = match self.allowlist {
+            Some(addresses) => {
+                let allowlist_uref = storage::new_dictionary(ALLOWLIST_KEY_NAME).unwrap_or_revert();
+
+                allowlist::write_allowlisted_to(allowlist_uref, initial_owner, true);
+                for (address, _amount) in &genesis_distribution {
+                    allowlist::write_allowlisted_to(allowlist_uref, *address, true);
+                }
+                for address in addresses {
+                    allowlist::write_allowlisted_to(allowlist_uref, address, true);
+                }
+
+                runtime::remove_key(ALLOWLIST_KEY_NAME);
+
+                named_keys.insert(ALLOWLIST_KEY_NAME.to_string(), Key::from(allowlist_uref));
+                Some(allowlist_uref)
+            }
+            None => None,
+        };
+
+        let (transfer_fee_uref, fee_exempt_uref) = match self.transfer_fee {
+            Some((transfer_fee, exempt)) => {
+                // Fees can be reconfigured by admins.
+                let transfer_fee_uref = storage::new_uref(transfer_fee).into_read_write();
+                let fee_exempt_uref =
+                    storage::new_dictionary(FEE_EXEMPT_KEY_NAME).unwrap_or_revert();
+
+                for address in exempt {
+                    fees::write_fee_exempt_to(fee_exempt_uref, address, true);
+                }
+
+                runtime::remove_key(FEE_EXEMPT_KEY_NAME);
+
+                named_keys.insert(TRANSFER_FEE_KEY_NAME.to_string(), Key::from(transfer_fee_uref));
+                named_keys.insert(FEE_EXEMPT_KEY_NAME.to_string(), Key::from(fee_exempt_uref));
+                (Some(transfer_fee_uref), OnceCell::from(fee_exempt_uref))
+            }
+            None => (None, OnceCell::new()),
+        };
+
+        let mut max_burn_rate_uref = OnceCell::new();
+        let (burn_rate_uref, burn_exempt_uref, total_burned_uref) = match self.transfer_burn {
+            Some((basis_points, max_basis_points, exempt)) => {
+                // The rate can be changed by admins, within the bound fixed here.
+                let burn_basis_points_uref = storage::new_uref(basis_points).into_read_write();
+                let max_burn_basis_points_uref = storage::new_uref(max_basis_points).into_read();
+                max_burn_rate_uref = OnceCell::from(max_burn_basis_points_uref);
+                let burn_exempt_uref =
+                    storage::new_dictionary(BURN_EXEMPT_KEY_NAME).unwrap_or_revert();
+                let total_burned_uref = storage::new_uref(U256::zero()).into_read_write();
+
+                for address in exempt {
+                    burn::write_burn_exempt_to(burn_exempt_uref, address, true);
+                }
+
+                runtime::remove_key(BURN_EXEMPT_KEY_NAME);
+
+                named_keys.insert(
+                    BURN_BASIS_POINTS_KEY_NAME.to_string(),
+                    Key::from(burn_basis_points_uref),
+                );
+                named_keys.insert(
+                    MAX_BURN_BASIS_POINTS_KEY_NAME.to_string(),
+                    Key::from(max_burn_basis_points_uref),
+                );
+                named_keys.insert(BURN_EXEMPT_KEY_NAME.to_string(), Key::from(burn_exempt_uref));
+                named_keys.insert(TOTAL_BURNED_KEY_NAME.to_string(), Key::from(total_burned_uref));
+                (
+                    Some(burn_basis_points_uref),
+                    OnceCell::from(burn_exempt_uref),
+                    Some(total_burned_uref),
+                )
+            }
+            None => (None, OnceCell::new(), None),
+        };
+
+        let max_supply_uref = match self.max_supply {
+            Some(max_supply) => {
+                let max_supply_uref = storage::new_uref(max_supply).into_read();
+                named_keys.insert(MAX_SUPPLY_KEY_NAME.to_string(), Key::from(max_supply_uref));
+                Some(max_supply_uref)
+            }
+            None => None,
+        };
+
+        let (emission_schedule_uref, last_emission_uref) = match self.emission_schedule {
+            Some(emission_schedule) => {
+                // Nothing accrues before the schedule starts.
+                let last_emission_uref =
+                    storage::new_uref(emission_schedule.start()).into_read_write();
+                let emission_schedule_uref = storage::new_uref(emission_schedule).into_read();
+
+                named_keys.insert(
+                    EMISSION_SCHEDULE_KEY_NAME.to_string(),
+                    Key::from(emission_schedule_uref),
+                );
+                named_keys.insert(
+                    LAST_EMISSION_KEY_NAME.to_string(),
+                    Key::from(last_emission_uref),
+                );
+                (Some(emission_schedule_uref), OnceCell::from(last_emission_uref))
+            }
+            None => (None, OnceCell::new()),
+        };
+
+        let total_shares_uref = if self.rebasing {
+            // Shares are issued and destroyed whenever tokens are minted or burned.
+            let total_shares_uref = storage::new_uref(self.initial_supply).into_read_write();
+            named_keys.insert(TOTAL_SHARES_KEY_NAME.to_string(), Key::from(total_shares_uref));
+            Some(total_shares_uref)
+        } else {
+            None
+        };
+
+        named_keys.insert(NAME_KEY_NAME.to_string(), name_key);
+        named_keys.insert(SYMBOL_KEY_NAME.to_string(), symbol_key);
+        named_keys.insert(DECIMALS_KEY_NAME.to_string(), decimals_key);
+        named_keys.insert(BALANCES_KEY_NAME.to_string(), balances_dictionary_key);
+        named_keys.insert(ALLOWANCES_KEY_NAME.to_string(), allowances_dictionary_key);
+        named_keys.insert(TOTAL_SUPPLY_KEY_NAME.to_string(), total_supply_key);
+        named_keys.insert(ROLES_KEY_NAME.to_string(), roles_dictionary_key);
+        named_keys.insert(FROZEN_KEY_NAME.to_string(), frozen_dictionary_key);
+        named_keys.insert(FORCE_TRANSFERS_KEY_NAME.to_string(), force_transfers_dictionary_key);
+        named_keys.insert(FORCE_TRANSFER_COUNT_KEY_NAME.to_string(), force_transfer_count_key);
+
+        let (contract_hash, _version) =
+            storage::new_locked_contract(self.entry_points, Some(named_keys), None, None);
+
+        // Hash of the installed contract will be reachable through named keys.
+        runtime::put_key(&self.contract_key_name, Key::from(contract_hash));
+
+        Ok(ERC20 {
+            balances_uref: balances_uref.into(),
+            allowances_uref: allowances_uref.into(),
+            total_supply_uref: total_supply_uref.into(),
+            roles_uref: roles_uref.into(),
+            frozen_uref: frozen_uref.into(),
+            allowlist_uref: allowlist_uref.into(),
+            force_transfers_uref: force_transfers_uref.into(),
+            force_transfer_count_uref: force_transfer_count_uref.into(),
+            transfer_fee_uref: transfer_fee_uref.into(),
+            fee_exempt_uref,
+            holders: holders.into(),
+            max_supply_uref: max_supply_uref.into(),
+            emission_schedule_uref: emission_schedule_uref.into(),
+            last_emission_uref,
+            burn_basis_points_uref: burn_rate_uref.into(),
+            max_burn_basis_points_uref: max_burn_rate_uref,
+            burn_exempt_uref,
+            total_burned_uref: total_burned_uref.into(),
+            total_shares_uref: total_shares_uref.into(),
+        })
+    }
+}
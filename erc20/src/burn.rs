@@ -0,0 +1,76 @@
+//! Implementation of burn-on-transfer.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{URef, U256};
+
+use crate::{
+    balances,
+    constants::{
+        BURN_BASIS_POINTS_KEY_NAME, BURN_EXEMPT_KEY_NAME, MAX_BASIS_POINTS,
+        MAX_BURN_BASIS_POINTS_KEY_NAME,
+    },
+    detail,
+    error::Error,
+    Address,
+};
+
+/// Returns the burn rate [`URef`], or `None` if the token was installed without burn-on-transfer.
+#[inline]
+pub(crate) fn burn_basis_points_uref() -> Option<URef> {
+    detail::get_optional_uref(BURN_BASIS_POINTS_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn max_burn_basis_points_uref() -> URef {
+    detail::get_uref(MAX_BURN_BASIS_POINTS_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn burn_exempt_uref() -> URef {
+    detail::get_uref(BURN_EXEMPT_KEY_NAME)
+}
+
+/// Validates a burn rate against its upper bound, which itself can not exceed the whole amount.
+pub(crate) fn validate_burn_basis_points(
+    basis_points: u32,
+    max_basis_points: u32,
+) -> Result<(), Error> {
+    if basis_points > max_basis_points || max_basis_points > MAX_BASIS_POINTS {
+        return Err(Error::InvalidBurnRate);
+    }
+    Ok(())
+}
+
+/// Calculates the part of `amount` burned at a rate of `basis_points`.
+pub(crate) fn calculate_burn(basis_points: u32, amount: U256) -> Result<U256, Error> {
+    let burned = amount
+        .checked_mul(U256::from(basis_points))
+        .ok_or(Error::Overflow)?
+        / U256::from(MAX_BASIS_POINTS);
+    Ok(burned)
+}
+
+/// Reads a burn rate from a specified [`URef`].
+pub(crate) fn read_basis_points_from(uref: URef) -> u32 {
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Writes a burn rate to a specified [`URef`].
+pub(crate) fn write_basis_points_to(uref: URef, basis_points: u32) {
+    storage::write(uref, basis_points);
+}
+
+/// Writes burn exemption of a specified address into a dictionary.
+pub(crate) fn write_burn_exempt_to(burn_exempt_uref: URef, address: Address, exempt: bool) {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    storage::dictionary_put(burn_exempt_uref, &dictionary_item_key, exempt);
+}
+
+/// Reads burn exemption of a specified address.
+///
+/// If a given address was never exempted, then `false` is returned.
+pub(crate) fn read_burn_exempt_from(burn_exempt_uref: URef, address: Address) -> bool {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    storage::dictionary_get(burn_exempt_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
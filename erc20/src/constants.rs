@@ -14,6 +14,56 @@ pub const BALANCES_KEY_NAME: &str = "balances";
 pub const ALLOWANCES_KEY_NAME: &str = "allowances";
 /// Name of named-key for `total_supply`
 pub const TOTAL_SUPPLY_KEY_NAME: &str = "total_supply";
+/// Name of dictionary-key for `roles`
+pub const ROLES_KEY_NAME: &str = "roles";
+/// Name of dictionary-key for `frozen`
+pub const FROZEN_KEY_NAME: &str = "frozen";
+/// Name of dictionary-key for `allowlist`
+pub const ALLOWLIST_KEY_NAME: &str = "allowlist";
+/// Name of dictionary-key for `force_transfers`
+pub const FORCE_TRANSFERS_KEY_NAME: &str = "force_transfers";
+/// Name of named-key for `force_transfer_count`
+pub const FORCE_TRANSFER_COUNT_KEY_NAME: &str = "force_transfer_count";
+/// Name of named-key for `transfer_fee`
+pub const TRANSFER_FEE_KEY_NAME: &str = "transfer_fee";
+/// Name of dictionary-key for `fee_exempt`
+pub const FEE_EXEMPT_KEY_NAME: &str = "fee_exempt";
+/// Name of dictionary-key for `holders`
+pub const HOLDERS_KEY_NAME: &str = "holders";
+/// Name of dictionary-key for `holder_positions`
+pub const HOLDER_POSITIONS_KEY_NAME: &str = "holder_positions";
+/// Name of named-key for `holder_count`
+pub const HOLDER_COUNT_KEY_NAME: &str = "holder_count";
+/// Name of named-key for `max_supply`
+pub const MAX_SUPPLY_KEY_NAME: &str = "max_supply";
+/// Name of named-key for `emission_schedule`
+pub const EMISSION_SCHEDULE_KEY_NAME: &str = "emission_schedule";
+/// Name of named-key for `last_emission`
+pub const LAST_EMISSION_KEY_NAME: &str = "last_emission";
+/// Name of named-key for `burn_basis_points`
+pub const BURN_BASIS_POINTS_KEY_NAME: &str = "burn_basis_points";
+/// Name of named-key for `max_burn_basis_points`
+pub const MAX_BURN_BASIS_POINTS_KEY_NAME: &str = "max_burn_basis_points";
+/// Name of dictionary-key for `burn_exempt`
+pub const BURN_EXEMPT_KEY_NAME: &str = "burn_exempt";
+/// Name of named-key for `total_burned`
+pub const TOTAL_BURNED_KEY_NAME: &str = "total_burned";
+/// Name of named-key for `total_shares`
+pub const TOTAL_SHARES_KEY_NAME: &str = "total_shares";
+
+/// Number of basis points which make up the whole amount.
+pub const MAX_BASIS_POINTS: u32 = 10_000;
+/// Maximum length of the token name accepted at install, in bytes.
+pub const MAX_NAME_LENGTH: usize = 64;
+/// Maximum length of the token symbol accepted at install, in bytes.
+pub const MAX_SYMBOL_LENGTH: usize = 11;
+/// Maximum number of decimals accepted at install.
+pub const MAX_DECIMALS: u8 = 18;
+
+/// Name of the role allowed to manage roles and frozen accounts.
+pub const ADMIN_ROLE: &str = "admin";
+/// Name of the role allowed to transfer tokens without the owner's approval.
+pub const FORCE_TRANSFER_ROLE: &str = "force_transfer";
 
 /// Name of `name` entry point.
 pub const NAME_ENTRY_POINT_NAME: &str = "name";
@@ -25,6 +75,8 @@ pub const DECIMALS_ENTRY_POINT_NAME: &str = "decimals";
 pub const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
 /// Name of `transfer` entry point.
 pub const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
+/// Name of `mint` entry point.
+pub const MINT_ENTRY_POINT_NAME: &str = "mint";
 /// Name of `approve` entry point.
 pub const APPROVE_ENTRY_POINT_NAME: &str = "approve";
 /// Name of `allowance` entry point.
@@ -33,6 +85,62 @@ pub const ALLOWANCE_ENTRY_POINT_NAME: &str = "allowance";
 pub const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
 /// Name of `total_supply` entry point.
 pub const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
+/// Name of `has_role` entry point.
+pub const HAS_ROLE_ENTRY_POINT_NAME: &str = "has_role";
+/// Name of `grant_role` entry point.
+pub const GRANT_ROLE_ENTRY_POINT_NAME: &str = "grant_role";
+/// Name of `revoke_role` entry point.
+pub const REVOKE_ROLE_ENTRY_POINT_NAME: &str = "revoke_role";
+/// Name of `is_frozen` entry point.
+pub const IS_FROZEN_ENTRY_POINT_NAME: &str = "is_frozen";
+/// Name of `freeze` entry point.
+pub const FREEZE_ENTRY_POINT_NAME: &str = "freeze";
+/// Name of `unfreeze` entry point.
+pub const UNFREEZE_ENTRY_POINT_NAME: &str = "unfreeze";
+/// Name of `wipe_frozen_balance` entry point.
+pub const WIPE_FROZEN_BALANCE_ENTRY_POINT_NAME: &str = "wipe_frozen_balance";
+/// Name of `is_allowlisted` entry point.
+pub const IS_ALLOWLISTED_ENTRY_POINT_NAME: &str = "is_allowlisted";
+/// Name of `add_to_allowlist` entry point.
+pub const ADD_TO_ALLOWLIST_ENTRY_POINT_NAME: &str = "add_to_allowlist";
+/// Name of `remove_from_allowlist` entry point.
+pub const REMOVE_FROM_ALLOWLIST_ENTRY_POINT_NAME: &str = "remove_from_allowlist";
+/// Name of `force_transfer` entry point.
+pub const FORCE_TRANSFER_ENTRY_POINT_NAME: &str = "force_transfer";
+/// Name of `force_transfer_count` entry point.
+pub const FORCE_TRANSFER_COUNT_ENTRY_POINT_NAME: &str = "force_transfer_count";
+/// Name of `force_transfer_record` entry point.
+pub const FORCE_TRANSFER_RECORD_ENTRY_POINT_NAME: &str = "force_transfer_record";
+/// Name of `calculate_fee` entry point.
+pub const CALCULATE_FEE_ENTRY_POINT_NAME: &str = "calculate_fee";
+/// Name of `set_transfer_fee` entry point.
+pub const SET_TRANSFER_FEE_ENTRY_POINT_NAME: &str = "set_transfer_fee";
+/// Name of `set_fee_exempt` entry point.
+pub const SET_FEE_EXEMPT_ENTRY_POINT_NAME: &str = "set_fee_exempt";
+/// Name of `holder_count` entry point.
+pub const HOLDER_COUNT_ENTRY_POINT_NAME: &str = "holder_count";
+/// Name of `holder_at` entry point.
+pub const HOLDER_AT_ENTRY_POINT_NAME: &str = "holder_at";
+/// Name of `balances_of` entry point.
+pub const BALANCES_OF_ENTRY_POINT_NAME: &str = "balances_of";
+/// Name of `allowances_of` entry point.
+pub const ALLOWANCES_OF_ENTRY_POINT_NAME: &str = "allowances_of";
+/// Name of `max_supply` entry point.
+pub const MAX_SUPPLY_ENTRY_POINT_NAME: &str = "max_supply";
+/// Name of `emit` entry point.
+pub const EMIT_ENTRY_POINT_NAME: &str = "emit";
+/// Name of `total_burned` entry point.
+pub const TOTAL_BURNED_ENTRY_POINT_NAME: &str = "total_burned";
+/// Name of `set_burn_basis_points` entry point.
+pub const SET_BURN_BASIS_POINTS_ENTRY_POINT_NAME: &str = "set_burn_basis_points";
+/// Name of `set_burn_exempt` entry point.
+pub const SET_BURN_EXEMPT_ENTRY_POINT_NAME: &str = "set_burn_exempt";
+/// Name of `total_shares` entry point.
+pub const TOTAL_SHARES_ENTRY_POINT_NAME: &str = "total_shares";
+/// Name of `shares_of` entry point.
+pub const SHARES_OF_ENTRY_POINT_NAME: &str = "shares_of";
+/// Name of `rebase` entry point.
+pub const REBASE_ENTRY_POINT_NAME: &str = "rebase";
 
 /// Name of `address` runtime argument.
 pub const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
@@ -52,3 +160,21 @@ pub const SYMBOL_RUNTIME_ARG_NAME: &str = "symbol";
 pub const DECIMALS_RUNTIME_ARG_NAME: &str = "decimals";
 /// Name of `total_supply` runtime argument.
 pub const TOTAL_SUPPLY_RUNTIME_ARG_NAME: &str = "total_supply";
+/// Name of `role` runtime argument.
+pub const ROLE_RUNTIME_ARG_NAME: &str = "role";
+/// Name of `addresses` runtime argument.
+pub const ADDRESSES_RUNTIME_ARG_NAME: &str = "addresses";
+/// Name of `reason` runtime argument.
+pub const REASON_RUNTIME_ARG_NAME: &str = "reason";
+/// Name of `index` runtime argument.
+pub const INDEX_RUNTIME_ARG_NAME: &str = "index";
+/// Name of `basis_points` runtime argument.
+pub const BASIS_POINTS_RUNTIME_ARG_NAME: &str = "basis_points";
+/// Name of `max_fee` runtime argument.
+pub const MAX_FEE_RUNTIME_ARG_NAME: &str = "max_fee";
+/// Name of `fee_recipient` runtime argument.
+pub const FEE_RECIPIENT_RUNTIME_ARG_NAME: &str = "fee_recipient";
+/// Name of `exempt` runtime argument.
+pub const EXEMPT_RUNTIME_ARG_NAME: &str = "exempt";
+/// Name of `pairs` runtime argument.
+pub const PAIRS_RUNTIME_ARG_NAME: &str = "pairs";
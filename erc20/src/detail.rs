@@ -10,13 +10,21 @@ use casper_types::{bytesrepr::FromBytes, system::CallStackElement, ApiError, CLT
 use crate::{error::Error, Address};
 
 /// Gets [`URef`] under a name.
-pub(crate) fn get_uref(name: &str) -> URef {
+///
+/// Reverts with [`ApiError::MissingKey`] if there is no such named key.
+pub fn get_uref(name: &str) -> URef {
     let key = runtime::get_key(name)
         .ok_or(ApiError::MissingKey)
         .unwrap_or_revert();
     key.try_into().unwrap_or_revert()
 }
 
+/// Gets [`URef`] under a name, or `None` if there is no such named key.
+pub(crate) fn get_optional_uref(name: &str) -> Option<URef> {
+    let key = runtime::get_key(name)?;
+    Some(key.try_into().unwrap_or_revert())
+}
+
 /// Reads value from a named key.
 pub(crate) fn read_from<T>(name: &str) -> T
 where
@@ -52,16 +60,30 @@ fn call_stack_element_to_address(call_stack_element: CallStackElement) -> Addres
     }
 }
 
-/// Gets the immediate session caller of the current execution.
+/// Gets the address of the direct caller of the currently executing contract.
 ///
-/// This function ensures that only session code can execute this function, and disallows stored
-/// session/stored contracts.
-pub(crate) fn get_immediate_caller_address() -> Result<Address, Error> {
+/// The caller is either an account, for calls made from session code, or the package hash of a
+/// calling contract. Returns [`Error::InvalidContext`] if there is no caller, i.e. when called from
+/// session code, which runs as the only frame on the call stack.
+pub fn get_immediate_caller_address() -> Result<Address, Error> {
     get_immediate_call_stack_item()
         .map(call_stack_element_to_address)
         .ok_or(Error::InvalidContext)
 }
 
+/// Gets the package address of the currently executing contract, under which it holds tokens.
+///
+/// Reverts with [`Error::InvalidContext`] if the current execution is not a stored contract.
+pub fn self_package_address() -> Address {
+    match runtime::get_call_stack().into_iter().rev().next() {
+        Some(CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        }) => Address::from(contract_package_hash),
+        _ => runtime::revert(Error::InvalidContext),
+    }
+}
+
 /// Gets the caller address which is stored on the top of the call stack.
 ///
 /// This is similar to what [`runtime::get_caller`] does but it also supports stored contracts.
@@ -0,0 +1,194 @@
+//! Implementation of the supply emission schedule.
+//!
+//! Tokens accrue continuously at the rate of the current period, and are minted to the recipient
+//! whenever anyone calls `emit`.
+use alloc::vec::Vec;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef, U256,
+};
+
+use crate::{
+    constants::{EMISSION_SCHEDULE_KEY_NAME, LAST_EMISSION_KEY_NAME},
+    detail,
+    error::Error,
+    Address,
+};
+
+/// Period of an [`EmissionSchedule`] with a constant emission rate.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct EmissionPeriod {
+    /// Block time in milliseconds at which the period starts.
+    pub start: u64,
+    /// Tokens emitted per time unit of the schedule.
+    pub rate: U256,
+}
+
+/// Schedule of tokens minted over time on top of the initial supply.
+///
+/// Each period lasts until the next one starts, and the last one never ends.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct EmissionSchedule {
+    /// Address receiving the emitted tokens.
+    pub recipient: Address,
+    /// Length of the time unit the rates are expressed in, in milliseconds.
+    pub time_unit: u64,
+    /// Periods of the schedule, ordered by their start.
+    pub periods: Vec<EmissionPeriod>,
+}
+
+impl EmissionSchedule {
+    /// Creates a schedule which starts emitting `initial_rate` tokens per time unit at `start`, and
+    /// halves the rate every `halving_interval` milliseconds, `halvings` times.
+    pub fn halving(
+        recipient: Address,
+        time_unit: u64,
+        start: u64,
+        initial_rate: U256,
+        halving_interval: u64,
+        halvings: u32,
+    ) -> Self {
+        let periods = (0..=halvings)
+            .map(|halving| EmissionPeriod {
+                start: start.saturating_add(halving_interval.saturating_mul(u64::from(halving))),
+                rate: initial_rate >> halving,
+            })
+            .collect();
+        EmissionSchedule {
+            recipient,
+            time_unit,
+            periods,
+        }
+    }
+
+    /// Validates the schedule.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.time_unit == 0 || self.periods.is_empty() {
+            return Err(Error::InvalidEmissionSchedule);
+        }
+        for (period, next) in self.periods.iter().zip(self.periods.iter().skip(1)) {
+            if period.start >= next.start {
+                return Err(Error::InvalidEmissionSchedule);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the block time at which the schedule starts.
+    pub(crate) fn start(&self) -> u64 {
+        self.periods[0].start
+    }
+
+    /// Calculates the tokens accrued from the start of the schedule until block time `time`.
+    ///
+    /// Rounding down happens on the running total rather than per emission, so frequent emissions
+    /// do not lose tokens.
+    pub fn accrued_until(&self, time: u64) -> Result<U256, Error> {
+        let mut accrued = U256::zero();
+        for (index, period) in self.periods.iter().enumerate() {
+            if time <= period.start {
+                break;
+            }
+            let end = match self.periods.get(index + 1) {
+                Some(next) => next.start.min(time),
+                None => time,
+            };
+            accrued = U256::from(end - period.start)
+                .checked_mul(period.rate)
+                .and_then(|period_accrued| accrued.checked_add(period_accrued))
+                .ok_or(Error::Overflow)?;
+        }
+        Ok(accrued / U256::from(self.time_unit))
+    }
+}
+
+impl CLTyped for EmissionPeriod {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for EmissionPeriod {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.start.to_bytes()?);
+        result.append(&mut self.rate.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.start.serialized_length() + self.rate.serialized_length()
+    }
+}
+
+impl FromBytes for EmissionPeriod {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (start, remainder) = u64::from_bytes(bytes)?;
+        let (rate, remainder) = U256::from_bytes(remainder)?;
+        Ok((EmissionPeriod { start, rate }, remainder))
+    }
+}
+
+impl CLTyped for EmissionSchedule {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for EmissionSchedule {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.time_unit.to_bytes()?);
+        result.append(&mut self.periods.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.recipient.serialized_length()
+            + self.time_unit.serialized_length()
+            + self.periods.serialized_length()
+    }
+}
+
+impl FromBytes for EmissionSchedule {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (recipient, remainder) = Address::from_bytes(bytes)?;
+        let (time_unit, remainder) = u64::from_bytes(remainder)?;
+        let (periods, remainder) = Vec::<EmissionPeriod>::from_bytes(remainder)?;
+        let emission_schedule = EmissionSchedule {
+            recipient,
+            time_unit,
+            periods,
+        };
+        Ok((emission_schedule, remainder))
+    }
+}
+
+/// Returns the emission schedule [`URef`], or `None` if the token was installed without one.
+#[inline]
+pub(crate) fn emission_schedule_uref() -> Option<URef> {
+    detail::get_optional_uref(EMISSION_SCHEDULE_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn last_emission_uref() -> URef {
+    detail::get_uref(LAST_EMISSION_KEY_NAME)
+}
+
+/// Reads the emission schedule from a specified [`URef`].
+pub(crate) fn read_emission_schedule_from(uref: URef) -> EmissionSchedule {
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Reads the block time of the last emission from a specified [`URef`].
+pub(crate) fn read_last_emission_from(uref: URef) -> u64 {
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Writes the block time of the last emission to a specified [`URef`].
+pub(crate) fn write_last_emission_to(uref: URef, last_emission: u64) {
+    storage::write(uref, last_emission);
+}
@@ -8,12 +8,27 @@ use casper_types::{
 use crate::{
     address::Address,
     constants::{
-        ADDRESS_RUNTIME_ARG_NAME, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT_RUNTIME_ARG_NAME,
-        APPROVE_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, DECIMALS_ENTRY_POINT_NAME,
-        NAME_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
-        SPENDER_RUNTIME_ARG_NAME, SYMBOL_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME,
-        TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+        ADDRESSES_RUNTIME_ARG_NAME, ADDRESS_RUNTIME_ARG_NAME, ADD_TO_ALLOWLIST_ENTRY_POINT_NAME,
+        ALLOWANCES_OF_ENTRY_POINT_NAME, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT_RUNTIME_ARG_NAME,
+        APPROVE_ENTRY_POINT_NAME, BALANCES_OF_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME,
+        BASIS_POINTS_RUNTIME_ARG_NAME, CALCULATE_FEE_ENTRY_POINT_NAME, DECIMALS_ENTRY_POINT_NAME,
+        EMIT_ENTRY_POINT_NAME, EXEMPT_RUNTIME_ARG_NAME, FEE_RECIPIENT_RUNTIME_ARG_NAME,
+        FORCE_TRANSFER_COUNT_ENTRY_POINT_NAME, FORCE_TRANSFER_ENTRY_POINT_NAME,
+        FORCE_TRANSFER_RECORD_ENTRY_POINT_NAME, FREEZE_ENTRY_POINT_NAME,
+        GRANT_ROLE_ENTRY_POINT_NAME, HAS_ROLE_ENTRY_POINT_NAME, HOLDER_AT_ENTRY_POINT_NAME,
+        HOLDER_COUNT_ENTRY_POINT_NAME, INDEX_RUNTIME_ARG_NAME, IS_ALLOWLISTED_ENTRY_POINT_NAME,
+        IS_FROZEN_ENTRY_POINT_NAME, MAX_FEE_RUNTIME_ARG_NAME, MAX_SUPPLY_ENTRY_POINT_NAME,
+        NAME_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME, PAIRS_RUNTIME_ARG_NAME,
+        REASON_RUNTIME_ARG_NAME, REBASE_ENTRY_POINT_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        REMOVE_FROM_ALLOWLIST_ENTRY_POINT_NAME, REVOKE_ROLE_ENTRY_POINT_NAME, ROLE_RUNTIME_ARG_NAME,
+        SET_BURN_BASIS_POINTS_ENTRY_POINT_NAME, SET_BURN_EXEMPT_ENTRY_POINT_NAME,
+        SET_FEE_EXEMPT_ENTRY_POINT_NAME, SET_TRANSFER_FEE_ENTRY_POINT_NAME,
+        SHARES_OF_ENTRY_POINT_NAME, SPENDER_RUNTIME_ARG_NAME, SYMBOL_ENTRY_POINT_NAME,
+        TOTAL_BURNED_ENTRY_POINT_NAME, TOTAL_SHARES_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME,
+        TOTAL_SUPPLY_RUNTIME_ARG_NAME, TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+        UNFREEZE_ENTRY_POINT_NAME, WIPE_FROZEN_BALANCE_ENTRY_POINT_NAME,
     },
+    force_transfers::ForceTransferRecord,
 };
 
 /// Returns the `name` entry point.
@@ -127,6 +142,338 @@ pub fn decimals() -> EntryPoint {
     )
 }
 
+/// Returns the `has_role` entry point.
+pub fn has_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HAS_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `grant_role` entry point.
+pub fn grant_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GRANT_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `revoke_role` entry point.
+pub fn revoke_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REVOKE_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_frozen` entry point.
+pub fn is_frozen() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_FROZEN_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `freeze` entry point.
+pub fn freeze() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FREEZE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unfreeze` entry point.
+pub fn unfreeze() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNFREEZE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `wipe_frozen_balance` entry point.
+pub fn wipe_frozen_balance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WIPE_FROZEN_BALANCE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_allowlisted` entry point.
+pub fn is_allowlisted() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_ALLOWLISTED_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `add_to_allowlist` entry point.
+pub fn add_to_allowlist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_TO_ALLOWLIST_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESSES_RUNTIME_ARG_NAME, Vec::<Address>::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_from_allowlist` entry point.
+pub fn remove_from_allowlist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_FROM_ALLOWLIST_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESSES_RUNTIME_ARG_NAME, Vec::<Address>::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `force_transfer` entry point.
+pub fn force_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FORCE_TRANSFER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(REASON_RUNTIME_ARG_NAME, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `force_transfer_count` entry point.
+pub fn force_transfer_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FORCE_TRANSFER_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `force_transfer_record` entry point.
+pub fn force_transfer_record() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FORCE_TRANSFER_RECORD_ENTRY_POINT_NAME),
+        vec![Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type())],
+        ForceTransferRecord::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `calculate_fee` entry point.
+pub fn calculate_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CALCULATE_FEE_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_transfer_fee` entry point.
+pub fn set_transfer_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_TRANSFER_FEE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(BASIS_POINTS_RUNTIME_ARG_NAME, u32::cl_type()),
+            Parameter::new(MAX_FEE_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(FEE_RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_fee_exempt` entry point.
+pub fn set_fee_exempt() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_FEE_EXEMPT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(EXEMPT_RUNTIME_ARG_NAME, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `holder_count` entry point.
+pub fn holder_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLDER_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `holder_at` entry point.
+pub fn holder_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLDER_AT_ENTRY_POINT_NAME),
+        vec![Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type())],
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balances_of` entry point.
+pub fn balances_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCES_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESSES_RUNTIME_ARG_NAME, Vec::<Address>::cl_type())],
+        Vec::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `allowances_of` entry point.
+pub fn allowances_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ALLOWANCES_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(PAIRS_RUNTIME_ARG_NAME, Vec::<(Address, Address)>::cl_type())],
+        Vec::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `max_supply` entry point.
+pub fn max_supply() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MAX_SUPPLY_ENTRY_POINT_NAME),
+        Vec::new(),
+        Option::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `emit` entry point.
+pub fn emit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EMIT_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `total_burned` entry point.
+pub fn total_burned() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_BURNED_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_burn_basis_points` entry point.
+pub fn set_burn_basis_points() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_BURN_BASIS_POINTS_ENTRY_POINT_NAME),
+        vec![Parameter::new(BASIS_POINTS_RUNTIME_ARG_NAME, u32::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_burn_exempt` entry point.
+pub fn set_burn_exempt() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_BURN_EXEMPT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(EXEMPT_RUNTIME_ARG_NAME, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `total_shares` entry point.
+pub fn total_shares() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_SHARES_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `shares_of` entry point.
+pub fn shares_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SHARES_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `rebase` entry point.
+pub fn rebase() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REBASE_ENTRY_POINT_NAME),
+        vec![Parameter::new(TOTAL_SUPPLY_RUNTIME_ARG_NAME, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the default set of ERC20 token entry points.
 pub fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
@@ -0,0 +1,200 @@
+//! Typed client for calling ERC20 tokens from other contracts.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::contract_api::runtime;
+use casper_types::{
+    bytesrepr::FromBytes, runtime_args, CLTyped, ContractHash, ContractPackageHash, RuntimeArgs,
+    U256,
+};
+
+use crate::{
+    constants::{
+        ADDRESSES_RUNTIME_ARG_NAME, ADDRESS_RUNTIME_ARG_NAME, ALLOWANCES_OF_ENTRY_POINT_NAME,
+        ALLOWANCE_ENTRY_POINT_NAME, AMOUNT_RUNTIME_ARG_NAME, APPROVE_ENTRY_POINT_NAME,
+        BALANCES_OF_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, DECIMALS_ENTRY_POINT_NAME,
+        FORCE_TRANSFER_RECORD_ENTRY_POINT_NAME, HAS_ROLE_ENTRY_POINT_NAME,
+        HOLDER_AT_ENTRY_POINT_NAME, INDEX_RUNTIME_ARG_NAME, MINT_ENTRY_POINT_NAME,
+        NAME_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME, PAIRS_RUNTIME_ARG_NAME,
+        RECIPIENT_RUNTIME_ARG_NAME, ROLE_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME,
+        SYMBOL_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME,
+        TRANSFER_FROM_ENTRY_POINT_NAME,
+    },
+    Address, ForceTransferRecord,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Target {
+    Contract(ContractHash),
+    Package(ContractPackageHash),
+}
+
+/// Reference to an ERC20 token contract installed on chain.
+///
+/// Calls the token's entry points from within another contract. Tokens are moved on behalf of the
+/// calling contract, i.e. [`Erc20Ref::transfer`] spends the caller's package balance.
+///
+/// Any error returned by the token reverts the whole execution.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Erc20Ref {
+    target: Target,
+}
+
+impl Erc20Ref {
+    /// Creates a reference to the token installed under a given contract hash.
+    pub fn new(contract_hash: ContractHash) -> Self {
+        Self {
+            target: Target::Contract(contract_hash),
+        }
+    }
+
+    /// Creates a reference to the latest version of the token installed under a given contract
+    /// package hash.
+    pub fn from_package_hash(contract_package_hash: ContractPackageHash) -> Self {
+        Self {
+            target: Target::Package(contract_package_hash),
+        }
+    }
+
+    fn call<T: CLTyped + FromBytes>(&self, entry_point_name: &str, runtime_args: RuntimeArgs) -> T {
+        match self.target {
+            Target::Contract(contract_hash) => {
+                runtime::call_contract(contract_hash, entry_point_name, runtime_args)
+            }
+            Target::Package(contract_package_hash) => runtime::call_versioned_contract(
+                contract_package_hash,
+                None,
+                entry_point_name,
+                runtime_args,
+            ),
+        }
+    }
+
+    /// Returns name of the token.
+    pub fn name(&self) -> String {
+        self.call(NAME_ENTRY_POINT_NAME, RuntimeArgs::default())
+    }
+
+    /// Returns symbol of the token.
+    pub fn symbol(&self) -> String {
+        self.call(SYMBOL_ENTRY_POINT_NAME, RuntimeArgs::default())
+    }
+
+    /// Returns decimals of the token.
+    pub fn decimals(&self) -> u8 {
+        self.call(DECIMALS_ENTRY_POINT_NAME, RuntimeArgs::default())
+    }
+
+    /// Returns total supply of the token.
+    pub fn total_supply(&self) -> U256 {
+        self.call(TOTAL_SUPPLY_ENTRY_POINT_NAME, RuntimeArgs::default())
+    }
+
+    /// Returns the balance of `address`.
+    pub fn balance_of(&self, address: Address) -> U256 {
+        let args = runtime_args! {
+            ADDRESS_RUNTIME_ARG_NAME => address,
+        };
+        self.call(BALANCE_OF_ENTRY_POINT_NAME, args)
+    }
+
+    /// Returns the amount `spender` is allowed to withdraw from `owner`.
+    pub fn allowance(&self, owner: Address, spender: Address) -> U256 {
+        let args = runtime_args! {
+            OWNER_RUNTIME_ARG_NAME => owner,
+            SPENDER_RUNTIME_ARG_NAME => spender,
+        };
+        self.call(ALLOWANCE_ENTRY_POINT_NAME, args)
+    }
+
+    /// Returns the balances of all of the `addresses`, in the same order.
+    ///
+    /// Requires the token to expose the optional `balances_of` entry point.
+    pub fn balances_of(&self, addresses: Vec<Address>) -> Vec<U256> {
+        let args = runtime_args! {
+            ADDRESSES_RUNTIME_ARG_NAME => addresses,
+        };
+        self.call(BALANCES_OF_ENTRY_POINT_NAME, args)
+    }
+
+    /// Returns the allowances of all of the `(owner, spender)` pairs, in the same order.
+    ///
+    /// Requires the token to expose the optional `allowances_of` entry point.
+    pub fn allowances_of(&self, pairs: Vec<(Address, Address)>) -> Vec<U256> {
+        let args = runtime_args! {
+            PAIRS_RUNTIME_ARG_NAME => pairs,
+        };
+        self.call(ALLOWANCES_OF_ENTRY_POINT_NAME, args)
+    }
+
+    /// Returns `true` if `address` has been granted `role`.
+    ///
+    /// Requires the token to expose the optional `has_role` entry point.
+    pub fn has_role(&self, role: &str, address: Address) -> bool {
+        let args = runtime_args! {
+            ROLE_RUNTIME_ARG_NAME => role,
+            ADDRESS_RUNTIME_ARG_NAME => address,
+        };
+        self.call(HAS_ROLE_ENTRY_POINT_NAME, args)
+    }
+
+    /// Returns the forced transfers audit trail record stored under `index`.
+    ///
+    /// Requires the token to expose the optional `force_transfer_record` entry point.
+    pub fn force_transfer_record(&self, index: u64) -> ForceTransferRecord {
+        let args = runtime_args! {
+            INDEX_RUNTIME_ARG_NAME => index,
+        };
+        self.call(FORCE_TRANSFER_RECORD_ENTRY_POINT_NAME, args)
+    }
+
+    /// Returns the holder stored under `index`, or `None` past the last holder.
+    ///
+    /// Requires the token to expose the optional `holder_at` entry point.
+    pub fn holder_at(&self, index: u64) -> Option<Address> {
+        let args = runtime_args! {
+            INDEX_RUNTIME_ARG_NAME => index,
+        };
+        self.call(HOLDER_AT_ENTRY_POINT_NAME, args)
+    }
+
+    /// Transfers `amount` of tokens from the calling contract to `recipient`.
+    pub fn transfer(&self, recipient: Address, amount: U256) {
+        let args = runtime_args! {
+            RECIPIENT_RUNTIME_ARG_NAME => recipient,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+        };
+        self.call::<()>(TRANSFER_ENTRY_POINT_NAME, args)
+    }
+
+    /// Allows `spender` to transfer up to `amount` of the calling contract's tokens.
+    pub fn approve(&self, spender: Address, amount: U256) {
+        let args = runtime_args! {
+            SPENDER_RUNTIME_ARG_NAME => spender,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+        };
+        self.call::<()>(APPROVE_ENTRY_POINT_NAME, args)
+    }
+
+    /// Transfers `amount` of tokens from `owner` to `recipient`, spending the allowance `owner`
+    /// approved for the calling contract.
+    pub fn transfer_from(&self, owner: Address, recipient: Address, amount: U256) {
+        let args = runtime_args! {
+            OWNER_RUNTIME_ARG_NAME => owner,
+            RECIPIENT_RUNTIME_ARG_NAME => recipient,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+        };
+        self.call::<()>(TRANSFER_FROM_ENTRY_POINT_NAME, args)
+    }
+
+    /// Mints `amount` new tokens to `owner`.
+    ///
+    /// Requires the token to expose a `mint` entry point which lets the calling contract mint,
+    /// usually by checking that it was granted a minter role.
+    pub fn mint(&self, owner: Address, amount: U256) {
+        let args = runtime_args! {
+            OWNER_RUNTIME_ARG_NAME => owner,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+        };
+        self.call::<()>(MINT_ENTRY_POINT_NAME, args)
+    }
+}
@@ -9,7 +9,7 @@ use casper_types::ApiError;
 /// return those via the [`Error::User`] variant or equivalently via the [`ApiError::User`]
 /// variant.
 ///
-/// Such a user error should be in the range `[0..(u16::MAX - 4)]` (i.e. [0, 65532]) to avoid
+/// Such a user error should be in the range `[0..(u16::MAX - 19)]` (i.e. [0, 65516]) to avoid
 /// conflicting with the other `Error` variants.
 pub enum Error {
     /// ERC20 contract called from within an invalid context.
@@ -20,6 +20,36 @@ pub enum Error {
     InsufficientAllowance,
     /// Operation would cause an integer overflow.
     Overflow,
+    /// Caller has not been granted the role required by the operation.
+    PermissionDenied,
+    /// Account is frozen and can not send, receive or approve tokens.
+    AccountFrozen,
+    /// Account is expected to be frozen.
+    AccountNotFrozen,
+    /// Account is not on the allowlist.
+    NotAllowlisted,
+    /// Operation requires an optional feature which was not enabled at install.
+    FeatureDisabled,
+    /// Transfer fee is configured above 100% of the transferred amount.
+    InvalidFee,
+    /// Genesis distribution does not add up to the initial supply.
+    InvalidDistribution,
+    /// Token name is empty, too long, or contains unsupported characters.
+    InvalidName,
+    /// Token symbol is empty, too long, or contains characters other than ASCII letters and digits.
+    InvalidSymbol,
+    /// Token decimals exceed the supported maximum.
+    InvalidDecimals,
+    /// Operation would raise the total supply above the max supply.
+    SupplyCapExceeded,
+    /// Emission schedule has no periods, a zero time unit, or periods out of order.
+    InvalidEmissionSchedule,
+    /// Burn rate is configured above its upper bound, or the upper bound above 100%.
+    InvalidBurnRate,
+    /// Rebase would leave a zero total supply with shares outstanding, or the other way round.
+    InvalidRebase,
+    /// Extra named key given at install is named like one of the token's own keys.
+    ReservedKeyName,
     /// User error.
     User(u16),
 }
@@ -28,6 +58,21 @@ const ERROR_INVALID_CONTEXT: u16 = u16::MAX;
 const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 1;
 const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
 const ERROR_OVERFLOW: u16 = u16::MAX - 3;
+const ERROR_PERMISSION_DENIED: u16 = u16::MAX - 4;
+const ERROR_ACCOUNT_FROZEN: u16 = u16::MAX - 5;
+const ERROR_ACCOUNT_NOT_FROZEN: u16 = u16::MAX - 6;
+const ERROR_NOT_ALLOWLISTED: u16 = u16::MAX - 7;
+const ERROR_FEATURE_DISABLED: u16 = u16::MAX - 8;
+const ERROR_INVALID_FEE: u16 = u16::MAX - 9;
+const ERROR_INVALID_DISTRIBUTION: u16 = u16::MAX - 10;
+const ERROR_INVALID_NAME: u16 = u16::MAX - 11;
+const ERROR_INVALID_SYMBOL: u16 = u16::MAX - 12;
+const ERROR_INVALID_DECIMALS: u16 = u16::MAX - 13;
+const ERROR_SUPPLY_CAP_EXCEEDED: u16 = u16::MAX - 14;
+const ERROR_INVALID_EMISSION_SCHEDULE: u16 = u16::MAX - 15;
+const ERROR_INVALID_BURN_RATE: u16 = u16::MAX - 16;
+const ERROR_INVALID_REBASE: u16 = u16::MAX - 17;
+const ERROR_RESERVED_KEY_NAME: u16 = u16::MAX - 18;
 
 impl From<Error> for ApiError {
     fn from(error: Error) -> Self {
@@ -36,6 +81,21 @@ impl From<Error> for ApiError {
             Error::InsufficientBalance => ERROR_INSUFFICIENT_BALANCE,
             Error::InsufficientAllowance => ERROR_INSUFFICIENT_ALLOWANCE,
             Error::Overflow => ERROR_OVERFLOW,
+            Error::PermissionDenied => ERROR_PERMISSION_DENIED,
+            Error::AccountFrozen => ERROR_ACCOUNT_FROZEN,
+            Error::AccountNotFrozen => ERROR_ACCOUNT_NOT_FROZEN,
+            Error::NotAllowlisted => ERROR_NOT_ALLOWLISTED,
+            Error::FeatureDisabled => ERROR_FEATURE_DISABLED,
+            Error::InvalidFee => ERROR_INVALID_FEE,
+            Error::InvalidDistribution => ERROR_INVALID_DISTRIBUTION,
+            Error::InvalidName => ERROR_INVALID_NAME,
+            Error::InvalidSymbol => ERROR_INVALID_SYMBOL,
+            Error::InvalidDecimals => ERROR_INVALID_DECIMALS,
+            Error::SupplyCapExceeded => ERROR_SUPPLY_CAP_EXCEEDED,
+            Error::InvalidEmissionSchedule => ERROR_INVALID_EMISSION_SCHEDULE,
+            Error::InvalidBurnRate => ERROR_INVALID_BURN_RATE,
+            Error::InvalidRebase => ERROR_INVALID_REBASE,
+            Error::ReservedKeyName => ERROR_RESERVED_KEY_NAME,
             Error::User(user_error) => user_error,
         };
         ApiError::User(user_error)
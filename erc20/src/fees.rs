@@ -0,0 +1,119 @@
+//! Implementation of transfer fees.
+use alloc::vec::Vec;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef, U256,
+};
+
+use crate::{
+    balances,
+    constants::{FEE_EXEMPT_KEY_NAME, MAX_BASIS_POINTS, TRANSFER_FEE_KEY_NAME},
+    detail,
+    error::Error,
+    Address,
+};
+
+/// Configuration of the fee charged on transfers.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct TransferFee {
+    /// Fee charged, in hundredths of a percent of the transferred amount.
+    pub basis_points: u32,
+    /// Upper bound of the fee charged on a single transfer.
+    pub max_fee: U256,
+    /// Address which collects the fees.
+    pub recipient: Address,
+}
+
+impl TransferFee {
+    /// Validates the fee configuration.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.basis_points > MAX_BASIS_POINTS {
+            return Err(Error::InvalidFee);
+        }
+        Ok(())
+    }
+
+    /// Calculates the fee charged on a transfer of `amount` tokens.
+    pub fn calculate(&self, amount: U256) -> Result<U256, Error> {
+        let fee = amount
+            .checked_mul(U256::from(self.basis_points))
+            .ok_or(Error::Overflow)?
+            / U256::from(MAX_BASIS_POINTS);
+        Ok(fee.min(self.max_fee))
+    }
+}
+
+impl CLTyped for TransferFee {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for TransferFee {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.basis_points.to_bytes()?);
+        result.append(&mut self.max_fee.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.basis_points.serialized_length()
+            + self.max_fee.serialized_length()
+            + self.recipient.serialized_length()
+    }
+}
+
+impl FromBytes for TransferFee {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (basis_points, remainder) = u32::from_bytes(bytes)?;
+        let (max_fee, remainder) = U256::from_bytes(remainder)?;
+        let (recipient, remainder) = Address::from_bytes(remainder)?;
+        let transfer_fee = TransferFee {
+            basis_points,
+            max_fee,
+            recipient,
+        };
+        Ok((transfer_fee, remainder))
+    }
+}
+
+/// Returns the transfer fee [`URef`], or `None` if the token was installed without fees.
+#[inline]
+pub(crate) fn transfer_fee_uref() -> Option<URef> {
+    detail::get_optional_uref(TRANSFER_FEE_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn fee_exempt_uref() -> URef {
+    detail::get_uref(FEE_EXEMPT_KEY_NAME)
+}
+
+/// Reads the transfer fee configuration from a specified [`URef`].
+pub(crate) fn read_transfer_fee_from(uref: URef) -> TransferFee {
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Writes the transfer fee configuration to a specified [`URef`].
+pub(crate) fn write_transfer_fee_to(uref: URef, transfer_fee: TransferFee) {
+    storage::write(uref, transfer_fee);
+}
+
+/// Writes fee exemption of a specified address into a dictionary.
+pub(crate) fn write_fee_exempt_to(fee_exempt_uref: URef, address: Address, exempt: bool) {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    storage::dictionary_put(fee_exempt_uref, &dictionary_item_key, exempt);
+}
+
+/// Reads fee exemption of a specified address.
+///
+/// If a given address was never exempted, then `false` is returned.
+pub(crate) fn read_fee_exempt_from(fee_exempt_uref: URef, address: Address) -> bool {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    storage::dictionary_get(fee_exempt_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
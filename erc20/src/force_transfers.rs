@@ -0,0 +1,115 @@
+//! Implementation of the forced transfers audit trail.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef, U256,
+};
+
+use crate::{
+    constants::{FORCE_TRANSFERS_KEY_NAME, FORCE_TRANSFER_COUNT_KEY_NAME},
+    detail, Address,
+};
+
+/// Audit record of a transfer forced by an operator without the owner's approval.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ForceTransferRecord {
+    /// Address which forced the transfer.
+    pub operator: Address,
+    /// Address the tokens were taken from.
+    pub owner: Address,
+    /// Address the tokens were given to.
+    pub recipient: Address,
+    /// Amount of tokens transferred.
+    pub amount: U256,
+    /// Justification given by the operator, e.g. a court order reference.
+    pub reason: String,
+    /// Block time at which the transfer was forced.
+    pub timestamp: u64,
+}
+
+impl CLTyped for ForceTransferRecord {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for ForceTransferRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.operator.to_bytes()?);
+        result.append(&mut self.owner.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.reason.to_bytes()?);
+        result.append(&mut self.timestamp.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.operator.serialized_length()
+            + self.owner.serialized_length()
+            + self.recipient.serialized_length()
+            + self.amount.serialized_length()
+            + self.reason.serialized_length()
+            + self.timestamp.serialized_length()
+    }
+}
+
+impl FromBytes for ForceTransferRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (operator, remainder) = Address::from_bytes(bytes)?;
+        let (owner, remainder) = Address::from_bytes(remainder)?;
+        let (recipient, remainder) = Address::from_bytes(remainder)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (reason, remainder) = String::from_bytes(remainder)?;
+        let (timestamp, remainder) = u64::from_bytes(remainder)?;
+        let record = ForceTransferRecord {
+            operator,
+            owner,
+            recipient,
+            amount,
+            reason,
+            timestamp,
+        };
+        Ok((record, remainder))
+    }
+}
+
+#[inline]
+pub(crate) fn force_transfers_uref() -> URef {
+    detail::get_uref(FORCE_TRANSFERS_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn force_transfer_count_uref() -> URef {
+    detail::get_uref(FORCE_TRANSFER_COUNT_KEY_NAME)
+}
+
+/// Reads the number of forced transfers recorded so far.
+pub(crate) fn read_force_transfer_count_from(uref: URef) -> u64 {
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Appends a record at the end of the audit trail.
+pub(crate) fn append_record_to(
+    force_transfers_uref: URef,
+    force_transfer_count_uref: URef,
+    record: ForceTransferRecord,
+) {
+    let index = read_force_transfer_count_from(force_transfer_count_uref);
+    storage::dictionary_put(force_transfers_uref, &index.to_string(), record);
+    storage::write(force_transfer_count_uref, index + 1);
+}
+
+/// Reads a record stored under `index` in the audit trail.
+pub(crate) fn read_record_from(
+    force_transfers_uref: URef,
+    index: u64,
+) -> Option<ForceTransferRecord> {
+    storage::dictionary_get(force_transfers_uref, &index.to_string()).unwrap_or_revert()
+}
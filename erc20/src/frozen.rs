@@ -0,0 +1,27 @@
+//! Implementation of frozen accounts.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::URef;
+
+use crate::{balances, constants::FROZEN_KEY_NAME, detail, Address};
+
+#[inline]
+pub(crate) fn frozen_uref() -> URef {
+    detail::get_uref(FROZEN_KEY_NAME)
+}
+
+/// Writes frozen status of a specified address into a dictionary.
+pub(crate) fn write_frozen_to(frozen_uref: URef, address: Address, frozen: bool) {
+    // Frozen statuses share the item key scheme with balances, as both are keyed by an address.
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    storage::dictionary_put(frozen_uref, &dictionary_item_key, frozen);
+}
+
+/// Reads frozen status of a specified address.
+///
+/// If a given address was never frozen, then `false` is returned.
+pub(crate) fn read_frozen_from(frozen_uref: URef, address: Address) -> bool {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    storage::dictionary_get(frozen_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
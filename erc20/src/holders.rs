@@ -0,0 +1,95 @@
+//! Implementation of holder tracking.
+//!
+//! Holders are kept in a dense list, so they can be enumerated by index. Removing a holder moves
+//! the last holder into the freed slot, which keeps the list dense at the cost of its order.
+use alloc::string::ToString;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::URef;
+
+use crate::{
+    balances,
+    constants::{HOLDERS_KEY_NAME, HOLDER_COUNT_KEY_NAME, HOLDER_POSITIONS_KEY_NAME},
+    detail, Address,
+};
+
+/// Storage of the holder list.
+#[derive(Clone, Copy)]
+pub(crate) struct Holders {
+    /// Dictionary of holder addresses keyed by their index.
+    pub(crate) holders_uref: URef,
+    /// Dictionary of holder positions, i.e. indices incremented by one, keyed by address.
+    ///
+    /// Dictionary items can not be removed, hence a position of `0` marks an address which is not
+    /// a holder.
+    pub(crate) holder_positions_uref: URef,
+    /// Number of holders.
+    pub(crate) holder_count_uref: URef,
+}
+
+/// Returns the holder list, or `None` if the token was installed without holder tracking.
+pub(crate) fn holders() -> Option<Holders> {
+    let holder_count_uref = detail::get_optional_uref(HOLDER_COUNT_KEY_NAME)?;
+    Some(Holders {
+        holders_uref: detail::get_uref(HOLDERS_KEY_NAME),
+        holder_positions_uref: detail::get_uref(HOLDER_POSITIONS_KEY_NAME),
+        holder_count_uref,
+    })
+}
+
+impl Holders {
+    /// Reads the number of holders.
+    pub(crate) fn read_count(&self) -> u64 {
+        storage::read(self.holder_count_uref)
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+    }
+
+    /// Reads the holder stored under `index`, or `None` if the index is out of bounds.
+    pub(crate) fn read_holder_at(&self, index: u64) -> Option<Address> {
+        if index >= self.read_count() {
+            return None;
+        }
+        storage::dictionary_get(self.holders_uref, &index.to_string()).unwrap_or_revert()
+    }
+
+    fn read_position(&self, address: Address) -> u64 {
+        let dictionary_item_key = balances::make_dictionary_item_key(address);
+        storage::dictionary_get(self.holder_positions_uref, &dictionary_item_key)
+            .unwrap_or_revert()
+            .unwrap_or_default()
+    }
+
+    fn write_position(&self, address: Address, position: u64) {
+        let dictionary_item_key = balances::make_dictionary_item_key(address);
+        storage::dictionary_put(self.holder_positions_uref, &dictionary_item_key, position);
+    }
+
+    /// Adds `address` to the holder list if it holds tokens, or removes it otherwise.
+    pub(crate) fn update(&self, address: Address, holds_tokens: bool) {
+        let position = self.read_position(address);
+        match (position, holds_tokens) {
+            (0, true) => {
+                let count = self.read_count();
+                storage::dictionary_put(self.holders_uref, &count.to_string(), address);
+                self.write_position(address, count + 1);
+                storage::write(self.holder_count_uref, count + 1);
+            }
+            (position, false) if position != 0 => {
+                let last_index = self.read_count() - 1;
+                let index = position - 1;
+                if index != last_index {
+                    let last_holder: Address =
+                        storage::dictionary_get(self.holders_uref, &last_index.to_string())
+                            .unwrap_or_revert()
+                            .unwrap_or_revert();
+                    storage::dictionary_put(self.holders_uref, &index.to_string(), last_holder);
+                    self.write_position(last_holder, position);
+                }
+                self.write_position(address, 0);
+                storage::write(self.holder_count_uref, last_index);
+            }
+            _ => {}
+        }
+    }
+}
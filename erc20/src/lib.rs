@@ -17,29 +17,44 @@ extern crate alloc;
 
 mod address;
 mod allowances;
+mod allowlist;
 mod balances;
+mod builder;
+mod burn;
 pub mod constants;
 mod detail;
+mod emission;
 pub mod entry_points;
+mod erc20_ref;
 mod error;
+mod fees;
+mod force_transfers;
+mod frozen;
+mod holders;
+mod metadata;
+mod roles;
+mod shares;
 mod total_supply;
 
-use alloc::string::{String, ToString};
+use alloc::{string::String, vec::Vec};
 
 use once_cell::unsync::OnceCell;
 
-use casper_contract::{
-    contract_api::{runtime, storage},
-    unwrap_or_revert::UnwrapOrRevert,
-};
-use casper_types::{contracts::NamedKeys, EntryPoints, Key, URef, U256};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{EntryPoints, URef, U256};
 
 pub use address::Address;
+pub use builder::ERC20Builder;
 use constants::{
-    ALLOWANCES_KEY_NAME, BALANCES_KEY_NAME, DECIMALS_KEY_NAME, ERC20_TOKEN_CONTRACT_KEY_NAME,
-    NAME_KEY_NAME, SYMBOL_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
+    ADMIN_ROLE, DECIMALS_KEY_NAME, FORCE_TRANSFER_ROLE, NAME_KEY_NAME, SYMBOL_KEY_NAME,
 };
+pub use detail::{get_immediate_caller_address, get_uref, self_package_address};
+pub use emission::{EmissionPeriod, EmissionSchedule};
+pub use erc20_ref::Erc20Ref;
 pub use error::Error;
+pub use fees::TransferFee;
+pub use force_transfers::ForceTransferRecord;
+use holders::Holders;
 
 /// Implementation of ERC20 standard functionality.
 #[derive(Default)]
@@ -47,17 +62,25 @@ pub struct ERC20 {
     balances_uref: OnceCell<URef>,
     allowances_uref: OnceCell<URef>,
     total_supply_uref: OnceCell<URef>,
+    roles_uref: OnceCell<URef>,
+    frozen_uref: OnceCell<URef>,
+    allowlist_uref: OnceCell<Option<URef>>,
+    force_transfers_uref: OnceCell<URef>,
+    force_transfer_count_uref: OnceCell<URef>,
+    transfer_fee_uref: OnceCell<Option<URef>>,
+    fee_exempt_uref: OnceCell<URef>,
+    holders: OnceCell<Option<Holders>>,
+    max_supply_uref: OnceCell<Option<URef>>,
+    emission_schedule_uref: OnceCell<Option<URef>>,
+    last_emission_uref: OnceCell<URef>,
+    burn_basis_points_uref: OnceCell<Option<URef>>,
+    max_burn_basis_points_uref: OnceCell<URef>,
+    burn_exempt_uref: OnceCell<URef>,
+    total_burned_uref: OnceCell<Option<URef>>,
+    total_shares_uref: OnceCell<Option<URef>>,
 }
 
 impl ERC20 {
-    fn new(balances_uref: URef, allowances_uref: URef, total_supply_uref: URef) -> Self {
-        Self {
-            balances_uref: balances_uref.into(),
-            allowances_uref: allowances_uref.into(),
-            total_supply_uref: total_supply_uref.into(),
-        }
-    }
-
     fn total_supply_uref(&self) -> URef {
         *self
             .total_supply_uref
@@ -72,16 +95,120 @@ impl ERC20 {
         total_supply::write_total_supply_to(self.total_supply_uref(), total_supply)
     }
 
+    fn max_supply_uref(&self) -> Option<URef> {
+        *self
+            .max_supply_uref
+            .get_or_init(total_supply::max_supply_uref)
+    }
+
+    fn read_max_supply(&self) -> Option<U256> {
+        self.max_supply_uref()
+            .map(total_supply::read_total_supply_from)
+    }
+
+    fn emission_schedule_uref(&self) -> Option<URef> {
+        *self
+            .emission_schedule_uref
+            .get_or_init(emission::emission_schedule_uref)
+    }
+
+    fn last_emission_uref(&self) -> URef {
+        *self
+            .last_emission_uref
+            .get_or_init(emission::last_emission_uref)
+    }
+
     fn balances_uref(&self) -> URef {
         *self.balances_uref.get_or_init(balances::get_balances_uref)
     }
 
-    fn read_balance(&self, owner: Address) -> U256 {
+    /// Returns the raw entry of `owner` in the balances dictionary, which holds shares rather
+    /// than tokens in rebasing mode.
+    fn read_shares(&self, owner: Address) -> U256 {
         balances::read_balance_from(self.balances_uref(), owner)
     }
 
-    fn write_balance(&mut self, owner: Address, amount: U256) {
-        balances::write_balance_to(self.balances_uref(), owner, amount)
+    fn write_shares(&mut self, owner: Address, shares: U256) {
+        balances::write_balance_to(self.balances_uref(), self.holders(), owner, shares)
+    }
+
+    fn read_balance(&self, owner: Address) -> U256 {
+        let owner_shares = self.read_shares(owner);
+        match self.total_shares_uref() {
+            Some(total_shares_uref) => shares::shares_to_tokens(
+                owner_shares,
+                shares::read_total_shares_from(total_shares_uref),
+                self.read_total_supply(),
+            )
+            .unwrap_or_revert(),
+            None => owner_shares,
+        }
+    }
+
+    fn total_shares_uref(&self) -> Option<URef> {
+        *self
+            .total_shares_uref
+            .get_or_init(shares::total_shares_uref)
+    }
+
+    /// Converts `amount` of tokens to the shares stored in the balances dictionary, which are the
+    /// tokens themselves unless the token was installed with rebasing.
+    fn to_shares(&self, amount: U256, round_up: bool) -> Result<U256, Error> {
+        match self.total_shares_uref() {
+            Some(total_shares_uref) => shares::tokens_to_shares(
+                amount,
+                shares::read_total_shares_from(total_shares_uref),
+                self.read_total_supply(),
+                round_up,
+            ),
+            None => Ok(amount),
+        }
+    }
+
+    /// Moves the shares worth `amount` of tokens from `sender` to `recipient`.
+    fn move_balance(
+        &mut self,
+        sender: Address,
+        recipient: Address,
+        amount: U256,
+        round_up: bool,
+    ) -> Result<(), Error> {
+        let shares = self.to_shares(amount, round_up)?;
+        balances::transfer_balance(
+            self.balances_uref(),
+            self.holders(),
+            sender,
+            recipient,
+            shares,
+        )
+    }
+
+    /// Removes `shares` from `owner` and `amount` of tokens from the total supply.
+    ///
+    /// Callers are responsible for the allowlist check, which doesn't apply to wiping a frozen
+    /// balance.
+    fn burn_shares(&mut self, owner: Address, amount: U256, shares: U256) -> Result<(), Error> {
+        let new_shares = self
+            .read_shares(owner)
+            .checked_sub(shares)
+            .ok_or(Error::InsufficientBalance)?;
+        let new_total_supply = {
+            let total_supply = self.read_total_supply();
+            total_supply.checked_sub(amount).ok_or(Error::Overflow)?
+        };
+        if let Some(total_shares_uref) = self.total_shares_uref() {
+            let total_shares = shares::read_total_shares_from(total_shares_uref)
+                .checked_sub(shares)
+                .ok_or(Error::Overflow)?;
+            shares::write_total_shares_to(total_shares_uref, total_shares);
+        }
+        self.write_shares(owner, new_shares);
+        self.write_total_supply(new_total_supply);
+        self.record_burned(amount)
+    }
+
+    fn holders(&self) -> Option<Holders> {
+        *self.holders.get_or_init(holders::holders)
     }
 
     fn allowances_uref(&self) -> URef {
@@ -98,13 +225,230 @@ impl ERC20 {
         allowances::write_allowance_to(self.allowances_uref(), owner, spender, amount)
     }
 
+    fn roles_uref(&self) -> URef {
+        *self.roles_uref.get_or_init(roles::roles_uref)
+    }
+
+    fn read_role(&self, role: &str, address: Address) -> bool {
+        roles::read_role_from(self.roles_uref(), role, address)
+    }
+
+    fn write_role(&mut self, role: &str, address: Address, granted: bool) {
+        roles::write_role_to(self.roles_uref(), role, address, granted)
+    }
+
+    /// Returns the direct caller, provided it has been granted `role`.
+    fn ensure_caller_has_role(&self, role: &str) -> Result<Address, Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        if !self.read_role(role, caller) {
+            return Err(Error::PermissionDenied);
+        }
+        Ok(caller)
+    }
+
+    fn frozen_uref(&self) -> URef {
+        *self.frozen_uref.get_or_init(frozen::frozen_uref)
+    }
+
+    fn read_frozen(&self, address: Address) -> bool {
+        frozen::read_frozen_from(self.frozen_uref(), address)
+    }
+
+    fn write_frozen(&mut self, address: Address, frozen: bool) {
+        frozen::write_frozen_to(self.frozen_uref(), address, frozen)
+    }
+
+    fn ensure_not_frozen(&self, address: Address) -> Result<(), Error> {
+        if self.read_frozen(address) {
+            return Err(Error::AccountFrozen);
+        }
+        Ok(())
+    }
+
+    fn allowlist_uref(&self) -> Option<URef> {
+        *self.allowlist_uref.get_or_init(allowlist::allowlist_uref)
+    }
+
+    /// Ensures `address` may hold tokens, which is always the case unless allowlist mode is
+    /// enabled.
+    fn ensure_allowlisted(&self, address: Address) -> Result<(), Error> {
+        match self.allowlist_uref() {
+            Some(allowlist_uref) if !allowlist::read_allowlisted_from(allowlist_uref, address) => {
+                Err(Error::NotAllowlisted)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn write_allowlisted(
+        &mut self,
+        addresses: Vec<Address>,
+        allowlisted: bool,
+    ) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        let allowlist_uref = self.allowlist_uref().ok_or(Error::FeatureDisabled)?;
+        for address in addresses {
+            allowlist::write_allowlisted_to(allowlist_uref, address, allowlisted);
+        }
+        Ok(())
+    }
+
+    fn force_transfers_uref(&self) -> URef {
+        *self
+            .force_transfers_uref
+            .get_or_init(force_transfers::force_transfers_uref)
+    }
+
+    fn force_transfer_count_uref(&self) -> URef {
+        *self
+            .force_transfer_count_uref
+            .get_or_init(force_transfers::force_transfer_count_uref)
+    }
+
+    fn transfer_fee_uref(&self) -> Option<URef> {
+        *self.transfer_fee_uref.get_or_init(fees::transfer_fee_uref)
+    }
+
+    fn read_transfer_fee(&self) -> Option<TransferFee> {
+        self.transfer_fee_uref().map(fees::read_transfer_fee_from)
+    }
+
+    fn fee_exempt_uref(&self) -> URef {
+        *self.fee_exempt_uref.get_or_init(fees::fee_exempt_uref)
+    }
+
+    /// Returns the fee collector and the fee charged on a transfer, if there is any.
+    fn charged_fee(
+        &self,
+        sender: Address,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<Option<(Address, U256)>, Error> {
+        let transfer_fee = match self.read_transfer_fee() {
+            Some(transfer_fee) => transfer_fee,
+            None => return Ok(None),
+        };
+        if sender == recipient || self.is_fee_exempt(sender) || self.is_fee_exempt(recipient) {
+            return Ok(None);
+        }
+        let fee = transfer_fee.calculate(amount)?;
+        Ok(Some((transfer_fee.recipient, fee)))
+    }
+
+    fn burn_basis_points_uref(&self) -> Option<URef> {
+        *self
+            .burn_basis_points_uref
+            .get_or_init(burn::burn_basis_points_uref)
+    }
+
+    fn max_burn_basis_points_uref(&self) -> URef {
+        *self
+            .max_burn_basis_points_uref
+            .get_or_init(burn::max_burn_basis_points_uref)
+    }
+
+    fn burn_exempt_uref(&self) -> URef {
+        *self.burn_exempt_uref.get_or_init(burn::burn_exempt_uref)
+    }
+
+    fn total_burned_uref(&self) -> Option<URef> {
+        *self
+            .total_burned_uref
+            .get_or_init(total_supply::total_burned_uref)
+    }
+
+    /// Adds `amount` to the total burned counter, if the token keeps one.
+    fn record_burned(&mut self, amount: U256) -> Result<(), Error> {
+        if let Some(total_burned_uref) = self.total_burned_uref() {
+            let total_burned = total_supply::read_total_burned_from(total_burned_uref)
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+            total_supply::write_total_burned_to(total_burned_uref, total_burned);
+        }
+        Ok(())
+    }
+
+    /// Returns the part of a transfer of `amount` tokens burned on the way, if there is any.
+    fn charged_burn(
+        &self,
+        sender: Address,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<U256, Error> {
+        let basis_points = match self.burn_basis_points() {
+            Some(basis_points) => basis_points,
+            None => return Ok(U256::zero()),
+        };
+        if sender == recipient || self.is_burn_exempt(sender) || self.is_burn_exempt(recipient) {
+            return Ok(U256::zero());
+        }
+        burn::calculate_burn(basis_points, amount)
+    }
+
     fn transfer_balance(
         &mut self,
         sender: Address,
         recipient: Address,
         amount: U256,
     ) -> Result<(), Error> {
-        balances::transfer_balance(self.balances_uref(), sender, recipient, amount)
+        self.ensure_not_frozen(sender)?;
+        self.ensure_not_frozen(recipient)?;
+        self.ensure_allowlisted(sender)?;
+        self.ensure_allowlisted(recipient)?;
+
+        let fee = self.charged_fee(sender, recipient, amount)?;
+        let amount = match fee {
+            Some((fee_recipient, fee)) => {
+                // The fee recipient receives tokens, same as the recipient.
+                self.ensure_not_frozen(fee_recipient)?;
+                self.ensure_allowlisted(fee_recipient)?;
+                // Fee never exceeds the amount, as it is capped at `MAX_BASIS_POINTS`.
+                amount - fee
+            }
+            None => amount,
+        };
+
+        // The burn is taken from what is left after the fee, so the two never exceed the amount.
+        let burned = self.charged_burn(sender, recipient, amount)?;
+
+        // In rebasing mode the fee and the burn are converted to shares rounding down, and the
+        // rest rounding up, so together they never take more shares than the whole amount would.
+        // All of them are converted before burning, which changes the value of a share.
+        let fee_shares = match fee {
+            Some((_fee_recipient, fee)) => self.to_shares(fee, false)?,
+            None => U256::zero(),
+        };
+        let burned_shares = self.to_shares(burned, false)?;
+        let transferred_shares = self.to_shares(amount - burned, true)?;
+
+        // Nothing is written unless the sender covers the fee, the burn and the transfer together.
+        let required_shares = fee_shares
+            .checked_add(burned_shares)
+            .and_then(|shares| shares.checked_add(transferred_shares))
+            .ok_or(Error::Overflow)?;
+        if self.read_shares(sender) < required_shares {
+            return Err(Error::InsufficientBalance);
+        }
+
+        if let Some((fee_recipient, _fee)) = fee {
+            balances::transfer_balance(
+                self.balances_uref(),
+                self.holders(),
+                sender,
+                fee_recipient,
+                fee_shares,
+            )?;
+        }
+        if !burned.is_zero() {
+            self.burn_shares(sender, burned, burned_shares)?;
+        }
+        balances::transfer_balance(
+            self.balances_uref(),
+            self.holders(),
+            sender,
+            recipient,
+            transferred_shares,
+        )
     }
 
     /// Installs the ERC20 contract with the default set of entry points.
@@ -116,15 +460,17 @@ impl ERC20 {
         decimals: u8,
         initial_supply: U256,
     ) -> Result<ERC20, Error> {
-        let default_entry_points = entry_points::default();
-        ERC20::install_custom(
-            name,
-            symbol,
-            decimals,
-            initial_supply,
-            ERC20_TOKEN_CONTRACT_KEY_NAME,
-            default_entry_points,
-        )
+        ERC20::builder(name, symbol, decimals, initial_supply).install()
+    }
+
+    /// Returns an [`ERC20Builder`] which installs the ERC20 contract with optional features.
+    pub fn builder(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        initial_supply: U256,
+    ) -> ERC20Builder {
+        ERC20Builder::new(name, symbol, decimals, initial_supply)
     }
 
     /// Returns the name of the token.
@@ -152,6 +498,14 @@ impl ERC20 {
         self.read_balance(owner)
     }
 
+    /// Returns the balances of all of the `addresses`, in the same order.
+    pub fn balances_of(&self, addresses: Vec<Address>) -> Vec<U256> {
+        addresses
+            .into_iter()
+            .map(|address| self.read_balance(address))
+            .collect()
+    }
+
     /// Transfers `amount` of tokens from the direct caller to `recipient`.
     pub fn transfer(&mut self, recipient: Address, amount: U256) -> Result<(), Error> {
         let sender = detail::get_immediate_caller_address()?;
@@ -170,6 +524,7 @@ impl ERC20 {
         if amount.is_zero() {
             return Ok(());
         }
+        self.ensure_not_frozen(spender)?;
         let spender_allowance = self.read_allowance(owner, spender);
         let new_spender_allowance = spender_allowance
             .checked_sub(amount)
@@ -179,9 +534,31 @@ impl ERC20 {
         Ok(())
     }
 
+    /// Subtracts `amount` from the tokens of `owner` allowed to be spent by `spender`.
+    ///
+    /// # Security
+    ///
+    /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
+    /// public entry point.
+    pub fn spend_allowance(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let new_spender_allowance = self
+            .read_allowance(owner, spender)
+            .checked_sub(amount)
+            .ok_or(Error::InsufficientAllowance)?;
+        self.write_allowance(owner, spender, new_spender_allowance);
+        Ok(())
+    }
+
     /// Allows `spender` to transfer up to `amount` of the direct caller's tokens.
     pub fn approve(&mut self, spender: Address, amount: U256) -> Result<(), Error> {
         let owner = detail::get_immediate_caller_address()?;
+        self.ensure_not_frozen(owner)?;
+        self.ensure_not_frozen(spender)?;
         self.write_allowance(owner, spender, amount);
         Ok(())
     }
@@ -191,6 +568,14 @@ impl ERC20 {
         self.read_allowance(owner, spender)
     }
 
+    /// Returns the allowances of all of the `(owner, spender)` pairs, in the same order.
+    pub fn allowances_of(&self, pairs: Vec<(Address, Address)>) -> Vec<U256> {
+        pairs
+            .into_iter()
+            .map(|(owner, spender)| self.read_allowance(owner, spender))
+            .collect()
+    }
+
     /// Mints `amount` new tokens and adds them to `owner`'s balance and to the token total supply.
     ///
     /// # Security
@@ -198,15 +583,30 @@ impl ERC20 {
     /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
     /// public entry point.
     pub fn mint(&mut self, owner: Address, amount: U256) -> Result<(), Error> {
-        let new_balance = {
-            let balance = self.read_balance(owner);
-            balance.checked_add(amount).ok_or(Error::Overflow)?
+        self.ensure_not_frozen(owner)?;
+        self.ensure_allowlisted(owner)?;
+        // Minted shares are rounded down, so minting never dilutes the existing holders.
+        let minted_shares = self.to_shares(amount, false)?;
+        let new_shares = {
+            let shares = self.read_shares(owner);
+            shares.checked_add(minted_shares).ok_or(Error::Overflow)?
         };
         let new_total_supply = {
             let total_supply: U256 = self.read_total_supply();
             total_supply.checked_add(amount).ok_or(Error::Overflow)?
         };
-        self.write_balance(owner, new_balance);
+        if let Some(max_supply) = self.read_max_supply() {
+            if new_total_supply > max_supply {
+                return Err(Error::SupplyCapExceeded);
+            }
+        }
+        if let Some(total_shares_uref) = self.total_shares_uref() {
+            let total_shares = shares::read_total_shares_from(total_shares_uref)
+                .checked_add(minted_shares)
+                .ok_or(Error::Overflow)?;
+            shares::write_total_shares_to(total_shares_uref, total_shares);
+        }
+        self.write_shares(owner, new_shares);
         self.write_total_supply(new_total_supply);
         Ok(())
     }
@@ -219,94 +619,351 @@ impl ERC20 {
     /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
     /// public entry point.
     pub fn burn(&mut self, owner: Address, amount: U256) -> Result<(), Error> {
-        let new_balance = {
-            let balance = self.read_balance(owner);
-            balance
-                .checked_sub(amount)
-                .ok_or(Error::InsufficientBalance)?
-        };
-        let new_total_supply = {
-            let total_supply = self.read_total_supply();
-            total_supply.checked_sub(amount).ok_or(Error::Overflow)?
-        };
-        self.write_balance(owner, new_balance);
-        self.write_total_supply(new_total_supply);
+        self.ensure_allowlisted(owner)?;
+        let shares = self.to_shares(amount, true)?;
+        self.burn_shares(owner, amount, shares)
+    }
+
+    /// Returns `true` if `address` has been granted `role`.
+    pub fn has_role(&self, role: &str, address: Address) -> bool {
+        self.read_role(role, address)
+    }
+
+    /// Grants `role` to `address`.
+    ///
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn grant_role(&mut self, role: &str, address: Address) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        self.write_role(role, address, true);
         Ok(())
     }
 
-    /// Installs the ERC20 contract with a custom set of entry points.
+    /// Revokes `role` from `address`.
     ///
-    /// # Warning
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn revoke_role(&mut self, role: &str, address: Address) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        self.write_role(role, address, false);
+        Ok(())
+    }
+
+    /// Returns `true` if `address` is frozen.
+    pub fn is_frozen(&self, address: Address) -> bool {
+        self.read_frozen(address)
+    }
+
+    /// Freezes `address`, so it can no longer send, receive or approve tokens.
     ///
-    /// Contract developers should use [`ERC20::install`] instead, as it will create the default set
-    /// of ERC20 entry points. Using `install_custom` with a different set of entry points might
-    /// lead to problems with integrators such as wallets, and exchanges.
-    #[doc(hidden)]
-    pub fn install_custom(
-        name: String,
-        symbol: String,
-        decimals: u8,
-        initial_supply: U256,
-        contract_key_name: &str,
-        entry_points: EntryPoints,
-    ) -> Result<ERC20, Error> {
-        let balances_uref = storage::new_dictionary(BALANCES_KEY_NAME).unwrap_or_revert();
-        let allowances_uref = storage::new_dictionary(ALLOWANCES_KEY_NAME).unwrap_or_revert();
-        // We need to hold on a RW access rights because tokens can be minted or burned.
-        let total_supply_uref = storage::new_uref(initial_supply).into_read_write();
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn freeze(&mut self, address: Address) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        self.write_frozen(address, true);
+        Ok(())
+    }
+
+    /// Unfreezes a previously frozen `address`.
+    ///
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn unfreeze(&mut self, address: Address) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        self.write_frozen(address, false);
+        Ok(())
+    }
 
-        let mut named_keys = NamedKeys::new();
+    /// Burns the entire balance of a frozen `address`, even if it is no longer on the allowlist.
+    ///
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn wipe_frozen_balance(&mut self, address: Address) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        if !self.read_frozen(address) {
+            return Err(Error::AccountNotFrozen);
+        }
+        // Burning the shares rather than the balance leaves no rounding dust behind.
+        let balance = self.read_balance(address);
+        let shares = self.read_shares(address);
+        self.burn_shares(address, balance, shares)
+    }
 
-        let name_key = {
-            let name_uref = storage::new_uref(name).into_read();
-            Key::from(name_uref)
-        };
+    /// Returns `true` if `address` is on the allowlist.
+    ///
+    /// Always returns `false` if the token was installed without allowlist mode.
+    pub fn is_allowlisted(&self, address: Address) -> bool {
+        self.allowlist_uref()
+            .map(|allowlist_uref| allowlist::read_allowlisted_from(allowlist_uref, address))
+            .unwrap_or_default()
+    }
 
-        let symbol_key = {
-            let symbol_uref = storage::new_uref(symbol).into_read();
-            Key::from(symbol_uref)
-        };
+    /// Adds all of the `addresses` to the allowlist.
+    ///
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn add_to_allowlist(&mut self, addresses: Vec<Address>) -> Result<(), Error> {
+        self.write_allowlisted(addresses, true)
+    }
 
-        let decimals_key = {
-            let decimals_uref = storage::new_uref(decimals).into_read();
-            Key::from(decimals_uref)
+    /// Removes all of the `addresses` from the allowlist.
+    ///
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn remove_from_allowlist(&mut self, addresses: Vec<Address>) -> Result<(), Error> {
+        self.write_allowlisted(addresses, false)
+    }
+
+    /// Transfers `amount` of tokens from `owner` to `recipient` without the owner's approval, and
+    /// appends a [`ForceTransferRecord`] to the audit trail.
+    ///
+    /// Frozen accounts, the allowlist, allowances, transfer fees and burns are not taken into
+    /// account, as this is meant for court orders and lost key recoveries.
+    ///
+    /// The direct caller is required to hold the
+    /// [`FORCE_TRANSFER_ROLE`](constants::FORCE_TRANSFER_ROLE).
+    pub fn force_transfer(
+        &mut self,
+        owner: Address,
+        recipient: Address,
+        amount: U256,
+        reason: String,
+    ) -> Result<(), Error> {
+        let operator = self.ensure_caller_has_role(FORCE_TRANSFER_ROLE)?;
+        self.move_balance(owner, recipient, amount, true)?;
+        let record = ForceTransferRecord {
+            operator,
+            owner,
+            recipient,
+            amount,
+            reason,
+            timestamp: runtime::get_blocktime().into(),
         };
+        force_transfers::append_record_to(
+            self.force_transfers_uref(),
+            self.force_transfer_count_uref(),
+            record,
+        );
+        Ok(())
+    }
 
-        let total_supply_key = Key::from(total_supply_uref);
+    /// Returns the number of records in the forced transfers audit trail.
+    pub fn force_transfer_count(&self) -> u64 {
+        force_transfers::read_force_transfer_count_from(self.force_transfer_count_uref())
+    }
 
-        let balances_dictionary_key = {
-            // Sets up initial balance for the caller - either an account, or a contract.
-            let caller = detail::get_caller_address()?;
-            balances::write_balance_to(balances_uref, caller, initial_supply);
+    /// Returns the forced transfers audit trail record stored under `index`.
+    pub fn force_transfer_record(&self, index: u64) -> Option<ForceTransferRecord> {
+        force_transfers::read_record_from(self.force_transfers_uref(), index)
+    }
 
-            runtime::remove_key(BALANCES_KEY_NAME);
+    /// Returns the transfer fee configuration, or `None` if the token was installed without fees.
+    pub fn transfer_fee(&self) -> Option<TransferFee> {
+        self.read_transfer_fee()
+    }
 
-            Key::from(balances_uref)
-        };
+    /// Returns the fee which would be charged on a transfer of `amount` tokens between two
+    /// addresses which are not exempt from fees.
+    pub fn calculate_fee(&self, amount: U256) -> Result<U256, Error> {
+        match self.read_transfer_fee() {
+            Some(transfer_fee) => transfer_fee.calculate(amount),
+            None => Ok(U256::zero()),
+        }
+    }
+
+    /// Replaces the transfer fee configuration.
+    ///
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn set_transfer_fee(&mut self, transfer_fee: TransferFee) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        let transfer_fee_uref = self.transfer_fee_uref().ok_or(Error::FeatureDisabled)?;
+        transfer_fee.validate()?;
+        fees::write_transfer_fee_to(transfer_fee_uref, transfer_fee);
+        Ok(())
+    }
+
+    /// Returns `true` if transfers from or to `address` are exempt from fees.
+    pub fn is_fee_exempt(&self, address: Address) -> bool {
+        self.transfer_fee_uref().is_some()
+            && fees::read_fee_exempt_from(self.fee_exempt_uref(), address)
+    }
+
+    /// Sets whether transfers from or to `address` are exempt from fees.
+    ///
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn set_fee_exempt(&mut self, address: Address, exempt: bool) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        if self.transfer_fee_uref().is_none() {
+            return Err(Error::FeatureDisabled);
+        }
+        fees::write_fee_exempt_to(self.fee_exempt_uref(), address, exempt);
+        Ok(())
+    }
+
+    /// Returns the number of addresses holding a non-zero balance.
+    ///
+    /// Requires the token to be installed with holder tracking.
+    pub fn holder_count(&self) -> Result<u64, Error> {
+        let holders = self.holders().ok_or(Error::FeatureDisabled)?;
+        Ok(holders.read_count())
+    }
+
+    /// Returns the holder stored under `index`, or `None` if `index` is not below
+    /// [`ERC20::holder_count`].
+    ///
+    /// Holders are not kept in any particular order, and removing a holder moves the last one into
+    /// its place. Requires the token to be installed with holder tracking.
+    pub fn holder_at(&self, index: u64) -> Result<Option<Address>, Error> {
+        let holders = self.holders().ok_or(Error::FeatureDisabled)?;
+        Ok(holders.read_holder_at(index))
+    }
 
-        let allowances_dictionary_key = {
-            runtime::remove_key(ALLOWANCES_KEY_NAME);
+    /// Returns the cap on the total supply, or `None` if the token was installed without one.
+    pub fn max_supply(&self) -> Option<U256> {
+        self.read_max_supply()
+    }
+
+    /// Returns the emission schedule, or `None` if the token was installed without one.
+    pub fn emission_schedule(&self) -> Option<EmissionSchedule> {
+        self.emission_schedule_uref()
+            .map(emission::read_emission_schedule_from)
+    }
 
-            Key::from(allowances_uref)
+    /// Mints the tokens accrued under the emission schedule since the last emission to the
+    /// schedule's recipient, and returns the amount minted.
+    ///
+    /// Anyone can call this, as the amount only depends on the block time. Emission stops once
+    /// the total supply reaches the max supply, if one is configured. Requires the token to be
+    /// installed with an emission schedule.
+    pub fn emit(&mut self) -> Result<U256, Error> {
+        let emission_schedule = self.emission_schedule().ok_or(Error::FeatureDisabled)?;
+        let last_emission_uref = self.last_emission_uref();
+        let last_emission = emission::read_last_emission_from(last_emission_uref);
+        let now: u64 = runtime::get_blocktime().into();
+        if now <= last_emission {
+            return Ok(U256::zero());
+        }
+
+        // Accrual of a schedule only ever grows with time.
+        let accrued = emission_schedule.accrued_until(now)?
+            - emission_schedule.accrued_until(last_emission)?;
+        let amount = match self.read_max_supply() {
+            Some(max_supply) => accrued.min(max_supply.saturating_sub(self.read_total_supply())),
+            None => accrued,
         };
 
-        named_keys.insert(NAME_KEY_NAME.to_string(), name_key);
-        named_keys.insert(SYMBOL_KEY_NAME.to_string(), symbol_key);
-        named_keys.insert(DECIMALS_KEY_NAME.to_string(), decimals_key);
-        named_keys.insert(BALANCES_KEY_NAME.to_string(), balances_dictionary_key);
-        named_keys.insert(ALLOWANCES_KEY_NAME.to_string(), allowances_dictionary_key);
-        named_keys.insert(TOTAL_SUPPLY_KEY_NAME.to_string(), total_supply_key);
+        emission::write_last_emission_to(last_emission_uref, now);
+        if !amount.is_zero() {
+            self.mint(emission_schedule.recipient, amount)?;
+        }
+        Ok(amount)
+    }
+
+    /// Returns the share of every transfer burned, in basis points, or `None` if the token was
+    /// installed without burn-on-transfer.
+    pub fn burn_basis_points(&self) -> Option<u32> {
+        self.burn_basis_points_uref()
+            .map(burn::read_basis_points_from)
+    }
 
-        let (contract_hash, _version) =
-            storage::new_locked_contract(entry_points, Some(named_keys), None, None);
+    /// Sets the share of every transfer burned, in basis points, up to the bound set at install.
+    ///
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn set_burn_basis_points(&mut self, basis_points: u32) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        let burn_basis_points_uref = self
+            .burn_basis_points_uref()
+            .ok_or(Error::FeatureDisabled)?;
+        let max_basis_points = burn::read_basis_points_from(self.max_burn_basis_points_uref());
+        burn::validate_burn_basis_points(basis_points, max_basis_points)?;
+        burn::write_basis_points_to(burn_basis_points_uref, basis_points);
+        Ok(())
+    }
 
-        // Hash of the installed contract will be reachable through named keys.
-        runtime::put_key(contract_key_name, Key::from(contract_hash));
+    /// Returns `true` if transfers from or to `address` are exempt from burning.
+    pub fn is_burn_exempt(&self, address: Address) -> bool {
+        self.burn_basis_points_uref().is_some()
+            && burn::read_burn_exempt_from(self.burn_exempt_uref(), address)
+    }
 
-        Ok(ERC20::new(
-            balances_uref,
-            allowances_uref,
-            total_supply_uref,
-        ))
+    /// Sets whether transfers from or to `address` are exempt from burning.
+    ///
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn set_burn_exempt(&mut self, address: Address, exempt: bool) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        if self.burn_basis_points_uref().is_none() {
+            return Err(Error::FeatureDisabled);
+        }
+        burn::write_burn_exempt_to(self.burn_exempt_uref(), address, exempt);
+        Ok(())
+    }
+
+    /// Returns the amount of tokens burned since install, both on transfers and through
+    /// [`ERC20::burn`].
+    ///
+    /// Requires the token to be installed with burn-on-transfer.
+    pub fn total_burned(&self) -> Result<U256, Error> {
+        let total_burned_uref = self.total_burned_uref().ok_or(Error::FeatureDisabled)?;
+        Ok(total_supply::read_total_burned_from(total_burned_uref))
+    }
+
+    /// Returns the total number of balance shares.
+    ///
+    /// Requires the token to be installed with rebasing.
+    pub fn total_shares(&self) -> Result<U256, Error> {
+        let total_shares_uref = self.total_shares_uref().ok_or(Error::FeatureDisabled)?;
+        Ok(shares::read_total_shares_from(total_shares_uref))
+    }
+
+    /// Returns the balance shares held by `owner`.
+    ///
+    /// Requires the token to be installed with rebasing.
+    pub fn shares_of(&self, owner: Address) -> Result<U256, Error> {
+        if self.total_shares_uref().is_none() {
+            return Err(Error::FeatureDisabled);
+        }
+        Ok(self.read_shares(owner))
+    }
+
+    /// Sets the total supply to `total_supply`, scaling every balance by the same factor while
+    /// the shares stay unchanged.
+    ///
+    /// The total supply can only be zero while there are no shares, and can not exceed the max
+    /// supply, if one is configured. Requires the token to be installed with rebasing.
+    ///
+    /// The direct caller is required to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn rebase(&mut self, total_supply: U256) -> Result<(), Error> {
+        self.ensure_caller_has_role(ADMIN_ROLE)?;
+        let total_shares_uref = self.total_shares_uref().ok_or(Error::FeatureDisabled)?;
+        let total_shares = shares::read_total_shares_from(total_shares_uref);
+        if total_shares.is_zero() != total_supply.is_zero() {
+            return Err(Error::InvalidRebase);
+        }
+        if let Some(max_supply) = self.read_max_supply() {
+            if total_supply > max_supply {
+                return Err(Error::SupplyCapExceeded);
+            }
+        }
+        self.write_total_supply(total_supply);
+        Ok(())
+    }
+
+    /// Installs the ERC20 contract with a custom set of entry points.
+    ///
+    /// # Warning
+    ///
+    /// Contract developers should use [`ERC20::install`] instead, as it will create the default set
+    /// of ERC20 entry points. Using `install_custom` with a different set of entry points might
+    /// lead to problems with integrators such as wallets, and exchanges.
+    ///
+    /// Unlike [`ERC20::install`], this does not validate the name, symbol and decimals, so that
+    /// existing callers keep installing with the metadata they already use.
+    #[doc(hidden)]
+    pub fn install_custom(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        initial_supply: U256,
+        contract_key_name: &str,
+        entry_points: EntryPoints,
+    ) -> Result<ERC20, Error> {
+        ERC20::builder(name, symbol, decimals, initial_supply)
+            .with_contract_key_name(contract_key_name)
+            .with_entry_points(entry_points)
+            .without_metadata_validation()
+            .install()
     }
 }
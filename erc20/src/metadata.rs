@@ -0,0 +1,38 @@
+//! Validation of the token metadata set at install.
+use crate::{
+    constants::{MAX_DECIMALS, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH},
+    error::Error,
+};
+
+/// Validates that the name is made of printable ASCII characters, and is neither empty, longer
+/// than [`MAX_NAME_LENGTH`], nor padded with spaces.
+pub(crate) fn validate_name(name: &str) -> Result<(), Error> {
+    if name.is_empty()
+        || name.len() > MAX_NAME_LENGTH
+        || name.trim() != name
+        || !name.chars().all(|c| c.is_ascii_graphic() || c == ' ')
+    {
+        return Err(Error::InvalidName);
+    }
+    Ok(())
+}
+
+/// Validates that the symbol is made of ASCII letters and digits, and is neither empty nor longer
+/// than [`MAX_SYMBOL_LENGTH`].
+pub(crate) fn validate_symbol(symbol: &str) -> Result<(), Error> {
+    if symbol.is_empty()
+        || symbol.len() > MAX_SYMBOL_LENGTH
+        || !symbol.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(Error::InvalidSymbol);
+    }
+    Ok(())
+}
+
+/// Validates that the decimals do not exceed [`MAX_DECIMALS`].
+pub(crate) fn validate_decimals(decimals: u8) -> Result<(), Error> {
+    if decimals > MAX_DECIMALS {
+        return Err(Error::InvalidDecimals);
+    }
+    Ok(())
+}
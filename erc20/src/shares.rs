@@ -0,0 +1,59 @@
+//! Implementation of the rebasing mode, in which balances are stored as shares of the total
+//! supply.
+//!
+//! Converting tokens to shares rounds in favour of the token: a holder is debited shares rounded
+//! up and credited shares rounded down, so rounding can never create tokens.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{URef, U256};
+
+use crate::{constants::TOTAL_SHARES_KEY_NAME, detail, error::Error};
+
+/// Returns the total shares [`URef`], or `None` if the token was installed without rebasing.
+#[inline]
+pub(crate) fn total_shares_uref() -> Option<URef> {
+    detail::get_optional_uref(TOTAL_SHARES_KEY_NAME)
+}
+
+/// Reads the total shares from a specified [`URef`].
+pub(crate) fn read_total_shares_from(uref: URef) -> U256 {
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Writes the total shares to a specified [`URef`].
+pub(crate) fn write_total_shares_to(uref: URef, total_shares: U256) {
+    storage::write(uref, total_shares);
+}
+
+/// Converts `shares` to tokens, rounding down.
+pub(crate) fn shares_to_tokens(
+    shares: U256,
+    total_shares: U256,
+    total_supply: U256,
+) -> Result<U256, Error> {
+    if total_shares.is_zero() {
+        return Ok(shares);
+    }
+    let tokens = shares.checked_mul(total_supply).ok_or(Error::Overflow)? / total_shares;
+    Ok(tokens)
+}
+
+/// Converts `amount` of tokens to shares, rounding down when `round_up` is `false`, and up
+/// otherwise.
+///
+/// Shares and tokens are one to one until there are shares and a non-zero total supply.
+pub(crate) fn tokens_to_shares(
+    amount: U256,
+    total_shares: U256,
+    total_supply: U256,
+    round_up: bool,
+) -> Result<U256, Error> {
+    if total_shares.is_zero() || total_supply.is_zero() {
+        return Ok(amount);
+    }
+    let scaled = amount.checked_mul(total_shares).ok_or(Error::Overflow)?;
+    let shares = scaled / total_supply;
+    if round_up && !(scaled % total_supply).is_zero() {
+        return Ok(shares + 1);
+    }
+    Ok(shares)
+}
@@ -3,7 +3,10 @@
 use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{URef, U256};
 
-use crate::{constants::TOTAL_SUPPLY_KEY_NAME, detail};
+use crate::{
+    constants::{MAX_SUPPLY_KEY_NAME, TOTAL_BURNED_KEY_NAME, TOTAL_SUPPLY_KEY_NAME},
+    detail,
+};
 
 #[inline]
 pub(crate) fn total_supply_uref() -> URef {
@@ -19,3 +22,26 @@ pub(crate) fn read_total_supply_from(uref: URef) -> U256 {
 pub(crate) fn write_total_supply_to(uref: URef, value: U256) {
     storage::write(uref, value);
 }
+
+/// Returns the max supply [`URef`], or `None` if the token was installed without a supply cap.
+#[inline]
+pub(crate) fn max_supply_uref() -> Option<URef> {
+    detail::get_optional_uref(MAX_SUPPLY_KEY_NAME)
+}
+
+/// Returns the total burned [`URef`], or `None` if the token was installed without
+/// burn-on-transfer.
+#[inline]
+pub(crate) fn total_burned_uref() -> Option<URef> {
+    detail::get_optional_uref(TOTAL_BURNED_KEY_NAME)
+}
+
+/// Reads the total burned amount from a specified [`URef`].
+pub(crate) fn read_total_burned_from(uref: URef) -> U256 {
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Writes the total burned amount to a specified [`URef`].
+pub(crate) fn write_total_burned_to(uref: URef, value: U256) {
+    storage::write(uref, value);
+}
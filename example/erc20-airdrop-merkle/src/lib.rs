@@ -0,0 +1,78 @@
+//! Merkle tree encoding shared by the airdrop contract and the offline tree builder.
+//!
+//! Every leaf commits to the index, account and amount of a single claim. An inner node hashes its
+//! two children in ascending order, so a proof is a plain list of sibling hashes from the leaf up
+//! to the root. Leaves and inner nodes are hashed with distinct prefixes, which prevents an inner
+//! node from being passed off as a leaf.
+//!
+//! The hash function is supplied by the caller: the contract uses the host's blake2b, while the
+//! `std` feature builds the tree and its proofs offline, see [`MerkleTree`].
+
+#![warn(missing_docs)]
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+mod tree;
+
+use alloc::{vec, vec::Vec};
+
+use casper_erc20::Address;
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    U256,
+};
+
+#[cfg(feature = "std")]
+pub use tree::{blake2b, Claim, MerkleTree, TreeError};
+
+/// Length of a hash, in bytes.
+pub const HASH_LENGTH: usize = 32;
+
+/// A blake2b-256 hash of a leaf or of an inner node.
+pub type Hash = [u8; HASH_LENGTH];
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Returns the bytes hashed into the leaf of a claim.
+pub fn leaf_preimage(
+    index: u64,
+    account: Address,
+    amount: U256,
+) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut preimage = vec![LEAF_PREFIX];
+    preimage.append(&mut index.to_bytes()?);
+    preimage.append(&mut account.to_bytes()?);
+    preimage.append(&mut amount.to_bytes()?);
+    Ok(preimage)
+}
+
+/// Returns the bytes hashed into the parent of two nodes, regardless of their order.
+pub fn node_preimage(left: &Hash, right: &Hash) -> Vec<u8> {
+    let (first, second) = if left <= right {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    let mut preimage = Vec::with_capacity(1 + 2 * HASH_LENGTH);
+    preimage.push(NODE_PREFIX);
+    preimage.extend_from_slice(first);
+    preimage.extend_from_slice(second);
+    preimage
+}
+
+/// Returns the root reached by hashing `leaf` together with each hash of `proof` in turn.
+///
+/// A proof is valid if the returned hash equals the root of the tree.
+pub fn process_proof<F>(leaf: Hash, proof: &[Hash], hash: F) -> Hash
+where
+    F: Fn(&[u8]) -> Hash,
+{
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| hash(&node_preimage(&node, sibling)))
+}
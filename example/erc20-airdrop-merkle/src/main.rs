@@ -0,0 +1,47 @@
+//! Builds the merkle tree of an airdrop from a CSV file of `address,amount` lines.
+//!
+//! Prints the root to pass to the airdrop contract at install, followed by one CSV line per claim
+//! holding its index, address, amount and proof. The proof is a list of hex encoded hashes
+//! separated by `:`.
+//!
+//! ```bash
+//! cargo run -p erc20-airdrop-merkle -- recipients.csv
+//! ```
+use std::{env, fs, process};
+
+use casper_types::Key;
+
+use erc20_airdrop_merkle::MerkleTree;
+
+fn main() {
+    let path = env::args().nth(1).unwrap_or_else(|| {
+        eprintln!("usage: erc20-airdrop-tree <CSV FILE>");
+        process::exit(2);
+    });
+    let csv = fs::read_to_string(&path).unwrap_or_else(|error| {
+        eprintln!("failed to read {}: {}", path, error);
+        process::exit(2);
+    });
+    let tree = MerkleTree::from_csv(&csv).unwrap_or_else(|error| {
+        eprintln!("failed to build tree from {}: {}", path, error);
+        process::exit(1);
+    });
+
+    println!("# root: {}", hex::encode(tree.root()));
+    println!("index,address,amount,proof");
+    for claim in tree.claims() {
+        let proof: Vec<String> = tree
+            .proof(claim.index)
+            .expect("should have proof")
+            .iter()
+            .map(hex::encode)
+            .collect();
+        println!(
+            "{},{},{},{}",
+            claim.index,
+            Key::from(claim.account).to_formatted_string(),
+            claim.amount,
+            proof.join(":")
+        );
+    }
+}
@@ -0,0 +1,331 @@
+//! Offline construction of the tree and of the proof of every claim.
+use std::{convert::TryFrom, fmt, vec, vec::Vec};
+
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+use casper_erc20::Address;
+use casper_types::{bytesrepr, ContractPackageHash, Key, U256};
+
+use crate::{leaf_preimage, node_preimage, Hash, HASH_LENGTH};
+
+/// Returns the blake2b-256 hash of `data`, as computed by the host on chain.
+pub fn blake2b(data: &[u8]) -> Hash {
+    let mut hash = [0u8; HASH_LENGTH];
+    let mut hasher = VarBlake2b::new(HASH_LENGTH).expect("should create hasher");
+    hasher.update(data);
+    hasher.finalize_variable(|digest| hash.copy_from_slice(digest));
+    hash
+}
+
+/// Error building a tree.
+#[derive(PartialEq, Eq, Debug)]
+pub enum TreeError {
+    /// There are no claims to build a tree from.
+    Empty,
+    /// A line of the CSV input is malformed.
+    InvalidLine {
+        /// Line number, counted from 1.
+        line: usize,
+        /// Reason the line was rejected.
+        reason: &'static str,
+    },
+    /// A claim could not be serialized into a leaf.
+    Serialization(bytesrepr::Error),
+}
+
+impl fmt::Display for TreeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TreeError::Empty => write!(f, "no claims to build a tree from"),
+            TreeError::InvalidLine { line, reason } => write!(f, "line {}: {}", line, reason),
+            TreeError::Serialization(error) => write!(f, "failed to serialize claim: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for TreeError {}
+
+impl From<bytesrepr::Error> for TreeError {
+    fn from(error: bytesrepr::Error) -> Self {
+        TreeError::Serialization(error)
+    }
+}
+
+/// A single claim of the airdrop.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Claim {
+    /// Position of the claim, which is also its bit in the contract's claimed bitmap.
+    pub index: u64,
+    /// Recipient of the tokens.
+    pub account: Address,
+    /// Amount of tokens to be claimed.
+    pub amount: U256,
+}
+
+/// Merkle tree over the claims of an airdrop.
+///
+/// A level with an odd number of nodes promotes its last node to the next level unchanged, hence
+/// the proof of a claim can be shorter than the height of the tree.
+#[derive(Debug)]
+pub struct MerkleTree {
+    claims: Vec<Claim>,
+    /// Hashes of every level, from the leaves up to the root.
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    /// Builds a tree over `recipients`, indexed in the given order.
+    pub fn new(recipients: Vec<(Address, U256)>) -> Result<Self, TreeError> {
+        if recipients.is_empty() {
+            return Err(TreeError::Empty);
+        }
+
+        let claims: Vec<Claim> = recipients
+            .into_iter()
+            .enumerate()
+            .map(|(index, (account, amount))| Claim {
+                index: index as u64,
+                account,
+                amount,
+            })
+            .collect();
+        let leaves = claims
+            .iter()
+            .map(|claim| leaf_preimage(claim.index, claim.account, claim.amount))
+            .map(|preimage| preimage.map(|preimage| blake2b(&preimage)))
+            .collect::<Result<Vec<Hash>, _>>()?;
+
+        let mut levels = vec![leaves];
+        while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+            let next_level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => blake2b(&node_preimage(left, right)),
+                    _ => pair[0],
+                })
+                .collect();
+            levels.push(next_level);
+        }
+
+        Ok(MerkleTree { claims, levels })
+    }
+
+    /// Builds a tree from CSV input of `address,amount` lines.
+    ///
+    /// Addresses are formatted keys, i.e. `account-hash-<hex>` or `hash-<hex>` for a contract
+    /// package, and amounts are decimal integers. Blank lines, lines starting with `#` and a
+    /// leading `address,amount` header are skipped.
+    pub fn from_csv(csv: &str) -> Result<Self, TreeError> {
+        let mut recipients = Vec::new();
+        for (line_index, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid_line = |reason| TreeError::InvalidLine {
+                line: line_index + 1,
+                reason,
+            };
+            let mut fields = line.split(',').map(str::trim);
+            let (address, amount) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(address), Some(amount), None) => (address, amount),
+                _ => return Err(invalid_line("expected two fields")),
+            };
+            if recipients.is_empty() && address.eq_ignore_ascii_case("address") {
+                continue;
+            }
+
+            let account = match Key::from_formatted_str(address) {
+                Ok(Key::Account(account_hash)) => Address::from(account_hash),
+                Ok(Key::Hash(contract_package_hash)) => {
+                    Address::from(ContractPackageHash::new(contract_package_hash))
+                }
+                _ => return Err(invalid_line("invalid address")),
+            };
+            let amount = U256::from_dec_str(amount).map_err(|_| invalid_line("invalid amount"))?;
+            recipients.push((account, amount));
+        }
+        MerkleTree::new(recipients)
+    }
+
+    /// Returns the root hash, to be stored by the contract at install.
+    pub fn root(&self) -> Hash {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Returns all claims, ordered by index.
+    pub fn claims(&self) -> &[Claim] {
+        &self.claims
+    }
+
+    /// Returns the proof of the claim at `index`, or `None` if there is no such claim.
+    pub fn proof(&self, index: u64) -> Option<Vec<Hash>> {
+        let mut position = usize::try_from(index).ok()?;
+        if position >= self.claims.len() {
+            return None;
+        }
+
+        let mut proof = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(position ^ 1) {
+                proof.push(*sibling);
+            }
+            position /= 2;
+        }
+        Some(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{format, string::ToString};
+
+    use casper_types::account::AccountHash;
+
+    use super::*;
+    use crate::process_proof;
+
+    fn recipients(count: u8) -> Vec<(Address, U256)> {
+        (0..count)
+            .map(|byte| {
+                let account = Address::from(AccountHash::new([byte; 32]));
+                (account, U256::from(1_000 * (u64::from(byte) + 1)))
+            })
+            .collect()
+    }
+
+    fn leaf(claim: &Claim) -> Hash {
+        blake2b(&leaf_preimage(claim.index, claim.account, claim.amount).unwrap())
+    }
+
+    /// Checks every proof of `tree` the way the contract does.
+    fn assert_proofs_verify(tree: &MerkleTree) {
+        for claim in tree.claims() {
+            let proof = tree.proof(claim.index).unwrap();
+            assert_eq!(
+                process_proof(leaf(claim), &proof, blake2b),
+                tree.root(),
+                "{:?}",
+                claim
+            );
+        }
+    }
+
+    #[test]
+    fn should_use_leaf_as_root_of_single_claim() {
+        let tree = MerkleTree::new(recipients(1)).unwrap();
+
+        assert_eq!(tree.root(), leaf(&tree.claims()[0]));
+        assert_eq!(tree.proof(0), Some(Vec::new()));
+        assert_eq!(tree.proof(1), None);
+        assert_proofs_verify(&tree);
+    }
+
+    #[test]
+    fn should_promote_last_node_of_odd_level() {
+        let tree = MerkleTree::new(recipients(3)).unwrap();
+        let leaves: Vec<Hash> = tree.claims().iter().map(leaf).collect();
+
+        let node = blake2b(&node_preimage(&leaves[0], &leaves[1]));
+        assert_eq!(tree.root(), blake2b(&node_preimage(&node, &leaves[2])));
+        assert_eq!(tree.proof(0), Some(vec![leaves[1], leaves[2]]));
+        assert_eq!(tree.proof(2), Some(vec![node]));
+        assert_proofs_verify(&tree);
+    }
+
+    #[test]
+    fn should_verify_proofs_of_every_claim() {
+        for count in 2..=9 {
+            let tree = MerkleTree::new(recipients(count)).unwrap();
+            assert_eq!(tree.claims().len(), usize::from(count));
+            assert_proofs_verify(&tree);
+        }
+    }
+
+    #[test]
+    fn should_not_verify_tampered_claims() {
+        let tree = MerkleTree::new(recipients(5)).unwrap();
+        let claim = tree.claims()[3];
+        let proof = tree.proof(claim.index).unwrap();
+
+        let more = Claim {
+            amount: claim.amount + 1,
+            ..claim
+        };
+        let other_index = Claim { index: 2, ..claim };
+        for tampered in [more, other_index].iter() {
+            assert_ne!(process_proof(leaf(tampered), &proof, blake2b), tree.root());
+        }
+    }
+
+    #[test]
+    fn should_not_pass_inner_node_off_as_leaf() {
+        let tree = MerkleTree::new(recipients(4)).unwrap();
+        let leaves: Vec<Hash> = tree.claims().iter().map(leaf).collect();
+
+        // The parent of the first two leaves plus the sibling pair proves the root, yet it is
+        // hashed with the node prefix, so no claim has it as its leaf.
+        let node = blake2b(&node_preimage(&leaves[0], &leaves[1]));
+        let sibling = blake2b(&node_preimage(&leaves[2], &leaves[3]));
+        assert_eq!(process_proof(node, &[sibling], blake2b), tree.root());
+        assert!(!leaves.contains(&node));
+        assert_ne!(
+            leaf_preimage(0, tree.claims()[0].account, tree.claims()[0].amount).unwrap()[0],
+            node_preimage(&leaves[0], &leaves[1])[0]
+        );
+    }
+
+    #[test]
+    fn should_not_build_empty_tree() {
+        assert_eq!(MerkleTree::new(Vec::new()).unwrap_err(), TreeError::Empty);
+        assert_eq!(
+            MerkleTree::from_csv("address,amount\n# nobody\n").unwrap_err(),
+            TreeError::Empty
+        );
+    }
+
+    #[test]
+    fn should_build_tree_from_csv() {
+        let account = AccountHash::new([1; 32]);
+        let contract_package = ContractPackageHash::new([2; 32]);
+        let csv = format!(
+            "address,amount\n# airdrop\n{},100\n\n{}, 250\n",
+            Key::Account(account).to_formatted_string(),
+            Key::Hash(contract_package.value()).to_formatted_string()
+        );
+
+        let tree = MerkleTree::from_csv(&csv).unwrap();
+        let expected = MerkleTree::new(vec![
+            (Address::from(account), U256::from(100)),
+            (Address::from(contract_package), U256::from(250)),
+        ])
+        .unwrap();
+        assert_eq!(tree.claims(), expected.claims());
+        assert_eq!(tree.root(), expected.root());
+    }
+
+    #[test]
+    fn should_reject_malformed_csv_lines() {
+        let account = Key::Account(AccountHash::new([1; 32])).to_formatted_string();
+        let cases = [
+            (format!("{}\n", account), "expected two fields"),
+            (format!("{},1,2\n", account), "expected two fields"),
+            ("uref-00,1\n".to_string(), "invalid address"),
+            (format!("{},-1\n", account), "invalid amount"),
+        ];
+        for (csv, reason) in cases.iter() {
+            assert_eq!(
+                MerkleTree::from_csv(&format!("address,amount\n{}", csv)).unwrap_err(),
+                TreeError::InvalidLine {
+                    line: 2,
+                    reason: *reason
+                },
+                "{}",
+                csv
+            );
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Airdrop contract distributing ERC20 tokens to the claims committed to by a merkle root.
+//!
+//! The tree and the proofs are built offline with the `erc20-airdrop-merkle` crate. The contract is
+//! funded by transferring tokens to its package hash.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{get_uref, Address, Erc20Ref, Error};
+use casper_types::{
+    contracts::NamedKeys, CLType, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, U256,
+};
+use erc20_airdrop_merkle::Hash;
+
+const CLAIM_ENTRY_POINT_NAME: &str = "claim";
+const IS_CLAIMED_ENTRY_POINT_NAME: &str = "is_claimed";
+
+const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
+const MERKLE_ROOT_RUNTIME_ARG_NAME: &str = "merkle_root";
+const INDEX_RUNTIME_ARG_NAME: &str = "index";
+const ACCOUNT_RUNTIME_ARG_NAME: &str = "account";
+const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
+const PROOF_RUNTIME_ARG_NAME: &str = "proof";
+
+const TOKEN_CONTRACT_KEY_NAME: &str = "token_contract";
+const MERKLE_ROOT_KEY_NAME: &str = "merkle_root";
+const CLAIMED_KEY_NAME: &str = "claimed";
+const ERC20_AIRDROP_KEY_NAME: &str = "erc20_airdrop";
+const ERC20_AIRDROP_PACKAGE_KEY_NAME: &str = "erc20_airdrop_package";
+
+/// Number of claims tracked by a single word of the claimed bitmap.
+const BITMAP_WORD_BITS: u64 = 256;
+
+/// Claim at the given index was already made.
+const ERROR_ALREADY_CLAIMED: u16 = 1;
+/// Proof does not lead from the claim to the merkle root.
+const ERROR_INVALID_PROOF: u16 = 2;
+
+fn token() -> Erc20Ref {
+    let token_contract: ContractHash = storage::read(get_uref(TOKEN_CONTRACT_KEY_NAME))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    Erc20Ref::new(token_contract)
+}
+
+fn merkle_root() -> Hash {
+    storage::read(get_uref(MERKLE_ROOT_KEY_NAME))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Returns the dictionary item key of the bitmap word holding `index`, and the bit within it.
+fn bitmap_position(index: u64) -> (String, U256) {
+    let word_index = index / BITMAP_WORD_BITS;
+    let bit = U256::one() << (index % BITMAP_WORD_BITS);
+    (word_index.to_string(), bit)
+}
+
+fn read_bitmap_word(dictionary_item_key: &str) -> U256 {
+    storage::dictionary_get(get_uref(CLAIMED_KEY_NAME), dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn is_claimed_at(index: u64) -> bool {
+    let (dictionary_item_key, bit) = bitmap_position(index);
+    !(read_bitmap_word(&dictionary_item_key) & bit).is_zero()
+}
+
+fn set_claimed(index: u64) {
+    let (dictionary_item_key, bit) = bitmap_position(index);
+    let word = read_bitmap_word(&dictionary_item_key) | bit;
+    storage::dictionary_put(get_uref(CLAIMED_KEY_NAME), &dictionary_item_key, word);
+}
+
+#[no_mangle]
+pub extern "C" fn claim() {
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+    let account: Address = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let proof: Vec<Hash> = runtime::get_named_arg(PROOF_RUNTIME_ARG_NAME);
+
+    if is_claimed_at(index) {
+        runtime::revert(Error::User(ERROR_ALREADY_CLAIMED));
+    }
+
+    let leaf_preimage =
+        erc20_airdrop_merkle::leaf_preimage(index, account, amount).unwrap_or_revert();
+    let leaf = runtime::blake2b(&leaf_preimage);
+    let root =
+        erc20_airdrop_merkle::process_proof(leaf, &proof, |preimage| runtime::blake2b(preimage));
+    if root != merkle_root() {
+        runtime::revert(Error::User(ERROR_INVALID_PROOF));
+    }
+
+    // Anyone is able to submit a claim, but the tokens always go to the account it was made for.
+    set_claimed(index);
+    token().transfer(account, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn is_claimed() {
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+    runtime::ret(CLValue::from_t(is_claimed_at(index)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let merkle_root: Hash = runtime::get_named_arg(MERKLE_ROOT_RUNTIME_ARG_NAME);
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(CLAIM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(PROOF_RUNTIME_ARG_NAME, Vec::<Hash>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(IS_CLAIMED_ENTRY_POINT_NAME),
+        vec![Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let claimed_uref = storage::new_dictionary(CLAIMED_KEY_NAME).unwrap_or_revert();
+    runtime::remove_key(CLAIMED_KEY_NAME);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        TOKEN_CONTRACT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(token_contract).into_read()),
+    );
+    named_keys.insert(
+        MERKLE_ROOT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(merkle_root).into_read()),
+    );
+    named_keys.insert(CLAIMED_KEY_NAME.to_string(), Key::from(claimed_uref));
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(ERC20_AIRDROP_PACKAGE_KEY_NAME.to_string()),
+        None,
+    );
+    runtime::put_key(ERC20_AIRDROP_KEY_NAME, Key::from(contract_hash));
+}
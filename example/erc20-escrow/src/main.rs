@@ -0,0 +1,247 @@
+//! Escrow contract settling OTC trades of one ERC20 token for another.
+//!
+//! A maker deposits the tokens it sells when creating an order. A taker fills the order by paying
+//! the requested tokens straight to the maker, and receives the deposit in exchange. Orders which
+//! were not filled by their expiry can be cancelled by the maker to get the deposit back.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+mod order;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{get_uref, Address, Erc20Ref, Error};
+use casper_types::{
+    contracts::NamedKeys, CLType, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, U256,
+};
+
+use order::Order;
+
+const CREATE_ORDER_ENTRY_POINT_NAME: &str = "create_order";
+const FILL_ORDER_ENTRY_POINT_NAME: &str = "fill_order";
+const CANCEL_ORDER_ENTRY_POINT_NAME: &str = "cancel_order";
+const IS_OPEN_ENTRY_POINT_NAME: &str = "is_open";
+
+const ORDER_ID_RUNTIME_ARG_NAME: &str = "order_id";
+const SELL_TOKEN_RUNTIME_ARG_NAME: &str = "sell_token";
+const SELL_AMOUNT_RUNTIME_ARG_NAME: &str = "sell_amount";
+const BUY_TOKEN_RUNTIME_ARG_NAME: &str = "buy_token";
+const BUY_AMOUNT_RUNTIME_ARG_NAME: &str = "buy_amount";
+const TAKER_RUNTIME_ARG_NAME: &str = "taker";
+const EXPIRY_RUNTIME_ARG_NAME: &str = "expiry";
+
+const ORDERS_KEY_NAME: &str = "orders";
+const ORDER_COUNT_KEY_NAME: &str = "order_count";
+const ERC20_ESCROW_KEY_NAME: &str = "erc20_escrow";
+const ERC20_ESCROW_PACKAGE_KEY_NAME: &str = "erc20_escrow_package";
+
+/// Order has no tokens on either side, trades a token for itself, or is already expired.
+const ERROR_INVALID_ORDER: u16 = 1;
+/// There is no order with the given id.
+const ERROR_ORDER_NOT_FOUND: u16 = 2;
+/// Order was already filled or cancelled.
+const ERROR_ORDER_CLOSED: u16 = 3;
+/// Order reached its expiry and can no longer be filled.
+const ERROR_ORDER_EXPIRED: u16 = 4;
+/// Order did not reach its expiry yet and can't be cancelled.
+const ERROR_ORDER_NOT_EXPIRED: u16 = 5;
+/// Only the maker of an order is able to cancel it.
+const ERROR_NOT_MAKER: u16 = 6;
+/// Order is reserved for another taker.
+const ERROR_NOT_TAKER: u16 = 7;
+
+fn read_order(order_id: u64) -> Option<Order> {
+    storage::dictionary_get(get_uref(ORDERS_KEY_NAME), &order_id.to_string()).unwrap_or_revert()
+}
+
+/// Returns the open order with the given id, reverting if it doesn't exist or is closed.
+fn read_open_order(order_id: u64) -> Order {
+    let order = read_order(order_id).unwrap_or_revert_with(Error::User(ERROR_ORDER_NOT_FOUND));
+    if order.closed {
+        runtime::revert(Error::User(ERROR_ORDER_CLOSED));
+    }
+    order
+}
+
+fn write_order(order_id: u64, order: Order) {
+    storage::dictionary_put(get_uref(ORDERS_KEY_NAME), &order_id.to_string(), order);
+}
+
+fn now() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+/// Pulls `amount` of `token` from `owner` into the escrow, and returns how many tokens the escrow
+/// was actually credited, which is less than `amount` for tokens charging a fee or burning on
+/// transfer.
+fn deposit(token: ContractHash, owner: Address, amount: U256) -> U256 {
+    let token = Erc20Ref::new(token);
+    let escrow = casper_erc20::self_package_address();
+    let balance_before = token.balance_of(escrow);
+    token.transfer_from(owner, escrow, amount);
+    token.balance_of(escrow).saturating_sub(balance_before)
+}
+
+#[no_mangle]
+pub extern "C" fn create_order() {
+    let sell_token: ContractHash = runtime::get_named_arg(SELL_TOKEN_RUNTIME_ARG_NAME);
+    let sell_amount: U256 = runtime::get_named_arg(SELL_AMOUNT_RUNTIME_ARG_NAME);
+    let buy_token: ContractHash = runtime::get_named_arg(BUY_TOKEN_RUNTIME_ARG_NAME);
+    let buy_amount: U256 = runtime::get_named_arg(BUY_AMOUNT_RUNTIME_ARG_NAME);
+    let taker: Option<Address> = runtime::get_named_arg(TAKER_RUNTIME_ARG_NAME);
+    let expiry: u64 = runtime::get_named_arg(EXPIRY_RUNTIME_ARG_NAME);
+
+    if sell_amount.is_zero() || buy_amount.is_zero() || sell_token == buy_token || expiry <= now() {
+        runtime::revert(Error::User(ERROR_INVALID_ORDER));
+    }
+
+    let maker = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let order_count_uref = get_uref(ORDER_COUNT_KEY_NAME);
+    let order_id: u64 = storage::read(order_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    storage::write(
+        order_count_uref,
+        order_id
+            .checked_add(1)
+            .unwrap_or_revert_with(Error::Overflow),
+    );
+
+    // Maker is expected to approve this contract's package hash for at least `sell_amount` tokens.
+    // Only what the escrow received is sold, so that the order can't be paid out of the deposits
+    // of other orders.
+    let sell_amount = deposit(sell_token, maker, sell_amount);
+    if sell_amount.is_zero() {
+        runtime::revert(Error::User(ERROR_INVALID_ORDER));
+    }
+
+    let order = Order {
+        maker,
+        sell_token,
+        sell_amount,
+        buy_token,
+        buy_amount,
+        taker,
+        expiry,
+        closed: false,
+    };
+    write_order(order_id, order);
+
+    runtime::ret(CLValue::from_t(order_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn fill_order() {
+    let order_id: u64 = runtime::get_named_arg(ORDER_ID_RUNTIME_ARG_NAME);
+    let mut order = read_open_order(order_id);
+
+    let taker = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    if order.taker.map_or(false, |allowed| allowed != taker) {
+        runtime::revert(Error::User(ERROR_NOT_TAKER));
+    }
+    if now() >= order.expiry {
+        runtime::revert(Error::User(ERROR_ORDER_EXPIRED));
+    }
+
+    order.closed = true;
+    write_order(order_id, order.clone());
+
+    // Taker is expected to approve this contract's package hash for at least `buy_amount` tokens.
+    Erc20Ref::new(order.buy_token).transfer_from(taker, order.maker, order.buy_amount);
+    Erc20Ref::new(order.sell_token).transfer(taker, order.sell_amount);
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_order() {
+    let order_id: u64 = runtime::get_named_arg(ORDER_ID_RUNTIME_ARG_NAME);
+    let mut order = read_open_order(order_id);
+
+    let caller = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    if caller != order.maker {
+        runtime::revert(Error::User(ERROR_NOT_MAKER));
+    }
+    // Until then the taker can count on the order being fillable.
+    if now() < order.expiry {
+        runtime::revert(Error::User(ERROR_ORDER_NOT_EXPIRED));
+    }
+
+    order.closed = true;
+    write_order(order_id, order.clone());
+
+    Erc20Ref::new(order.sell_token).transfer(order.maker, order.sell_amount);
+}
+
+#[no_mangle]
+pub extern "C" fn is_open() {
+    let order_id: u64 = runtime::get_named_arg(ORDER_ID_RUNTIME_ARG_NAME);
+    let is_open = read_order(order_id).map_or(false, |order| !order.closed);
+    runtime::ret(CLValue::from_t(is_open).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(CREATE_ORDER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SELL_TOKEN_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(SELL_AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(BUY_TOKEN_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(BUY_AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(TAKER_RUNTIME_ARG_NAME, Option::<Address>::cl_type()),
+            Parameter::new(EXPIRY_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(FILL_ORDER_ENTRY_POINT_NAME),
+        vec![Parameter::new(ORDER_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(CANCEL_ORDER_ENTRY_POINT_NAME),
+        vec![Parameter::new(ORDER_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(IS_OPEN_ENTRY_POINT_NAME),
+        vec![Parameter::new(ORDER_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let orders_uref = storage::new_dictionary(ORDERS_KEY_NAME).unwrap_or_revert();
+    runtime::remove_key(ORDERS_KEY_NAME);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(ORDERS_KEY_NAME.to_string(), Key::from(orders_uref));
+    named_keys.insert(
+        ORDER_COUNT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(0u64).into_read_write()),
+    );
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(ERC20_ESCROW_PACKAGE_KEY_NAME.to_string()),
+        None,
+    );
+    runtime::put_key(ERC20_ESCROW_KEY_NAME, Key::from(contract_hash));
+}
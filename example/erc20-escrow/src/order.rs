@@ -0,0 +1,86 @@
+//! Order escrowed by the contract until it is filled or cancelled.
+use alloc::vec::Vec;
+
+use casper_erc20::Address;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, ContractHash, U256,
+};
+
+/// Order selling `sell_amount` of `sell_token`, held by the escrow, for `buy_amount` of
+/// `buy_token` paid straight to the maker.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Order {
+    /// Address which created the order, and receives the bought tokens.
+    pub maker: Address,
+    /// Token deposited by the maker.
+    pub sell_token: ContractHash,
+    /// Amount of `sell_token` released to the taker on fill.
+    pub sell_amount: U256,
+    /// Token paid by the taker.
+    pub buy_token: ContractHash,
+    /// Amount of `buy_token` paid by the taker on fill.
+    pub buy_amount: U256,
+    /// Only address able to fill the order, or `None` if anyone is.
+    pub taker: Option<Address>,
+    /// Block time from which the order can no longer be filled, and can be cancelled.
+    pub expiry: u64,
+    /// Whether the order was filled or cancelled.
+    pub closed: bool,
+}
+
+impl CLTyped for Order {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Order {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.maker.to_bytes()?);
+        result.append(&mut self.sell_token.to_bytes()?);
+        result.append(&mut self.sell_amount.to_bytes()?);
+        result.append(&mut self.buy_token.to_bytes()?);
+        result.append(&mut self.buy_amount.to_bytes()?);
+        result.append(&mut self.taker.to_bytes()?);
+        result.append(&mut self.expiry.to_bytes()?);
+        result.append(&mut self.closed.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.maker.serialized_length()
+            + self.sell_token.serialized_length()
+            + self.sell_amount.serialized_length()
+            + self.buy_token.serialized_length()
+            + self.buy_amount.serialized_length()
+            + self.taker.serialized_length()
+            + self.expiry.serialized_length()
+            + self.closed.serialized_length()
+    }
+}
+
+impl FromBytes for Order {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (maker, remainder) = Address::from_bytes(bytes)?;
+        let (sell_token, remainder) = ContractHash::from_bytes(remainder)?;
+        let (sell_amount, remainder) = U256::from_bytes(remainder)?;
+        let (buy_token, remainder) = ContractHash::from_bytes(remainder)?;
+        let (buy_amount, remainder) = U256::from_bytes(remainder)?;
+        let (taker, remainder) = Option::<Address>::from_bytes(remainder)?;
+        let (expiry, remainder) = u64::from_bytes(remainder)?;
+        let (closed, remainder) = bool::from_bytes(remainder)?;
+        let order = Order {
+            maker,
+            sell_token,
+            sell_amount,
+            buy_token,
+            buy_amount,
+            taker,
+            expiry,
+            closed,
+        };
+        Ok((order, remainder))
+    }
+}
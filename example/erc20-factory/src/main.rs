@@ -0,0 +1,292 @@
+//! Factory contract which installs new ERC20 tokens and keeps a registry of them by symbol.
+//!
+//! Every token is a separate locked contract running this contract's wasm, which is why the token
+//! entry points are exported here next to the factory ones.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{
+    constants::{
+        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, DECIMALS_RUNTIME_ARG_NAME,
+        NAME_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        SPENDER_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME, TOTAL_SUPPLY_RUNTIME_ARG_NAME,
+    },
+    get_uref, Address, Error, ERC20,
+};
+use casper_types::{
+    bytesrepr::FromBytes, contracts::NamedKeys, CLType, CLTyped, CLValue, ContractHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter, U256,
+};
+
+const CREATE_TOKEN_ENTRY_POINT_NAME: &str = "create_token";
+const TOKEN_COUNT_ENTRY_POINT_NAME: &str = "token_count";
+const TOKEN_BY_SYMBOL_ENTRY_POINT_NAME: &str = "token_by_symbol";
+const LIST_TOKENS_ENTRY_POINT_NAME: &str = "list_tokens";
+
+const OFFSET_RUNTIME_ARG_NAME: &str = "offset";
+const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
+
+const ADMIN_KEY_NAME: &str = "admin";
+const REGISTRY_KEY_NAME: &str = "registry";
+const TOKENS_KEY_NAME: &str = "tokens";
+const TOKEN_COUNT_KEY_NAME: &str = "token_count";
+const ERC20_FACTORY_KEY_NAME: &str = "erc20_factory";
+const ERC20_FACTORY_PACKAGE_KEY_NAME: &str = "erc20_factory_package";
+/// Prefix of the named key the builder puts a created token's hash under, until it is registered.
+const TOKEN_CONTRACT_KEY_NAME_PREFIX: &str = "erc20_token_contract_";
+
+/// Maximum number of tokens returned by a single `list_tokens` call.
+const MAX_LIST_LIMIT: u64 = 100;
+
+/// A token with the same symbol was already created.
+const ERROR_SYMBOL_ALREADY_REGISTERED: u16 = 1;
+
+fn read_from<T: CLTyped + FromBytes>(name: &str) -> T {
+    storage::read(get_uref(name))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Symbols can be longer than the 64 characters allowed in dictionary item keys, so they are
+/// hashed.
+fn make_dictionary_item_key(symbol: &str) -> String {
+    hex::encode(runtime::blake2b(symbol.as_bytes()))
+}
+
+fn read_token_by_symbol(symbol: &str) -> Option<ContractHash> {
+    let dictionary_item_key = make_dictionary_item_key(symbol);
+    storage::dictionary_get(get_uref(REGISTRY_KEY_NAME), &dictionary_item_key).unwrap_or_revert()
+}
+
+fn read_token_at(index: u64) -> (String, ContractHash) {
+    let symbol: String = storage::dictionary_get(get_uref(TOKENS_KEY_NAME), &index.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let contract_hash = read_token_by_symbol(&symbol).unwrap_or_revert();
+    (symbol, contract_hash)
+}
+
+#[no_mangle]
+pub extern "C" fn name() {
+    let name = ERC20::default().name();
+    runtime::ret(CLValue::from_t(name).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn symbol() {
+    let symbol = ERC20::default().symbol();
+    runtime::ret(CLValue::from_t(symbol).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn decimals() {
+    let decimals = ERC20::default().decimals();
+    runtime::ret(CLValue::from_t(decimals).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    let total_supply = ERC20::default().total_supply();
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let balance = ERC20::default().balance_of(address);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default()
+        .transfer(recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default().approve(spender, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let val = ERC20::default().allowance(owner, spender);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC20::default()
+        .transfer_from(owner, recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn create_token() {
+    let name: String = runtime::get_named_arg(NAME_RUNTIME_ARG_NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
+    let decimals: u8 = runtime::get_named_arg(DECIMALS_RUNTIME_ARG_NAME);
+    let total_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY_RUNTIME_ARG_NAME);
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+
+    let caller = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let admin: Address = read_from(ADMIN_KEY_NAME);
+    if caller != admin {
+        runtime::revert(Error::PermissionDenied);
+    }
+    if read_token_by_symbol(&symbol).is_some() {
+        runtime::revert(Error::User(ERROR_SYMBOL_ALREADY_REGISTERED));
+    }
+
+    // Every token gets its own named key, so installing one never overwrites another.
+    let contract_key_name = format!("{}{}", TOKEN_CONTRACT_KEY_NAME_PREFIX, symbol);
+    ERC20::builder(name, symbol.clone(), decimals, total_supply)
+        .with_contract_key_name(&contract_key_name)
+        .with_initial_owner(owner)
+        .install()
+        .unwrap_or_revert();
+    let contract_hash = runtime::get_key(&contract_key_name)
+        .and_then(Key::into_hash)
+        .map(ContractHash::new)
+        .unwrap_or_revert();
+    // The registry keeps the hash, so the factory's named keys don't grow with every token.
+    runtime::remove_key(&contract_key_name);
+
+    let token_count_uref = get_uref(TOKEN_COUNT_KEY_NAME);
+    let index: u64 = storage::read(token_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    storage::dictionary_put(
+        get_uref(TOKENS_KEY_NAME),
+        &index.to_string(),
+        symbol.clone(),
+    );
+    storage::dictionary_put(
+        get_uref(REGISTRY_KEY_NAME),
+        &make_dictionary_item_key(&symbol),
+        contract_hash,
+    );
+    storage::write(token_count_uref, index + 1);
+}
+
+#[no_mangle]
+pub extern "C" fn token_count() {
+    let token_count: u64 = read_from(TOKEN_COUNT_KEY_NAME);
+    runtime::ret(CLValue::from_t(token_count).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn token_by_symbol() {
+    let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
+    let contract_hash = read_token_by_symbol(&symbol);
+    runtime::ret(CLValue::from_t(contract_hash).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn list_tokens() {
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let token_count: u64 = read_from(TOKEN_COUNT_KEY_NAME);
+    let end = offset
+        .saturating_add(cmp::min(limit, MAX_LIST_LIMIT))
+        .min(token_count);
+    let tokens: Vec<(String, ContractHash)> = (offset..end).map(read_token_at).collect();
+    runtime::ret(CLValue::from_t(tokens).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(CREATE_TOKEN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(NAME_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(SYMBOL_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(DECIMALS_RUNTIME_ARG_NAME, u8::cl_type()),
+            Parameter::new(TOTAL_SUPPLY_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(TOKEN_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(TOKEN_BY_SYMBOL_ENTRY_POINT_NAME),
+        vec![Parameter::new(SYMBOL_RUNTIME_ARG_NAME, String::cl_type())],
+        Option::<ContractHash>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(LIST_TOKENS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        Vec::<(String, ContractHash)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let registry_uref = storage::new_dictionary(REGISTRY_KEY_NAME).unwrap_or_revert();
+    let tokens_uref = storage::new_dictionary(TOKENS_KEY_NAME).unwrap_or_revert();
+    runtime::remove_key(REGISTRY_KEY_NAME);
+    runtime::remove_key(TOKENS_KEY_NAME);
+
+    // Whoever installs the factory is the only one allowed to create tokens.
+    let admin = Address::from(runtime::get_caller());
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        ADMIN_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(admin).into_read()),
+    );
+    named_keys.insert(REGISTRY_KEY_NAME.to_string(), Key::from(registry_uref));
+    named_keys.insert(TOKENS_KEY_NAME.to_string(), Key::from(tokens_uref));
+    named_keys.insert(
+        TOKEN_COUNT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(0u64).into_read_write()),
+    );
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(ERC20_FACTORY_PACKAGE_KEY_NAME.to_string()),
+        None,
+    );
+    runtime::put_key(ERC20_FACTORY_KEY_NAME, Key::from(contract_hash));
+}
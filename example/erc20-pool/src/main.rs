@@ -0,0 +1,464 @@
+//! Constant-product liquidity pool in the style of Uniswap V2, trading two ERC20 tokens and
+//! issuing its own ERC20 liquidity shares.
+//!
+//! The pool contract is the LP token itself, which is why the token entry points are exported
+//! here next to the pool ones. Both tokens are held under the pool's package hash, and are only
+//! ever pulled in through `transfer_from`, so the caller is expected to approve the pool first.
+//! Reserves are synced to the pool's balances after every operation, so tokens charging a fee or
+//! burning on transfer only count for what the pool actually received.
+//! Every swap pays a 0.3% fee which stays in the pool, to the benefit of liquidity providers.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{
+    constants::{
+        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, NAME_RUNTIME_ARG_NAME,
+        OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME,
+        SYMBOL_RUNTIME_ARG_NAME,
+    },
+    get_uref, Address, Erc20Ref, Error, ERC20,
+};
+use casper_types::{
+    contracts::NamedKeys, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess,
+    EntryPointType, Key, Parameter, U256,
+};
+
+const ADD_LIQUIDITY_ENTRY_POINT_NAME: &str = "add_liquidity";
+const REMOVE_LIQUIDITY_ENTRY_POINT_NAME: &str = "remove_liquidity";
+const SWAP_EXACT_IN_ENTRY_POINT_NAME: &str = "swap_exact_in";
+const GET_RESERVES_ENTRY_POINT_NAME: &str = "get_reserves";
+
+const TOKEN_A_RUNTIME_ARG_NAME: &str = "token_a";
+const TOKEN_B_RUNTIME_ARG_NAME: &str = "token_b";
+const AMOUNT_A_DESIRED_RUNTIME_ARG_NAME: &str = "amount_a_desired";
+const AMOUNT_B_DESIRED_RUNTIME_ARG_NAME: &str = "amount_b_desired";
+const AMOUNT_A_MIN_RUNTIME_ARG_NAME: &str = "amount_a_min";
+const AMOUNT_B_MIN_RUNTIME_ARG_NAME: &str = "amount_b_min";
+const LIQUIDITY_RUNTIME_ARG_NAME: &str = "liquidity";
+const TOKEN_IN_RUNTIME_ARG_NAME: &str = "token_in";
+const AMOUNT_IN_RUNTIME_ARG_NAME: &str = "amount_in";
+const AMOUNT_OUT_MIN_RUNTIME_ARG_NAME: &str = "amount_out_min";
+
+const TOKEN_A_KEY_NAME: &str = "token_a";
+const TOKEN_B_KEY_NAME: &str = "token_b";
+const RESERVE_A_KEY_NAME: &str = "reserve_a";
+const RESERVE_B_KEY_NAME: &str = "reserve_b";
+const ERC20_POOL_KEY_NAME: &str = "erc20_pool";
+
+/// Liquidity locked in the pool by the first deposit, so that the share price can't be inflated
+/// to the point where a single share is out of reach.
+const MINIMUM_LIQUIDITY: u64 = 1_000;
+/// Swap fee, in basis points of the input amount.
+const SWAP_FEE_BASIS_POINTS: u64 = 30;
+const BASIS_POINTS_SCALE: u64 = 10_000;
+
+/// Both sides of the pool are the same token.
+const ERROR_IDENTICAL_TOKENS: u16 = 1;
+/// Token is not one of the two tokens of the pool.
+const ERROR_INVALID_TOKEN: u16 = 2;
+/// Amount of token A deposited or withdrawn is below the given minimum.
+const ERROR_INSUFFICIENT_A_AMOUNT: u16 = 3;
+/// Amount of token B deposited or withdrawn is below the given minimum.
+const ERROR_INSUFFICIENT_B_AMOUNT: u16 = 4;
+/// Deposit is too small to be worth a single liquidity share.
+const ERROR_INSUFFICIENT_LIQUIDITY_MINTED: u16 = 5;
+/// Liquidity removed is too small to be worth a single unit of either token.
+const ERROR_INSUFFICIENT_LIQUIDITY_BURNED: u16 = 6;
+/// Amount to swap is zero.
+const ERROR_INSUFFICIENT_INPUT_AMOUNT: u16 = 7;
+/// Pool has no liquidity to swap against.
+const ERROR_INSUFFICIENT_LIQUIDITY: u16 = 8;
+/// Amount received by a swap is below the given minimum.
+const ERROR_INSUFFICIENT_OUTPUT_AMOUNT: u16 = 9;
+
+fn read_contract_hash(name: &str) -> ContractHash {
+    storage::read(get_uref(name))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+fn read_reserve(name: &str) -> U256 {
+    storage::read(get_uref(name))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+fn write_reserves(reserve_a: U256, reserve_b: U256) {
+    storage::write(get_uref(RESERVE_A_KEY_NAME), reserve_a);
+    storage::write(get_uref(RESERVE_B_KEY_NAME), reserve_b);
+}
+
+fn pool_balance(token: ContractHash) -> U256 {
+    Erc20Ref::new(token).balance_of(casper_erc20::self_package_address())
+}
+
+/// Sets the reserves to the balances of the pool, in the manner of Uniswap V2's `sync`.
+fn sync_reserves(token_a: ContractHash, token_b: ContractHash) {
+    write_reserves(pool_balance(token_a), pool_balance(token_b));
+}
+
+/// Pulls `amount` of `token` from `owner` into the pool, and returns how many tokens the pool was
+/// actually credited, which is less than `amount` for tokens charging a fee or burning on
+/// transfer.
+fn transfer_in(token: ContractHash, owner: Address, amount: U256) -> U256 {
+    let balance_before = pool_balance(token);
+    Erc20Ref::new(token).transfer_from(owner, casper_erc20::self_package_address(), amount);
+    pool_balance(token).saturating_sub(balance_before)
+}
+
+/// Returns `amount * numerator / denominator`, rounded down.
+fn mul_div(amount: U256, numerator: U256, denominator: U256) -> U256 {
+    amount
+        .checked_mul(numerator)
+        .unwrap_or_revert_with(Error::Overflow)
+        / denominator
+}
+
+/// Returns the amounts of token A and B to deposit, keeping to the current ratio of the reserves
+/// without exceeding either desired amount.
+fn deposit_amounts(
+    reserve_a: U256,
+    reserve_b: U256,
+    amount_a_desired: U256,
+    amount_b_desired: U256,
+    amount_a_min: U256,
+    amount_b_min: U256,
+) -> (U256, U256) {
+    if reserve_a.is_zero() && reserve_b.is_zero() {
+        // The first provider sets the price.
+        return (amount_a_desired, amount_b_desired);
+    }
+
+    let amount_b_optimal = mul_div(amount_a_desired, reserve_b, reserve_a);
+    if amount_b_optimal <= amount_b_desired {
+        if amount_b_optimal < amount_b_min {
+            runtime::revert(Error::User(ERROR_INSUFFICIENT_B_AMOUNT));
+        }
+        return (amount_a_desired, amount_b_optimal);
+    }
+
+    // Since the optimal amount of B is too high, the optimal amount of A is below the desired one.
+    let amount_a_optimal = mul_div(amount_b_desired, reserve_a, reserve_b);
+    if amount_a_optimal < amount_a_min {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_A_AMOUNT));
+    }
+    (amount_a_optimal, amount_b_desired)
+}
+
+/// Returns the amount received for swapping `amount_in` against the given reserves, net of the
+/// swap fee.
+fn amount_out(amount_in: U256, reserve_in: U256, reserve_out: U256) -> U256 {
+    let amount_in_with_fee = amount_in
+        .checked_mul(U256::from(BASIS_POINTS_SCALE - SWAP_FEE_BASIS_POINTS))
+        .unwrap_or_revert_with(Error::Overflow);
+    let denominator = reserve_in
+        .checked_mul(U256::from(BASIS_POINTS_SCALE))
+        .and_then(|scaled_reserve| scaled_reserve.checked_add(amount_in_with_fee))
+        .unwrap_or_revert_with(Error::Overflow);
+    mul_div(amount_in_with_fee, reserve_out, denominator)
+}
+
+#[no_mangle]
+pub extern "C" fn name() {
+    let name = ERC20::default().name();
+    runtime::ret(CLValue::from_t(name).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn symbol() {
+    let symbol = ERC20::default().symbol();
+    runtime::ret(CLValue::from_t(symbol).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn decimals() {
+    let decimals = ERC20::default().decimals();
+    runtime::ret(CLValue::from_t(decimals).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    let total_supply = ERC20::default().total_supply();
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let balance = ERC20::default().balance_of(address);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default()
+        .transfer(recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default().approve(spender, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let val = ERC20::default().allowance(owner, spender);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC20::default()
+        .transfer_from(owner, recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn add_liquidity() {
+    let amount_a_desired: U256 = runtime::get_named_arg(AMOUNT_A_DESIRED_RUNTIME_ARG_NAME);
+    let amount_b_desired: U256 = runtime::get_named_arg(AMOUNT_B_DESIRED_RUNTIME_ARG_NAME);
+    let amount_a_min: U256 = runtime::get_named_arg(AMOUNT_A_MIN_RUNTIME_ARG_NAME);
+    let amount_b_min: U256 = runtime::get_named_arg(AMOUNT_B_MIN_RUNTIME_ARG_NAME);
+
+    let reserve_a = read_reserve(RESERVE_A_KEY_NAME);
+    let reserve_b = read_reserve(RESERVE_B_KEY_NAME);
+    let (amount_a, amount_b) = deposit_amounts(
+        reserve_a,
+        reserve_b,
+        amount_a_desired,
+        amount_b_desired,
+        amount_a_min,
+        amount_b_min,
+    );
+
+    let token_a = read_contract_hash(TOKEN_A_KEY_NAME);
+    let token_b = read_contract_hash(TOKEN_B_KEY_NAME);
+    let provider = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let amount_a = transfer_in(token_a, provider, amount_a);
+    let amount_b = transfer_in(token_b, provider, amount_b);
+
+    let mut lp_token = ERC20::default();
+    let total_liquidity = lp_token.total_supply();
+    let liquidity = if total_liquidity.is_zero() {
+        let liquidity = amount_a
+            .checked_mul(amount_b)
+            .unwrap_or_revert_with(Error::Overflow)
+            .integer_sqrt()
+            .checked_sub(U256::from(MINIMUM_LIQUIDITY))
+            .unwrap_or_revert_with(Error::User(ERROR_INSUFFICIENT_LIQUIDITY_MINTED));
+        // Shares held by the pool itself can never be moved, hence stay locked for good.
+        lp_token
+            .mint(
+                casper_erc20::self_package_address(),
+                U256::from(MINIMUM_LIQUIDITY),
+            )
+            .unwrap_or_revert();
+        liquidity
+    } else {
+        mul_div(amount_a, total_liquidity, reserve_a).min(mul_div(
+            amount_b,
+            total_liquidity,
+            reserve_b,
+        ))
+    };
+    if liquidity.is_zero() {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_LIQUIDITY_MINTED));
+    }
+
+    sync_reserves(token_a, token_b);
+    lp_token.mint(provider, liquidity).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(liquidity).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn remove_liquidity() {
+    let liquidity: U256 = runtime::get_named_arg(LIQUIDITY_RUNTIME_ARG_NAME);
+    let amount_a_min: U256 = runtime::get_named_arg(AMOUNT_A_MIN_RUNTIME_ARG_NAME);
+    let amount_b_min: U256 = runtime::get_named_arg(AMOUNT_B_MIN_RUNTIME_ARG_NAME);
+
+    let mut lp_token = ERC20::default();
+    let total_liquidity = lp_token.total_supply();
+    if total_liquidity.is_zero() {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_LIQUIDITY_BURNED));
+    }
+
+    // Shares are redeemed against what the pool holds, which a rebasing token can move away from
+    // the reserves between syncs.
+    let token_a = read_contract_hash(TOKEN_A_KEY_NAME);
+    let token_b = read_contract_hash(TOKEN_B_KEY_NAME);
+    let amount_a = mul_div(liquidity, pool_balance(token_a), total_liquidity);
+    let amount_b = mul_div(liquidity, pool_balance(token_b), total_liquidity);
+    if amount_a.is_zero() || amount_b.is_zero() {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_LIQUIDITY_BURNED));
+    }
+    if amount_a < amount_a_min {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_A_AMOUNT));
+    }
+    if amount_b < amount_b_min {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_B_AMOUNT));
+    }
+
+    let provider = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    lp_token.burn(provider, liquidity).unwrap_or_revert();
+    Erc20Ref::new(token_a).transfer(provider, amount_a);
+    Erc20Ref::new(token_b).transfer(provider, amount_b);
+    sync_reserves(token_a, token_b);
+
+    runtime::ret(CLValue::from_t((amount_a, amount_b)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn swap_exact_in() {
+    let token_in: ContractHash = runtime::get_named_arg(TOKEN_IN_RUNTIME_ARG_NAME);
+    let amount_in: U256 = runtime::get_named_arg(AMOUNT_IN_RUNTIME_ARG_NAME);
+    let amount_out_min: U256 = runtime::get_named_arg(AMOUNT_OUT_MIN_RUNTIME_ARG_NAME);
+
+    let token_a = read_contract_hash(TOKEN_A_KEY_NAME);
+    let token_b = read_contract_hash(TOKEN_B_KEY_NAME);
+    let reserve_a = read_reserve(RESERVE_A_KEY_NAME);
+    let reserve_b = read_reserve(RESERVE_B_KEY_NAME);
+    let (token_out, reserve_in, reserve_out) = if token_in == token_a {
+        (token_b, reserve_a, reserve_b)
+    } else if token_in == token_b {
+        (token_a, reserve_b, reserve_a)
+    } else {
+        runtime::revert(Error::User(ERROR_INVALID_TOKEN))
+    };
+
+    if reserve_in.is_zero() || reserve_out.is_zero() {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_LIQUIDITY));
+    }
+    let trader = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let amount_in = transfer_in(token_in, trader, amount_in);
+    if amount_in.is_zero() {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_INPUT_AMOUNT));
+    }
+    let amount_out = amount_out(amount_in, reserve_in, reserve_out);
+    if amount_out.is_zero() || amount_out < amount_out_min {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_OUTPUT_AMOUNT));
+    }
+
+    Erc20Ref::new(token_out).transfer(trader, amount_out);
+    sync_reserves(token_a, token_b);
+
+    runtime::ret(CLValue::from_t(amount_out).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_reserves() {
+    let reserves = (
+        read_reserve(RESERVE_A_KEY_NAME),
+        read_reserve(RESERVE_B_KEY_NAME),
+    );
+    runtime::ret(CLValue::from_t(reserves).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let token_a: ContractHash = runtime::get_named_arg(TOKEN_A_RUNTIME_ARG_NAME);
+    let token_b: ContractHash = runtime::get_named_arg(TOKEN_B_RUNTIME_ARG_NAME);
+    let name: String = runtime::get_named_arg(NAME_RUNTIME_ARG_NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
+    if token_a == token_b {
+        runtime::revert(Error::User(ERROR_IDENTICAL_TOKENS));
+    }
+    // Liquidity starts out as the geometric mean of both deposits, hence sits halfway between the
+    // decimals of the two tokens.
+    let decimals = ((u16::from(Erc20Ref::new(token_a).decimals())
+        + u16::from(Erc20Ref::new(token_b).decimals()))
+        / 2) as u8;
+
+    let mut entry_points = casper_erc20::entry_points::default();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(ADD_LIQUIDITY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT_A_DESIRED_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(AMOUNT_B_DESIRED_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(AMOUNT_A_MIN_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(AMOUNT_B_MIN_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(REMOVE_LIQUIDITY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(LIQUIDITY_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(AMOUNT_A_MIN_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(AMOUNT_B_MIN_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        <(U256, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(SWAP_EXACT_IN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_IN_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(AMOUNT_IN_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(AMOUNT_OUT_MIN_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(GET_RESERVES_ENTRY_POINT_NAME),
+        Vec::new(),
+        <(U256, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        TOKEN_A_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(token_a).into_read()),
+    );
+    named_keys.insert(
+        TOKEN_B_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(token_b).into_read()),
+    );
+    named_keys.insert(
+        RESERVE_A_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(U256::zero()).into_read_write()),
+    );
+    named_keys.insert(
+        RESERVE_B_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(U256::zero()).into_read_write()),
+    );
+
+    // No liquidity exists until the first deposit.
+    ERC20::builder(name, symbol, decimals, U256::zero())
+        .with_contract_key_name(ERC20_POOL_KEY_NAME)
+        .with_entry_points(entry_points)
+        .with_named_keys(named_keys)
+        .install()
+        .unwrap_or_revert();
+}
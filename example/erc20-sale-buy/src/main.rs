@@ -0,0 +1,46 @@
+//! Session code buying tokens from an `erc20-sale` contract.
+//!
+//! Moves the quoted cost from the account's main purse to a temporary purse, which is the only
+//! purse the sale contract gets access to.
+#![no_std]
+#![no_main]
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, URef, U256, U512};
+
+const BUY_ENTRY_POINT_NAME: &str = "buy";
+const QUOTE_ENTRY_POINT_NAME: &str = "quote";
+
+const SALE_CONTRACT_RUNTIME_ARG_NAME: &str = "sale_contract";
+const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
+const PURSE_RUNTIME_ARG_NAME: &str = "purse";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let sale_contract: ContractHash = runtime::get_named_arg(SALE_CONTRACT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    let cost: U512 = runtime::call_contract(
+        sale_contract,
+        QUOTE_ENTRY_POINT_NAME,
+        runtime_args! {
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+        },
+    );
+
+    let purse: URef = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), purse, cost, None)
+        .unwrap_or_revert();
+
+    runtime::call_contract::<()>(
+        sale_contract,
+        BUY_ENTRY_POINT_NAME,
+        runtime_args! {
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+            PURSE_RUNTIME_ARG_NAME => purse,
+        },
+    );
+}
@@ -0,0 +1,527 @@
+//! Token sale contract selling an ERC20 token for CSPR.
+//!
+//! Buyers pay in motes from a purse they pass to `buy`, usually a temporary purse created by the
+//! `erc20-sale-buy` session code, and claim their tokens once the sale ended. The price per whole
+//! token is either fixed or raised in tiers as more tokens are sold.
+//!
+//! If the soft cap is reached, the owner withdraws the proceeds and buyers claim their tokens,
+//! which are either transferred from the tokens funded to this contract or minted on the fly.
+//! Otherwise buyers get their motes refunded and the owner gets the funded tokens back.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{
+    constants::{ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME},
+    get_uref, Address, Erc20Ref, Error,
+};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    contracts::NamedKeys,
+    CLType, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Key, Parameter, URef, U256, U512,
+};
+
+const BUY_ENTRY_POINT_NAME: &str = "buy";
+const CLAIM_ENTRY_POINT_NAME: &str = "claim";
+const REFUND_ENTRY_POINT_NAME: &str = "refund";
+const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+const QUOTE_ENTRY_POINT_NAME: &str = "quote";
+const PURCHASED_OF_ENTRY_POINT_NAME: &str = "purchased_of";
+const TOTAL_SOLD_ENTRY_POINT_NAME: &str = "total_sold";
+const TOTAL_RAISED_ENTRY_POINT_NAME: &str = "total_raised";
+
+const PURSE_RUNTIME_ARG_NAME: &str = "purse";
+const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
+const START_TIME_RUNTIME_ARG_NAME: &str = "start_time";
+const END_TIME_RUNTIME_ARG_NAME: &str = "end_time";
+const PRICE_RUNTIME_ARG_NAME: &str = "price";
+const TIERS_RUNTIME_ARG_NAME: &str = "tiers";
+const HARD_CAP_RUNTIME_ARG_NAME: &str = "hard_cap";
+const SOFT_CAP_RUNTIME_ARG_NAME: &str = "soft_cap";
+const MAX_PER_BUYER_RUNTIME_ARG_NAME: &str = "max_per_buyer";
+const MINT_RUNTIME_ARG_NAME: &str = "mint";
+
+const TOKEN_CONTRACT_KEY_NAME: &str = "token_contract";
+const TOKEN_UNIT_KEY_NAME: &str = "token_unit";
+const OWNER_KEY_NAME: &str = "owner";
+const START_TIME_KEY_NAME: &str = "start_time";
+const END_TIME_KEY_NAME: &str = "end_time";
+const PRICE_KEY_NAME: &str = "price";
+const TIERS_KEY_NAME: &str = "tiers";
+const HARD_CAP_KEY_NAME: &str = "hard_cap";
+const SOFT_CAP_KEY_NAME: &str = "soft_cap";
+const MAX_PER_BUYER_KEY_NAME: &str = "max_per_buyer";
+const MINT_KEY_NAME: &str = "mint";
+const PURSE_KEY_NAME: &str = "purse";
+const TOTAL_SOLD_KEY_NAME: &str = "total_sold";
+const TOTAL_RAISED_KEY_NAME: &str = "total_raised";
+const UNCLAIMED_KEY_NAME: &str = "unclaimed";
+const PURCHASES_KEY_NAME: &str = "purchases";
+const CONTRIBUTIONS_KEY_NAME: &str = "contributions";
+const ERC20_SALE_KEY_NAME: &str = "erc20_sale";
+const ERC20_SALE_PACKAGE_KEY_NAME: &str = "erc20_sale_package";
+
+/// Sale ends before it starts, has no hard cap, or has tiers which are not in ascending order.
+const ERROR_INVALID_SALE: u16 = 1;
+/// Sale did not start yet, or already ended.
+const ERROR_SALE_NOT_ACTIVE: u16 = 2;
+/// Sale did not end yet.
+const ERROR_SALE_NOT_ENDED: u16 = 3;
+/// Amount to buy is zero.
+const ERROR_ZERO_AMOUNT: u16 = 4;
+/// Purchase would sell more tokens than the hard cap.
+const ERROR_HARD_CAP_EXCEEDED: u16 = 5;
+/// Purchase would take the buyer above the per-buyer limit.
+const ERROR_BUYER_LIMIT_EXCEEDED: u16 = 6;
+/// Purse passed to `buy` holds less motes than the purchase costs.
+const ERROR_INSUFFICIENT_PAYMENT: u16 = 7;
+/// Sale raised less than the soft cap, so tokens can't be claimed nor proceeds withdrawn.
+const ERROR_SOFT_CAP_NOT_MET: u16 = 8;
+/// Sale raised at least the soft cap, so there is nothing to refund.
+const ERROR_SOFT_CAP_MET: u16 = 9;
+/// Caller has no tokens left to claim.
+const ERROR_NOTHING_TO_CLAIM: u16 = 10;
+/// Caller has no motes left to refund.
+const ERROR_NOTHING_TO_REFUND: u16 = 11;
+/// Only accounts can buy, as refunds are paid back to the buyer's main purse.
+const ERROR_BUYER_NOT_ACCOUNT: u16 = 12;
+/// Tokens funded to this contract don't cover the purchase on top of the ones sold so far.
+const ERROR_INSUFFICIENT_TOKENS: u16 = 13;
+
+fn read_from<T: CLTyped + FromBytes>(name: &str) -> T {
+    storage::read(get_uref(name))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+fn write_to<T: CLTyped + ToBytes>(name: &str, value: T) {
+    storage::write(get_uref(name), value);
+}
+
+fn make_dictionary_item_key(address: Address) -> String {
+    let preimage = address.to_bytes().unwrap_or_revert();
+    base64::encode(&preimage)
+}
+
+fn read_entry<T: CLTyped + FromBytes + Default>(dictionary_name: &str, address: Address) -> T {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_get(get_uref(dictionary_name), &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write_entry<T: CLTyped + ToBytes>(dictionary_name: &str, address: Address, value: T) {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_put(get_uref(dictionary_name), &dictionary_item_key, value);
+}
+
+fn token() -> Erc20Ref {
+    Erc20Ref::new(read_from::<ContractHash>(TOKEN_CONTRACT_KEY_NAME))
+}
+
+fn now() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+/// Sale ends at its end time, or as soon as the hard cap is sold out.
+fn has_ended() -> bool {
+    now() >= read_from::<u64>(END_TIME_KEY_NAME)
+        || read_from::<U256>(TOTAL_SOLD_KEY_NAME) >= read_from::<U256>(HARD_CAP_KEY_NAME)
+}
+
+fn soft_cap_met() -> bool {
+    read_from::<U512>(TOTAL_RAISED_KEY_NAME) >= read_from::<U512>(SOFT_CAP_KEY_NAME)
+}
+
+fn to_u512(value: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    value.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+/// Returns the cost in motes of buying `amount` tokens once `sold` tokens were already sold.
+///
+/// Each tier's price applies from its threshold on, so a purchase crossing a threshold pays both
+/// prices. The total is rounded up in favor of the sale.
+fn cost_of(sold: U256, amount: U256) -> U512 {
+    let tiers: Vec<(U256, U512)> = read_from(TIERS_KEY_NAME);
+    let end = sold
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::Overflow);
+
+    let mut price: U512 = read_from(PRICE_KEY_NAME);
+    let mut position = sold;
+    let mut total = U512::zero();
+    for (threshold, tier_price) in tiers {
+        if threshold >= end {
+            break;
+        }
+        if threshold > position {
+            total = to_u512(threshold - position)
+                .checked_mul(price)
+                .and_then(|cost| cost.checked_add(total))
+                .unwrap_or_revert_with(Error::Overflow);
+            position = threshold;
+        }
+        price = tier_price;
+    }
+    total = to_u512(end - position)
+        .checked_mul(price)
+        .and_then(|cost| cost.checked_add(total))
+        .unwrap_or_revert_with(Error::Overflow);
+
+    let token_unit: U512 = read_from(TOKEN_UNIT_KEY_NAME);
+    let (quotient, remainder) = total.div_mod(token_unit);
+    if remainder.is_zero() {
+        quotient
+    } else {
+        quotient + 1
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn buy() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let purse: URef = runtime::get_named_arg(PURSE_RUNTIME_ARG_NAME);
+
+    let buyer = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    if buyer.as_account_hash().is_none() {
+        runtime::revert(Error::User(ERROR_BUYER_NOT_ACCOUNT));
+    }
+    if now() < read_from::<u64>(START_TIME_KEY_NAME) || has_ended() {
+        runtime::revert(Error::User(ERROR_SALE_NOT_ACTIVE));
+    }
+    if amount.is_zero() {
+        runtime::revert(Error::User(ERROR_ZERO_AMOUNT));
+    }
+
+    let sold: U256 = read_from(TOTAL_SOLD_KEY_NAME);
+    let new_sold = sold
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::Overflow);
+    if new_sold > read_from::<U256>(HARD_CAP_KEY_NAME) {
+        runtime::revert(Error::User(ERROR_HARD_CAP_EXCEEDED));
+    }
+    let purchased = read_entry::<U256>(PURCHASES_KEY_NAME, buyer)
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::Overflow);
+    if let Some(max_per_buyer) = read_from::<Option<U256>>(MAX_PER_BUYER_KEY_NAME) {
+        if purchased > max_per_buyer {
+            runtime::revert(Error::User(ERROR_BUYER_LIMIT_EXCEEDED));
+        }
+    }
+    let unclaimed = read_from::<U256>(UNCLAIMED_KEY_NAME)
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::Overflow);
+    // Without minting, every token sold has to be funded to this contract before it is bought.
+    if !read_from::<bool>(MINT_KEY_NAME)
+        && unclaimed > token().balance_of(casper_erc20::self_package_address())
+    {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_TOKENS));
+    }
+
+    let cost = cost_of(sold, amount);
+    if system::get_purse_balance(purse).unwrap_or_revert() < cost {
+        runtime::revert(Error::User(ERROR_INSUFFICIENT_PAYMENT));
+    }
+    system::transfer_from_purse_to_purse(purse, get_uref(PURSE_KEY_NAME), cost, None)
+        .unwrap_or_revert();
+
+    let contributed = read_entry::<U512>(CONTRIBUTIONS_KEY_NAME, buyer)
+        .checked_add(cost)
+        .unwrap_or_revert_with(Error::Overflow);
+    let raised = read_from::<U512>(TOTAL_RAISED_KEY_NAME)
+        .checked_add(cost)
+        .unwrap_or_revert_with(Error::Overflow);
+    write_entry(PURCHASES_KEY_NAME, buyer, purchased);
+    write_entry(CONTRIBUTIONS_KEY_NAME, buyer, contributed);
+    write_to(TOTAL_SOLD_KEY_NAME, new_sold);
+    write_to(TOTAL_RAISED_KEY_NAME, raised);
+    write_to(UNCLAIMED_KEY_NAME, unclaimed);
+}
+
+#[no_mangle]
+pub extern "C" fn claim() {
+    if !has_ended() {
+        runtime::revert(Error::User(ERROR_SALE_NOT_ENDED));
+    }
+    if !soft_cap_met() {
+        runtime::revert(Error::User(ERROR_SOFT_CAP_NOT_MET));
+    }
+
+    let buyer = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = read_entry(PURCHASES_KEY_NAME, buyer);
+    if amount.is_zero() {
+        runtime::revert(Error::User(ERROR_NOTHING_TO_CLAIM));
+    }
+    write_entry(PURCHASES_KEY_NAME, buyer, U256::zero());
+    write_to(
+        UNCLAIMED_KEY_NAME,
+        read_from::<U256>(UNCLAIMED_KEY_NAME) - amount,
+    );
+
+    if read_from::<bool>(MINT_KEY_NAME) {
+        // Token is expected to let this contract's package hash mint, e.g. as a minter role.
+        token().mint(buyer, amount);
+    } else {
+        token().transfer(buyer, amount);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn refund() {
+    if !has_ended() {
+        runtime::revert(Error::User(ERROR_SALE_NOT_ENDED));
+    }
+    if soft_cap_met() {
+        runtime::revert(Error::User(ERROR_SOFT_CAP_MET));
+    }
+
+    let buyer = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let contributed: U512 = read_entry(CONTRIBUTIONS_KEY_NAME, buyer);
+    if contributed.is_zero() {
+        runtime::revert(Error::User(ERROR_NOTHING_TO_REFUND));
+    }
+    write_entry(CONTRIBUTIONS_KEY_NAME, buyer, U512::zero());
+
+    // Only accounts are able to buy, so every contribution belongs to an account.
+    let account_hash = *buyer.as_account_hash().unwrap_or_revert();
+    system::transfer_from_purse_to_account(
+        get_uref(PURSE_KEY_NAME),
+        account_hash,
+        contributed,
+        None,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    let caller = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let owner: Address = read_from(OWNER_KEY_NAME);
+    if caller != owner {
+        runtime::revert(Error::PermissionDenied);
+    }
+    if !has_ended() {
+        runtime::revert(Error::User(ERROR_SALE_NOT_ENDED));
+    }
+
+    // Tokens sold are kept for their buyers, unless they are about to be refunded.
+    let reserved = if soft_cap_met() {
+        let purse = get_uref(PURSE_KEY_NAME);
+        let proceeds = system::get_purse_balance(purse).unwrap_or_revert();
+        if !proceeds.is_zero() {
+            let account_hash = *owner.as_account_hash().unwrap_or_revert();
+            system::transfer_from_purse_to_account(purse, account_hash, proceeds, None)
+                .unwrap_or_revert();
+        }
+        read_from(UNCLAIMED_KEY_NAME)
+    } else {
+        U256::zero()
+    };
+
+    if !read_from::<bool>(MINT_KEY_NAME) {
+        let token = token();
+        let unsold = token
+            .balance_of(casper_erc20::self_package_address())
+            .saturating_sub(reserved);
+        if !unsold.is_zero() {
+            token.transfer(owner, unsold);
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn quote() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let cost = cost_of(read_from(TOTAL_SOLD_KEY_NAME), amount);
+    runtime::ret(CLValue::from_t(cost).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn purchased_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let purchased: U256 = read_entry(PURCHASES_KEY_NAME, address);
+    runtime::ret(CLValue::from_t(purchased).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_sold() {
+    let total_sold: U256 = read_from(TOTAL_SOLD_KEY_NAME);
+    runtime::ret(CLValue::from_t(total_sold).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_raised() {
+    let total_raised: U512 = read_from(TOTAL_RAISED_KEY_NAME);
+    runtime::ret(CLValue::from_t(total_raised).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let start_time: u64 = runtime::get_named_arg(START_TIME_RUNTIME_ARG_NAME);
+    let end_time: u64 = runtime::get_named_arg(END_TIME_RUNTIME_ARG_NAME);
+    // Price in motes per whole token, i.e. per 10^decimals of the token's smallest unit.
+    let price: U512 = runtime::get_named_arg(PRICE_RUNTIME_ARG_NAME);
+    // Pairs of tokens sold from which on a new price applies, empty for a fixed price.
+    let tiers: Vec<(U256, U512)> = runtime::get_named_arg(TIERS_RUNTIME_ARG_NAME);
+    let hard_cap: U256 = runtime::get_named_arg(HARD_CAP_RUNTIME_ARG_NAME);
+    let soft_cap: U512 = runtime::get_named_arg(SOFT_CAP_RUNTIME_ARG_NAME);
+    let max_per_buyer: Option<U256> = runtime::get_named_arg(MAX_PER_BUYER_RUNTIME_ARG_NAME);
+    let mint: bool = runtime::get_named_arg(MINT_RUNTIME_ARG_NAME);
+
+    let tiers_ascending = tiers.windows(2).all(|window| window[0].0 < window[1].0);
+    if end_time <= start_time || hard_cap.is_zero() || !tiers_ascending {
+        runtime::revert(Error::User(ERROR_INVALID_SALE));
+    }
+
+    let decimals = Erc20Ref::new(token_contract).decimals();
+    let token_unit = U512::from(10u64)
+        .checked_pow(U512::from(decimals))
+        .unwrap_or_revert_with(Error::Overflow);
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(BUY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(PURSE_RUNTIME_ARG_NAME, URef::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    for entry_point_name in &[
+        CLAIM_ENTRY_POINT_NAME,
+        REFUND_ENTRY_POINT_NAME,
+        WITHDRAW_ENTRY_POINT_NAME,
+    ] {
+        entry_points.add_entry_point(EntryPoint::new(
+            String::from(*entry_point_name),
+            Vec::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+    }
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(QUOTE_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(PURCHASED_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(TOTAL_SOLD_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(TOTAL_RAISED_ENTRY_POINT_NAME),
+        Vec::new(),
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    for dictionary_name in &[PURCHASES_KEY_NAME, CONTRIBUTIONS_KEY_NAME] {
+        let dictionary_uref = storage::new_dictionary(dictionary_name).unwrap_or_revert();
+        runtime::remove_key(dictionary_name);
+        named_keys.insert(dictionary_name.to_string(), Key::from(dictionary_uref));
+    }
+
+    // Whoever installs the contract is the only one allowed to withdraw the proceeds.
+    let owner = Address::from(runtime::get_caller());
+
+    named_keys.insert(
+        TOKEN_CONTRACT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(token_contract).into_read()),
+    );
+    named_keys.insert(
+        TOKEN_UNIT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(token_unit).into_read()),
+    );
+    named_keys.insert(
+        OWNER_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(owner).into_read()),
+    );
+    named_keys.insert(
+        START_TIME_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(start_time).into_read()),
+    );
+    named_keys.insert(
+        END_TIME_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(end_time).into_read()),
+    );
+    named_keys.insert(
+        PRICE_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(price).into_read()),
+    );
+    named_keys.insert(
+        TIERS_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(tiers).into_read()),
+    );
+    named_keys.insert(
+        HARD_CAP_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(hard_cap).into_read()),
+    );
+    named_keys.insert(
+        SOFT_CAP_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(soft_cap).into_read()),
+    );
+    named_keys.insert(
+        MAX_PER_BUYER_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(max_per_buyer).into_read()),
+    );
+    named_keys.insert(
+        MINT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(mint).into_read()),
+    );
+    named_keys.insert(
+        PURSE_KEY_NAME.to_string(),
+        Key::from(system::create_purse()),
+    );
+    named_keys.insert(
+        TOTAL_SOLD_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(U256::zero()).into_read_write()),
+    );
+    named_keys.insert(
+        TOTAL_RAISED_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(U512::zero()).into_read_write()),
+    );
+    named_keys.insert(
+        UNCLAIMED_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(U256::zero()).into_read_write()),
+    );
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(ERC20_SALE_PACKAGE_KEY_NAME.to_string()),
+        None,
+    );
+    runtime::put_key(ERC20_SALE_KEY_NAME, Key::from(contract_hash));
+}
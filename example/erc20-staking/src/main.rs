@@ -0,0 +1,432 @@
+//! Staking contract which pays an ERC20 reward token to holders staking another ERC20 token.
+//!
+//! Rewards notified by the owner are streamed at a constant rate over the rewards duration and
+//! shared between stakers in proportion to their stake, in the style of Synthetix'
+//! `StakingRewards`.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{
+    constants::{ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME},
+    get_uref, Address, Erc20Ref, Error,
+};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    contracts::NamedKeys,
+    CLType, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Key, Parameter, U256,
+};
+
+const STAKE_ENTRY_POINT_NAME: &str = "stake";
+const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+const GET_REWARD_ENTRY_POINT_NAME: &str = "get_reward";
+const EXIT_ENTRY_POINT_NAME: &str = "exit";
+const NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME: &str = "notify_reward_amount";
+const REWARD_PER_TOKEN_ENTRY_POINT_NAME: &str = "reward_per_token";
+const EARNED_ENTRY_POINT_NAME: &str = "earned";
+const STAKED_BALANCE_OF_ENTRY_POINT_NAME: &str = "staked_balance_of";
+const TOTAL_STAKED_ENTRY_POINT_NAME: &str = "total_staked";
+
+const STAKING_TOKEN_RUNTIME_ARG_NAME: &str = "staking_token";
+const REWARD_TOKEN_RUNTIME_ARG_NAME: &str = "reward_token";
+const REWARDS_DURATION_RUNTIME_ARG_NAME: &str = "rewards_duration";
+
+const STAKING_TOKEN_KEY_NAME: &str = "staking_token";
+const REWARD_TOKEN_KEY_NAME: &str = "reward_token";
+const OWNER_KEY_NAME: &str = "owner";
+const REWARDS_DURATION_KEY_NAME: &str = "rewards_duration";
+const REWARD_RATE_KEY_NAME: &str = "reward_rate";
+const PERIOD_FINISH_KEY_NAME: &str = "period_finish";
+const LAST_UPDATE_TIME_KEY_NAME: &str = "last_update_time";
+const REWARD_PER_TOKEN_STORED_KEY_NAME: &str = "reward_per_token_stored";
+const TOTAL_STAKED_KEY_NAME: &str = "total_staked";
+const BALANCES_KEY_NAME: &str = "balances";
+const USER_REWARD_PER_TOKEN_PAID_KEY_NAME: &str = "user_reward_per_token_paid";
+const REWARDS_KEY_NAME: &str = "rewards";
+const ERC20_STAKING_KEY_NAME: &str = "erc20_staking";
+const ERC20_STAKING_PACKAGE_KEY_NAME: &str = "erc20_staking_package";
+
+/// Reward per token is scaled up by this factor, so that small rewards shared by a large stake
+/// don't round down to zero.
+const REWARD_PER_TOKEN_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Amount to stake, withdraw or notify is zero.
+const ERROR_ZERO_AMOUNT: u16 = 1;
+/// Rewards duration set at install is zero.
+const ERROR_INVALID_DURATION: u16 = 2;
+/// Notified reward is too small to be paid at a non-zero rate over the rewards duration.
+const ERROR_REWARD_TOO_LOW: u16 = 3;
+
+fn read_from<T: CLTyped + FromBytes>(name: &str) -> T {
+    storage::read(get_uref(name))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+fn write_to<T: CLTyped + ToBytes>(name: &str, value: T) {
+    storage::write(get_uref(name), value);
+}
+
+fn make_dictionary_item_key(address: Address) -> String {
+    let preimage = address.to_bytes().unwrap_or_revert();
+    base64::encode(&preimage)
+}
+
+fn read_amount(dictionary_name: &str, address: Address) -> U256 {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_get(get_uref(dictionary_name), &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write_amount(dictionary_name: &str, address: Address, amount: U256) {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_put(get_uref(dictionary_name), &dictionary_item_key, amount);
+}
+
+fn token(key_name: &str) -> Erc20Ref {
+    Erc20Ref::new(read_from::<ContractHash>(key_name))
+}
+
+fn now() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+fn last_time_reward_applicable() -> u64 {
+    let period_finish: u64 = read_from(PERIOD_FINISH_KEY_NAME);
+    now().min(period_finish)
+}
+
+fn reward_per_token_at(time: u64) -> U256 {
+    let stored: U256 = read_from(REWARD_PER_TOKEN_STORED_KEY_NAME);
+    let total_staked: U256 = read_from(TOTAL_STAKED_KEY_NAME);
+    if total_staked.is_zero() {
+        return stored;
+    }
+    let last_update_time: u64 = read_from(LAST_UPDATE_TIME_KEY_NAME);
+    let reward_rate: U256 = read_from(REWARD_RATE_KEY_NAME);
+    // Time only moves on, and the last update never happens after the period finishes.
+    let accrued = U256::from(time - last_update_time)
+        .checked_mul(reward_rate)
+        .and_then(|reward| reward.checked_mul(U256::from(REWARD_PER_TOKEN_SCALE)))
+        .unwrap_or_revert_with(Error::Overflow);
+    stored + accrued / total_staked
+}
+
+fn earned_with(address: Address, reward_per_token: U256) -> U256 {
+    let balance = read_amount(BALANCES_KEY_NAME, address);
+    let paid = read_amount(USER_REWARD_PER_TOKEN_PAID_KEY_NAME, address);
+    let accrued = balance
+        .checked_mul(reward_per_token - paid)
+        .unwrap_or_revert_with(Error::Overflow)
+        / U256::from(REWARD_PER_TOKEN_SCALE);
+    read_amount(REWARDS_KEY_NAME, address) + accrued
+}
+
+/// Settles the rewards accrued so far, globally and for `address` if there is one.
+fn update_reward(address: Option<Address>) {
+    let time = last_time_reward_applicable();
+    let reward_per_token = reward_per_token_at(time);
+    write_to(REWARD_PER_TOKEN_STORED_KEY_NAME, reward_per_token);
+    write_to(LAST_UPDATE_TIME_KEY_NAME, time);
+    if let Some(address) = address {
+        write_amount(
+            REWARDS_KEY_NAME,
+            address,
+            earned_with(address, reward_per_token),
+        );
+        write_amount(
+            USER_REWARD_PER_TOKEN_PAID_KEY_NAME,
+            address,
+            reward_per_token,
+        );
+    }
+}
+
+fn withdraw_stake(staker: Address, amount: U256) {
+    if amount.is_zero() {
+        runtime::revert(Error::User(ERROR_ZERO_AMOUNT));
+    }
+    let balance = read_amount(BALANCES_KEY_NAME, staker)
+        .checked_sub(amount)
+        .unwrap_or_revert_with(Error::InsufficientBalance);
+    let total_staked: U256 = read_from(TOTAL_STAKED_KEY_NAME);
+    write_amount(BALANCES_KEY_NAME, staker, balance);
+    write_to(TOTAL_STAKED_KEY_NAME, total_staked - amount);
+
+    token(STAKING_TOKEN_KEY_NAME).transfer(staker, amount);
+}
+
+fn pay_reward(staker: Address) {
+    let reward = read_amount(REWARDS_KEY_NAME, staker);
+    if !reward.is_zero() {
+        write_amount(REWARDS_KEY_NAME, staker, U256::zero());
+        token(REWARD_TOKEN_KEY_NAME).transfer(staker, reward);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn stake() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    if amount.is_zero() {
+        runtime::revert(Error::User(ERROR_ZERO_AMOUNT));
+    }
+
+    let staker = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    update_reward(Some(staker));
+
+    let balance = read_amount(BALANCES_KEY_NAME, staker);
+    let total_staked: U256 = read_from(TOTAL_STAKED_KEY_NAME);
+    let new_total_staked = total_staked
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::Overflow);
+    // No balance exceeds the total staked, so this can't overflow either.
+    write_amount(BALANCES_KEY_NAME, staker, balance + amount);
+    write_to(TOTAL_STAKED_KEY_NAME, new_total_staked);
+
+    // Staker is expected to approve this contract's package hash for at least `amount` tokens.
+    token(STAKING_TOKEN_KEY_NAME).transfer_from(
+        staker,
+        casper_erc20::self_package_address(),
+        amount,
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let staker = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    update_reward(Some(staker));
+    withdraw_stake(staker, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn get_reward() {
+    let staker = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    update_reward(Some(staker));
+    pay_reward(staker);
+}
+
+#[no_mangle]
+pub extern "C" fn exit() {
+    let staker = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    update_reward(Some(staker));
+    withdraw_stake(staker, read_amount(BALANCES_KEY_NAME, staker));
+    pay_reward(staker);
+}
+
+#[no_mangle]
+pub extern "C" fn notify_reward_amount() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    let caller = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let owner: Address = read_from(OWNER_KEY_NAME);
+    if caller != owner {
+        runtime::revert(Error::PermissionDenied);
+    }
+    if amount.is_zero() {
+        runtime::revert(Error::User(ERROR_ZERO_AMOUNT));
+    }
+
+    update_reward(None);
+
+    // Whatever is left of the current period is paid out over the new one.
+    let now = now();
+    let period_finish: u64 = read_from(PERIOD_FINISH_KEY_NAME);
+    let rewards_duration: u64 = read_from(REWARDS_DURATION_KEY_NAME);
+    let reward = if now < period_finish {
+        let reward_rate: U256 = read_from(REWARD_RATE_KEY_NAME);
+        let leftover = U256::from(period_finish - now)
+            .checked_mul(reward_rate)
+            .unwrap_or_revert_with(Error::Overflow);
+        amount
+            .checked_add(leftover)
+            .unwrap_or_revert_with(Error::Overflow)
+    } else {
+        amount
+    };
+    let reward_rate = reward / U256::from(rewards_duration);
+    if reward_rate.is_zero() {
+        runtime::revert(Error::User(ERROR_REWARD_TOO_LOW));
+    }
+
+    write_to(REWARD_RATE_KEY_NAME, reward_rate);
+    write_to(LAST_UPDATE_TIME_KEY_NAME, now);
+    write_to(PERIOD_FINISH_KEY_NAME, now + rewards_duration);
+
+    // Owner is expected to approve this contract's package hash for at least `amount` tokens.
+    token(REWARD_TOKEN_KEY_NAME).transfer_from(owner, casper_erc20::self_package_address(), amount);
+}
+
+#[no_mangle]
+pub extern "C" fn reward_per_token() {
+    let reward_per_token = reward_per_token_at(last_time_reward_applicable());
+    runtime::ret(CLValue::from_t(reward_per_token).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn earned() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let reward_per_token = reward_per_token_at(last_time_reward_applicable());
+    let earned = earned_with(address, reward_per_token);
+    runtime::ret(CLValue::from_t(earned).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn staked_balance_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let balance = read_amount(BALANCES_KEY_NAME, address);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_staked() {
+    let total_staked: U256 = read_from(TOTAL_STAKED_KEY_NAME);
+    runtime::ret(CLValue::from_t(total_staked).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let staking_token: ContractHash = runtime::get_named_arg(STAKING_TOKEN_RUNTIME_ARG_NAME);
+    let reward_token: ContractHash = runtime::get_named_arg(REWARD_TOKEN_RUNTIME_ARG_NAME);
+    let rewards_duration: u64 = runtime::get_named_arg(REWARDS_DURATION_RUNTIME_ARG_NAME);
+    if rewards_duration == 0 {
+        runtime::revert(Error::User(ERROR_INVALID_DURATION));
+    }
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(STAKE_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(WITHDRAW_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(GET_REWARD_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(EXIT_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(REWARD_PER_TOKEN_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(EARNED_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(STAKED_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(TOTAL_STAKED_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    for dictionary_name in &[
+        BALANCES_KEY_NAME,
+        USER_REWARD_PER_TOKEN_PAID_KEY_NAME,
+        REWARDS_KEY_NAME,
+    ] {
+        let dictionary_uref = storage::new_dictionary(dictionary_name).unwrap_or_revert();
+        runtime::remove_key(dictionary_name);
+        named_keys.insert(dictionary_name.to_string(), Key::from(dictionary_uref));
+    }
+
+    // Whoever installs the contract is the only one allowed to notify rewards.
+    let owner = Address::from(runtime::get_caller());
+
+    named_keys.insert(
+        STAKING_TOKEN_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(staking_token).into_read()),
+    );
+    named_keys.insert(
+        REWARD_TOKEN_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(reward_token).into_read()),
+    );
+    named_keys.insert(
+        OWNER_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(owner).into_read()),
+    );
+    named_keys.insert(
+        REWARDS_DURATION_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(rewards_duration).into_read()),
+    );
+    named_keys.insert(
+        REWARD_RATE_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(U256::zero()).into_read_write()),
+    );
+    named_keys.insert(
+        PERIOD_FINISH_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(0u64).into_read_write()),
+    );
+    named_keys.insert(
+        LAST_UPDATE_TIME_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(0u64).into_read_write()),
+    );
+    named_keys.insert(
+        REWARD_PER_TOKEN_STORED_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(U256::zero()).into_read_write()),
+    );
+    named_keys.insert(
+        TOTAL_STAKED_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(U256::zero()).into_read_write()),
+    );
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(ERC20_STAKING_PACKAGE_KEY_NAME.to_string()),
+        None,
+    );
+    runtime::put_key(ERC20_STAKING_KEY_NAME, Key::from(contract_hash));
+}
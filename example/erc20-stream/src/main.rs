@@ -0,0 +1,262 @@
+//! Payment streaming contract releasing ERC20 tokens to a recipient continuously over time.
+//!
+//! A sender deposits tokens for a recipient when creating a stream, and the deposit accrues to
+//! the recipient linearly between the start and the stop time. The recipient withdraws whatever
+//! accrued so far, and either party can cancel the stream, which pays the accrued part to the
+//! recipient and the rest back to the sender.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+mod stream;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{
+    constants::{AMOUNT_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME},
+    get_uref, Address, Erc20Ref, Error,
+};
+use casper_types::{
+    contracts::NamedKeys, CLType, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, U256,
+};
+
+use stream::Stream;
+
+const CREATE_STREAM_ENTRY_POINT_NAME: &str = "create_stream";
+const WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME: &str = "withdraw_from_stream";
+const CANCEL_STREAM_ENTRY_POINT_NAME: &str = "cancel_stream";
+const WITHDRAWABLE_ENTRY_POINT_NAME: &str = "withdrawable";
+
+const STREAM_ID_RUNTIME_ARG_NAME: &str = "stream_id";
+const TOKEN_RUNTIME_ARG_NAME: &str = "token";
+const START_TIME_RUNTIME_ARG_NAME: &str = "start_time";
+const STOP_TIME_RUNTIME_ARG_NAME: &str = "stop_time";
+
+const STREAMS_KEY_NAME: &str = "streams";
+const STREAM_COUNT_KEY_NAME: &str = "stream_count";
+const ERC20_STREAM_KEY_NAME: &str = "erc20_stream";
+const ERC20_STREAM_PACKAGE_KEY_NAME: &str = "erc20_stream_package";
+
+/// Stream has no tokens, streams to its sender, starts in the past or stops before it starts.
+const ERROR_INVALID_STREAM: u16 = 1;
+/// There is no stream with the given id.
+const ERROR_STREAM_NOT_FOUND: u16 = 2;
+/// Stream was already cancelled.
+const ERROR_STREAM_CANCELLED: u16 = 3;
+/// Only the recipient of a stream is able to withdraw from it.
+const ERROR_NOT_RECIPIENT: u16 = 4;
+/// Only the sender or the recipient of a stream are able to cancel it.
+const ERROR_NOT_STREAM_PARTY: u16 = 5;
+/// Nothing accrued to the recipient since the last withdrawal.
+const ERROR_NOTHING_TO_WITHDRAW: u16 = 6;
+
+fn read_stream(stream_id: u64) -> Option<Stream> {
+    storage::dictionary_get(get_uref(STREAMS_KEY_NAME), &stream_id.to_string()).unwrap_or_revert()
+}
+
+/// Returns the stream with the given id, reverting if it doesn't exist or was cancelled.
+fn read_active_stream(stream_id: u64) -> Stream {
+    let stream = read_stream(stream_id).unwrap_or_revert_with(Error::User(ERROR_STREAM_NOT_FOUND));
+    if stream.cancelled {
+        runtime::revert(Error::User(ERROR_STREAM_CANCELLED));
+    }
+    stream
+}
+
+fn write_stream(stream_id: u64, stream: Stream) {
+    storage::dictionary_put(get_uref(STREAMS_KEY_NAME), &stream_id.to_string(), stream);
+}
+
+fn now() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+/// Pulls `amount` of `token` from `owner` into this contract, and returns how many tokens the
+/// contract was actually credited, which is less than `amount` for tokens charging a fee or
+/// burning on transfer.
+fn deposit(token: ContractHash, owner: Address, amount: U256) -> U256 {
+    let token = Erc20Ref::new(token);
+    let stream_address = casper_erc20::self_package_address();
+    let balance_before = token.balance_of(stream_address);
+    token.transfer_from(owner, stream_address, amount);
+    token
+        .balance_of(stream_address)
+        .saturating_sub(balance_before)
+}
+
+/// Returns the tokens accrued to the recipient and not withdrawn yet.
+fn withdrawable_amount(stream: &Stream) -> U256 {
+    let accrued = stream
+        .accrued_at(now())
+        .unwrap_or_revert_with(Error::Overflow);
+    accrued - stream.withdrawn
+}
+
+#[no_mangle]
+pub extern "C" fn create_stream() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let token: ContractHash = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let start_time: u64 = runtime::get_named_arg(START_TIME_RUNTIME_ARG_NAME);
+    let stop_time: u64 = runtime::get_named_arg(STOP_TIME_RUNTIME_ARG_NAME);
+
+    let sender = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    if amount.is_zero() || recipient == sender || start_time < now() || stop_time <= start_time {
+        runtime::revert(Error::User(ERROR_INVALID_STREAM));
+    }
+
+    let stream_count_uref = get_uref(STREAM_COUNT_KEY_NAME);
+    let stream_id: u64 = storage::read(stream_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    storage::write(
+        stream_count_uref,
+        stream_id
+            .checked_add(1)
+            .unwrap_or_revert_with(Error::Overflow),
+    );
+
+    // Sender is expected to approve this contract's package hash for at least `amount` tokens.
+    // Only what this contract received is streamed, so that the stream can't be paid out of the
+    // deposits of other streams of the same token.
+    let deposit = deposit(token, sender, amount);
+    if deposit.is_zero() {
+        runtime::revert(Error::User(ERROR_INVALID_STREAM));
+    }
+
+    let stream = Stream {
+        sender,
+        recipient,
+        token,
+        deposit,
+        start_time,
+        stop_time,
+        withdrawn: U256::zero(),
+        cancelled: false,
+    };
+    write_stream(stream_id, stream);
+
+    runtime::ret(CLValue::from_t(stream_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw_from_stream() {
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID_RUNTIME_ARG_NAME);
+    let mut stream = read_active_stream(stream_id);
+
+    let caller = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    if caller != stream.recipient {
+        runtime::revert(Error::User(ERROR_NOT_RECIPIENT));
+    }
+    let amount = withdrawable_amount(&stream);
+    if amount.is_zero() {
+        runtime::revert(Error::User(ERROR_NOTHING_TO_WITHDRAW));
+    }
+
+    stream.withdrawn += amount;
+    write_stream(stream_id, stream.clone());
+
+    Erc20Ref::new(stream.token).transfer(stream.recipient, amount);
+
+    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_stream() {
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID_RUNTIME_ARG_NAME);
+    let mut stream = read_active_stream(stream_id);
+
+    let caller = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    if caller != stream.sender && caller != stream.recipient {
+        runtime::revert(Error::User(ERROR_NOT_STREAM_PARTY));
+    }
+    let recipient_amount = withdrawable_amount(&stream);
+    let sender_amount = stream.deposit - stream.withdrawn - recipient_amount;
+
+    stream.withdrawn += recipient_amount;
+    stream.cancelled = true;
+    write_stream(stream_id, stream.clone());
+
+    let token = Erc20Ref::new(stream.token);
+    if !recipient_amount.is_zero() {
+        token.transfer(stream.recipient, recipient_amount);
+    }
+    if !sender_amount.is_zero() {
+        token.transfer(stream.sender, sender_amount);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn withdrawable() {
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID_RUNTIME_ARG_NAME);
+    let withdrawable = read_stream(stream_id)
+        .filter(|stream| !stream.cancelled)
+        .map_or_else(U256::zero, |stream| withdrawable_amount(&stream));
+    runtime::ret(CLValue::from_t(withdrawable).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(CREATE_STREAM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(START_TIME_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(STOP_TIME_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME),
+        vec![Parameter::new(STREAM_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(CANCEL_STREAM_ENTRY_POINT_NAME),
+        vec![Parameter::new(STREAM_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(WITHDRAWABLE_ENTRY_POINT_NAME),
+        vec![Parameter::new(STREAM_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let streams_uref = storage::new_dictionary(STREAMS_KEY_NAME).unwrap_or_revert();
+    runtime::remove_key(STREAMS_KEY_NAME);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(STREAMS_KEY_NAME.to_string(), Key::from(streams_uref));
+    named_keys.insert(
+        STREAM_COUNT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(0u64).into_read_write()),
+    );
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(ERC20_STREAM_PACKAGE_KEY_NAME.to_string()),
+        None,
+    );
+    runtime::put_key(ERC20_STREAM_KEY_NAME, Key::from(contract_hash));
+}
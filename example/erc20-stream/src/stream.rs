@@ -0,0 +1,104 @@
+//! Stream of tokens paid by a sender to a recipient over time.
+use alloc::vec::Vec;
+
+use casper_erc20::Address;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, ContractHash, U256,
+};
+
+/// Stream releasing `deposit` of `token` to the recipient linearly between `start_time` and
+/// `stop_time`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Stream {
+    /// Address which deposited the tokens, and gets the unstreamed part back on cancel.
+    pub sender: Address,
+    /// Address the tokens are streamed to.
+    pub recipient: Address,
+    /// Token streamed.
+    pub token: ContractHash,
+    /// Amount of tokens deposited by the sender.
+    pub deposit: U256,
+    /// Block time from which tokens start accruing to the recipient.
+    pub start_time: u64,
+    /// Block time at which the whole deposit has accrued to the recipient.
+    pub stop_time: u64,
+    /// Amount of tokens already withdrawn by the recipient.
+    pub withdrawn: U256,
+    /// Whether the stream was cancelled.
+    pub cancelled: bool,
+}
+
+impl Stream {
+    /// Returns the amount of tokens accrued to the recipient by `now`, withdrawn or not, or
+    /// `None` on overflow.
+    pub fn accrued_at(&self, now: u64) -> Option<U256> {
+        if now <= self.start_time {
+            Some(U256::zero())
+        } else if now >= self.stop_time {
+            Some(self.deposit)
+        } else {
+            let elapsed = U256::from(now - self.start_time);
+            let duration = U256::from(self.stop_time - self.start_time);
+            self.deposit
+                .checked_mul(elapsed)
+                .map(|scaled| scaled / duration)
+        }
+    }
+}
+
+impl CLTyped for Stream {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Stream {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.sender.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.token.to_bytes()?);
+        result.append(&mut self.deposit.to_bytes()?);
+        result.append(&mut self.start_time.to_bytes()?);
+        result.append(&mut self.stop_time.to_bytes()?);
+        result.append(&mut self.withdrawn.to_bytes()?);
+        result.append(&mut self.cancelled.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.sender.serialized_length()
+            + self.recipient.serialized_length()
+            + self.token.serialized_length()
+            + self.deposit.serialized_length()
+            + self.start_time.serialized_length()
+            + self.stop_time.serialized_length()
+            + self.withdrawn.serialized_length()
+            + self.cancelled.serialized_length()
+    }
+}
+
+impl FromBytes for Stream {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (sender, remainder) = Address::from_bytes(bytes)?;
+        let (recipient, remainder) = Address::from_bytes(remainder)?;
+        let (token, remainder) = ContractHash::from_bytes(remainder)?;
+        let (deposit, remainder) = U256::from_bytes(remainder)?;
+        let (start_time, remainder) = u64::from_bytes(remainder)?;
+        let (stop_time, remainder) = u64::from_bytes(remainder)?;
+        let (withdrawn, remainder) = U256::from_bytes(remainder)?;
+        let (cancelled, remainder) = bool::from_bytes(remainder)?;
+        let stream = Stream {
+            sender,
+            recipient,
+            token,
+            deposit,
+            start_time,
+            stop_time,
+            withdrawn,
+            cancelled,
+        };
+        Ok((stream, remainder))
+    }
+}
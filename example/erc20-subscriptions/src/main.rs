@@ -0,0 +1,228 @@
+//! Subscription contract letting merchants pull a fixed amount of an ERC20 token per period.
+//!
+//! A subscriber registers the merchant, the amount and the period, and approves this contract's
+//! package hash for the tokens it agrees to pay. The merchant then collects each elapsed period
+//! once through `transfer_from`, until the subscriber cancels.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+mod subscription;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{constants::AMOUNT_RUNTIME_ARG_NAME, get_uref, Address, Erc20Ref, Error};
+use casper_types::{
+    bytesrepr::ToBytes, contracts::NamedKeys, CLType, CLTyped, CLValue, ContractHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter, U256,
+};
+
+use subscription::Subscription;
+
+const SUBSCRIBE_ENTRY_POINT_NAME: &str = "subscribe";
+const CANCEL_ENTRY_POINT_NAME: &str = "cancel";
+const COLLECT_ENTRY_POINT_NAME: &str = "collect";
+const NEXT_COLLECTION_ENTRY_POINT_NAME: &str = "next_collection";
+
+const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
+const MERCHANT_RUNTIME_ARG_NAME: &str = "merchant";
+const SUBSCRIBER_RUNTIME_ARG_NAME: &str = "subscriber";
+const PERIOD_RUNTIME_ARG_NAME: &str = "period";
+
+const TOKEN_CONTRACT_KEY_NAME: &str = "token_contract";
+const SUBSCRIPTIONS_KEY_NAME: &str = "subscriptions";
+const ERC20_SUBSCRIPTIONS_KEY_NAME: &str = "erc20_subscriptions";
+const ERC20_SUBSCRIPTIONS_PACKAGE_KEY_NAME: &str = "erc20_subscriptions_package";
+
+/// Subscription has a zero amount or period, or a subscriber paying itself.
+const ERROR_INVALID_SUBSCRIPTION: u16 = 1;
+/// Subscriber already has an active subscription to the merchant.
+const ERROR_ALREADY_SUBSCRIBED: u16 = 2;
+/// There is no active subscription between the subscriber and the merchant.
+const ERROR_SUBSCRIPTION_NOT_FOUND: u16 = 3;
+/// Current period was already collected.
+const ERROR_PERIOD_NOT_ELAPSED: u16 = 4;
+
+/// Creates a dictionary item key for a (subscriber, merchant) pair.
+fn make_dictionary_item_key(subscriber: Address, merchant: Address) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(&mut subscriber.to_bytes().unwrap_or_revert());
+    preimage.append(&mut merchant.to_bytes().unwrap_or_revert());
+
+    let key_bytes = runtime::blake2b(&preimage);
+    hex::encode(&key_bytes)
+}
+
+fn read_subscription(subscriber: Address, merchant: Address) -> Option<Subscription> {
+    let dictionary_item_key = make_dictionary_item_key(subscriber, merchant);
+    storage::dictionary_get(get_uref(SUBSCRIPTIONS_KEY_NAME), &dictionary_item_key)
+        .unwrap_or_revert()
+}
+
+/// Returns the active subscription, reverting if there is none or it was cancelled.
+fn read_active_subscription(subscriber: Address, merchant: Address) -> Subscription {
+    match read_subscription(subscriber, merchant) {
+        Some(subscription) if !subscription.cancelled => subscription,
+        _ => runtime::revert(Error::User(ERROR_SUBSCRIPTION_NOT_FOUND)),
+    }
+}
+
+fn write_subscription(subscriber: Address, merchant: Address, subscription: Subscription) {
+    let dictionary_item_key = make_dictionary_item_key(subscriber, merchant);
+    storage::dictionary_put(
+        get_uref(SUBSCRIPTIONS_KEY_NAME),
+        &dictionary_item_key,
+        subscription,
+    );
+}
+
+fn now() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+#[no_mangle]
+pub extern "C" fn subscribe() {
+    let merchant: Address = runtime::get_named_arg(MERCHANT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let period: u64 = runtime::get_named_arg(PERIOD_RUNTIME_ARG_NAME);
+
+    let subscriber = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    if amount.is_zero() || period == 0 || subscriber == merchant {
+        runtime::revert(Error::User(ERROR_INVALID_SUBSCRIPTION));
+    }
+    let is_subscribed = read_subscription(subscriber, merchant)
+        .map_or(false, |subscription| !subscription.cancelled);
+    if is_subscribed {
+        runtime::revert(Error::User(ERROR_ALREADY_SUBSCRIBED));
+    }
+
+    // First period is due right away.
+    let subscription = Subscription {
+        amount,
+        period,
+        next_collection: now(),
+        cancelled: false,
+    };
+    write_subscription(subscriber, merchant, subscription);
+}
+
+#[no_mangle]
+pub extern "C" fn cancel() {
+    let merchant: Address = runtime::get_named_arg(MERCHANT_RUNTIME_ARG_NAME);
+    let subscriber = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+
+    let mut subscription = read_active_subscription(subscriber, merchant);
+    subscription.cancelled = true;
+    write_subscription(subscriber, merchant, subscription);
+}
+
+#[no_mangle]
+pub extern "C" fn collect() {
+    let subscriber: Address = runtime::get_named_arg(SUBSCRIBER_RUNTIME_ARG_NAME);
+    let merchant = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+
+    let mut subscription = read_active_subscription(subscriber, merchant);
+    if now() < subscription.next_collection {
+        runtime::revert(Error::User(ERROR_PERIOD_NOT_ELAPSED));
+    }
+    // Periods stay aligned to the subscription start, so a merchant collecting late can still
+    // collect every elapsed period, one call each.
+    subscription.next_collection = subscription
+        .next_collection
+        .checked_add(subscription.period)
+        .unwrap_or_revert_with(Error::Overflow);
+    write_subscription(subscriber, merchant, subscription.clone());
+
+    let token_contract: ContractHash = storage::read(get_uref(TOKEN_CONTRACT_KEY_NAME))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    Erc20Ref::new(token_contract).transfer_from(subscriber, merchant, subscription.amount);
+}
+
+#[no_mangle]
+pub extern "C" fn next_collection() {
+    let subscriber: Address = runtime::get_named_arg(SUBSCRIBER_RUNTIME_ARG_NAME);
+    let merchant: Address = runtime::get_named_arg(MERCHANT_RUNTIME_ARG_NAME);
+    let next_collection = read_subscription(subscriber, merchant)
+        .filter(|subscription| !subscription.cancelled)
+        .map(|subscription| subscription.next_collection);
+    runtime::ret(CLValue::from_t(next_collection).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(SUBSCRIBE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(MERCHANT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(PERIOD_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(CANCEL_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            MERCHANT_RUNTIME_ARG_NAME,
+            Address::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(COLLECT_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            SUBSCRIBER_RUNTIME_ARG_NAME,
+            Address::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(NEXT_COLLECTION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SUBSCRIBER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(MERCHANT_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        Option::<u64>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let subscriptions_uref = storage::new_dictionary(SUBSCRIPTIONS_KEY_NAME).unwrap_or_revert();
+    runtime::remove_key(SUBSCRIPTIONS_KEY_NAME);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        SUBSCRIPTIONS_KEY_NAME.to_string(),
+        Key::from(subscriptions_uref),
+    );
+    named_keys.insert(
+        TOKEN_CONTRACT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(token_contract).into_read()),
+    );
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(ERC20_SUBSCRIPTIONS_PACKAGE_KEY_NAME.to_string()),
+        None,
+    );
+    runtime::put_key(ERC20_SUBSCRIPTIONS_KEY_NAME, Key::from(contract_hash));
+}
@@ -0,0 +1,60 @@
+//! Subscription paid by a subscriber to a merchant.
+use alloc::vec::Vec;
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U256,
+};
+
+/// Subscription letting a merchant collect `amount` tokens from the subscriber once per `period`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Subscription {
+    /// Amount of tokens collected per period.
+    pub amount: U256,
+    /// Length of a period, in milliseconds.
+    pub period: u64,
+    /// Block time from which the merchant can collect the next period.
+    pub next_collection: u64,
+    /// Whether the subscriber cancelled the subscription.
+    pub cancelled: bool,
+}
+
+impl CLTyped for Subscription {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Subscription {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.period.to_bytes()?);
+        result.append(&mut self.next_collection.to_bytes()?);
+        result.append(&mut self.cancelled.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.amount.serialized_length()
+            + self.period.serialized_length()
+            + self.next_collection.serialized_length()
+            + self.cancelled.serialized_length()
+    }
+}
+
+impl FromBytes for Subscription {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (amount, remainder) = U256::from_bytes(bytes)?;
+        let (period, remainder) = u64::from_bytes(remainder)?;
+        let (next_collection, remainder) = u64::from_bytes(remainder)?;
+        let (cancelled, remainder) = bool::from_bytes(remainder)?;
+        let subscription = Subscription {
+            amount,
+            period,
+            next_collection,
+            cancelled,
+        };
+        Ok((subscription, remainder))
+    }
+}
@@ -1,186 +1,74 @@
-#[cfg(test)]
-mod test_fixture;
-
 #[cfg(test)]
 mod tests {
-    use casper_types::{Key, U256};
+    use casper_erc20::constants::{
+        DECIMALS_RUNTIME_ARG_NAME, NAME_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME,
+        TOTAL_SUPPLY_RUNTIME_ARG_NAME,
+    };
+    use casper_erc20_test_suite::Erc20TestSuite;
+    use casper_types::{runtime_args, RuntimeArgs, U256};
+
+    const CONTRACT_ERC20_TOKEN: &str = "erc20_token.wasm";
+
+    const TOKEN_NAME: &str = "Test ERC20";
+    const TOKEN_SYMBOL: &str = "TERC";
+    const TOKEN_DECIMALS: u8 = 8;
+    const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+
+    fn suite() -> Erc20TestSuite {
+        Erc20TestSuite::new(
+            CONTRACT_ERC20_TOKEN,
+            runtime_args! {
+                NAME_RUNTIME_ARG_NAME => TOKEN_NAME,
+                SYMBOL_RUNTIME_ARG_NAME => TOKEN_SYMBOL,
+                DECIMALS_RUNTIME_ARG_NAME => TOKEN_DECIMALS,
+                TOTAL_SUPPLY_RUNTIME_ARG_NAME => U256::from(TOKEN_TOTAL_SUPPLY),
+            },
+        )
+    }
 
-    use crate::test_fixture::{Sender, TestFixture};
+    #[test]
+    fn should_install_with_installer_balance() {
+        suite().check_installer_balance();
+    }
 
     #[test]
-    fn should_install() {
-        let fixture = TestFixture::install_contract();
-        assert_eq!(fixture.token_name(), TestFixture::TOKEN_NAME);
-        assert_eq!(fixture.token_symbol(), TestFixture::TOKEN_SYMBOL);
-        assert_eq!(fixture.token_decimals(), TestFixture::TOKEN_DECIMALS);
-        assert_eq!(
-            fixture.balance_of(Key::from(fixture.ali)),
-            Some(TestFixture::token_total_supply())
-        );
+    fn should_install_with_named_keys() {
+        suite().check_installer_named_keys();
     }
 
     #[test]
     fn should_transfer() {
-        let mut fixture = TestFixture::install_contract();
-        assert_eq!(fixture.balance_of(Key::from(fixture.bob)), None);
-        assert_eq!(
-            fixture.balance_of(Key::from(fixture.ali)),
-            Some(TestFixture::token_total_supply())
-        );
-        let transfer_amount_1 = U256::from(42);
-        fixture.transfer(
-            Key::from(fixture.bob),
-            transfer_amount_1,
-            Sender(fixture.ali),
-        );
-        assert_eq!(
-            fixture.balance_of(Key::from(fixture.bob)),
-            Some(transfer_amount_1)
-        );
-        assert_eq!(
-            fixture.balance_of(Key::from(fixture.ali)),
-            Some(TestFixture::token_total_supply() - transfer_amount_1)
-        );
-
-        let transfer_amount_2 = U256::from(20);
-        fixture.transfer(
-            Key::from(fixture.ali),
-            transfer_amount_2,
-            Sender(fixture.bob),
-        );
-        assert_eq!(
-            fixture.balance_of(Key::from(fixture.ali)),
-            Some(TestFixture::token_total_supply() - transfer_amount_1 + transfer_amount_2),
-        );
-        assert_eq!(
-            fixture.balance_of(Key::from(fixture.bob)),
-            Some(transfer_amount_1 - transfer_amount_2)
-        );
+        suite().check_transfers();
     }
 
     #[test]
     fn should_transfer_full_amount() {
-        let mut fixture = TestFixture::install_contract();
-
-        let initial_ali_balance = fixture.balance_of(Key::from(fixture.ali)).unwrap();
-        assert_eq!(fixture.balance_of(Key::from(fixture.bob)), None);
-
-        fixture.transfer(
-            Key::from(fixture.bob),
-            initial_ali_balance,
-            Sender(fixture.ali),
-        );
-
-        assert_eq!(
-            fixture.balance_of(Key::from(fixture.bob)),
-            Some(initial_ali_balance)
-        );
-        assert_eq!(
-            fixture.balance_of(Key::from(fixture.ali)),
-            Some(U256::zero())
-        );
+        suite().check_full_balance_transfers();
+    }
 
-        fixture.transfer(
-            Key::from(fixture.ali),
-            initial_ali_balance,
-            Sender(fixture.bob),
-        );
+    #[test]
+    fn should_transfer_to_self() {
+        suite().check_self_transfers();
+    }
 
-        assert_eq!(
-            fixture.balance_of(Key::from(fixture.bob)),
-            Some(U256::zero())
-        );
-        assert_eq!(
-            fixture.balance_of(Key::from(fixture.ali)),
-            Some(initial_ali_balance)
-        );
+    #[test]
+    fn should_transfer_zero_amount() {
+        suite().check_zero_amount_transfers();
     }
 
-    #[should_panic(expected = "ApiError::User(65534) [131070]")]
     #[test]
     fn should_not_transfer_with_insufficient_balance() {
-        let mut fixture = TestFixture::install_contract();
-
-        let initial_ali_balance = fixture.balance_of(Key::from(fixture.ali)).unwrap();
-        assert_eq!(fixture.balance_of(Key::from(fixture.bob)), None);
-
-        fixture.transfer(
-            Key::from(fixture.bob),
-            initial_ali_balance + U256::one(),
-            Sender(fixture.ali),
-        );
+        suite().check_insufficient_balance();
     }
 
     #[test]
     fn should_transfer_from() {
-        let approve_amount = U256::from(100);
-        let transfer_amount = U256::from(42);
-        assert!(approve_amount > transfer_amount);
-
-        let mut fixture = TestFixture::install_contract();
-
-        let owner = fixture.ali;
-        let spender = fixture.bob;
-        let recipient = fixture.joe;
-
-        let owner_balance_before = fixture
-            .balance_of(Key::from(owner))
-            .expect("owner should have balance");
-        fixture.approve(Key::from(spender), approve_amount, Sender(owner));
-        assert_eq!(
-            fixture.allowance(Key::from(owner), Key::from(spender)),
-            Some(approve_amount)
-        );
-
-        fixture.transfer_from(
-            Key::from(owner),
-            Key::from(recipient),
-            transfer_amount,
-            Sender(spender),
-        );
-
-        assert_eq!(
-            fixture.balance_of(Key::from(owner)),
-            Some(owner_balance_before - transfer_amount),
-            "should decrease balance of the owner"
-        );
-        assert_eq!(
-            fixture.allowance(Key::from(owner), Key::from(spender)),
-            Some(approve_amount - transfer_amount),
-            "should decrease allowance of the spender"
-        );
-        assert_eq!(
-            fixture.balance_of(Key::from(recipient)),
-            Some(transfer_amount),
-            "recipient should receive tokens"
-        );
+        suite().check_allowances();
     }
 
-    #[should_panic(expected = "ApiError::User(65533) [131069]")]
     #[test]
     fn should_not_transfer_from_more_than_approved() {
-        let approve_amount = U256::from(100);
-        let transfer_amount = U256::from(42);
-        assert!(approve_amount > transfer_amount);
-
-        let mut fixture = TestFixture::install_contract();
-
-        let owner = fixture.ali;
-        let spender = fixture.bob;
-        let recipient = fixture.joe;
-
-        fixture.approve(Key::from(spender), approve_amount, Sender(owner));
-        assert_eq!(
-            fixture.allowance(Key::from(owner), Key::from(spender)),
-            Some(approve_amount)
-        );
-
-        fixture.transfer_from(
-            Key::from(owner),
-            Key::from(recipient),
-            approve_amount + U256::one(),
-            Sender(spender),
-        );
+        suite().check_insufficient_allowance();
     }
 }
 
@@ -6,14 +6,15 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_erc20::{
     constants::{
-        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, DECIMALS_RUNTIME_ARG_NAME,
-        NAME_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
-        SPENDER_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME, TOTAL_SUPPLY_RUNTIME_ARG_NAME,
+        ADDRESSES_RUNTIME_ARG_NAME, ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME,
+        DECIMALS_RUNTIME_ARG_NAME, NAME_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME,
+        PAIRS_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME,
+        SYMBOL_RUNTIME_ARG_NAME, TOTAL_SUPPLY_RUNTIME_ARG_NAME,
     },
     Address, ERC20,
 };
@@ -50,6 +51,13 @@ pub extern "C" fn balance_of() {
     runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn balances_of() {
+    let addresses: Vec<Address> = runtime::get_named_arg(ADDRESSES_RUNTIME_ARG_NAME);
+    let balances = ERC20::default().balances_of(addresses);
+    runtime::ret(CLValue::from_t(balances).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn transfer() {
     let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
@@ -76,6 +84,13 @@ pub extern "C" fn allowance() {
     runtime::ret(CLValue::from_t(val).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn allowances_of() {
+    let pairs: Vec<(Address, Address)> = runtime::get_named_arg(PAIRS_RUNTIME_ARG_NAME);
+    let val = ERC20::default().allowances_of(pairs);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn transfer_from() {
     let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
@@ -93,5 +108,12 @@ fn call() {
     let decimals = runtime::get_named_arg(DECIMALS_RUNTIME_ARG_NAME);
     let total_supply = runtime::get_named_arg(TOTAL_SUPPLY_RUNTIME_ARG_NAME);
 
-    let _token = ERC20::install(name, symbol, decimals, total_supply).unwrap_or_revert();
+    let mut entry_points = casper_erc20::entry_points::default();
+    entry_points.add_entry_point(casper_erc20::entry_points::balances_of());
+    entry_points.add_entry_point(casper_erc20::entry_points::allowances_of());
+
+    let _token = ERC20::builder(name, symbol, decimals, total_supply)
+        .with_entry_points(entry_points)
+        .install()
+        .unwrap_or_revert();
 }
@@ -0,0 +1,404 @@
+//! Tokenized vault in the style of ERC-4626, which holds an underlying ERC20 asset and issues its
+//! own ERC20 shares to depositors.
+//!
+//! The vault contract is the share token itself, which is why the token entry points are exported
+//! here next to the vault ones. Conversions between assets and shares always round in favour of
+//! the vault, and use one virtual share and one virtual asset so that an empty vault starts at
+//! one share per asset.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{
+    constants::{
+        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, NAME_RUNTIME_ARG_NAME,
+        OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME,
+        SYMBOL_RUNTIME_ARG_NAME,
+    },
+    get_uref, Address, Erc20Ref, Error, ERC20,
+};
+use casper_types::{
+    contracts::NamedKeys, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess,
+    EntryPointType, Key, Parameter, U256,
+};
+
+const ASSET_ENTRY_POINT_NAME: &str = "asset";
+const TOTAL_ASSETS_ENTRY_POINT_NAME: &str = "total_assets";
+const CONVERT_TO_SHARES_ENTRY_POINT_NAME: &str = "convert_to_shares";
+const CONVERT_TO_ASSETS_ENTRY_POINT_NAME: &str = "convert_to_assets";
+const PREVIEW_DEPOSIT_ENTRY_POINT_NAME: &str = "preview_deposit";
+const PREVIEW_MINT_ENTRY_POINT_NAME: &str = "preview_mint";
+const PREVIEW_WITHDRAW_ENTRY_POINT_NAME: &str = "preview_withdraw";
+const PREVIEW_REDEEM_ENTRY_POINT_NAME: &str = "preview_redeem";
+const DEPOSIT_ENTRY_POINT_NAME: &str = "deposit";
+const MINT_ENTRY_POINT_NAME: &str = "mint";
+const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+const REDEEM_ENTRY_POINT_NAME: &str = "redeem";
+
+const ASSET_CONTRACT_RUNTIME_ARG_NAME: &str = "asset_contract";
+const ASSETS_RUNTIME_ARG_NAME: &str = "assets";
+const SHARES_RUNTIME_ARG_NAME: &str = "shares";
+const RECEIVER_RUNTIME_ARG_NAME: &str = "receiver";
+
+const ASSET_CONTRACT_KEY_NAME: &str = "asset_contract";
+const ERC20_VAULT_KEY_NAME: &str = "erc20_vault";
+
+/// Deposit is too small to be worth a single share, or redemption too small to be worth a single
+/// unit of the asset.
+const ERROR_ZERO_AMOUNT: u16 = 1;
+
+/// Direction in which a conversion between assets and shares is rounded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Rounding {
+    Down,
+    Up,
+}
+
+fn asset_contract() -> ContractHash {
+    storage::read(get_uref(ASSET_CONTRACT_KEY_NAME))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+fn asset_token() -> Erc20Ref {
+    Erc20Ref::new(asset_contract())
+}
+
+fn total_assets_held() -> U256 {
+    asset_token().balance_of(casper_erc20::self_package_address())
+}
+
+/// Returns `amount * numerator / denominator`, rounded in the given direction.
+fn mul_div(amount: U256, numerator: U256, denominator: U256, rounding: Rounding) -> U256 {
+    let product = amount
+        .checked_mul(numerator)
+        .unwrap_or_revert_with(Error::Overflow);
+    let quotient = product / denominator;
+    if rounding == Rounding::Up && !(product % denominator).is_zero() {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+fn to_shares(assets: U256, rounding: Rounding) -> U256 {
+    let total_shares = ERC20::default().total_supply();
+    mul_div(assets, total_shares + 1, total_assets_held() + 1, rounding)
+}
+
+fn to_assets(shares: U256, rounding: Rounding) -> U256 {
+    let total_shares = ERC20::default().total_supply();
+    mul_div(shares, total_assets_held() + 1, total_shares + 1, rounding)
+}
+
+/// Burns `shares` of `owner` and sends `assets` to `receiver`, spending the caller's allowance
+/// unless the caller is the owner.
+fn exit(owner: Address, receiver: Address, assets: U256, shares: U256) {
+    let caller = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let mut vault = ERC20::default();
+    if caller != owner {
+        vault
+            .spend_allowance(owner, caller, shares)
+            .unwrap_or_revert();
+    }
+    vault.burn(owner, shares).unwrap_or_revert();
+    asset_token().transfer(receiver, assets);
+}
+
+/// Takes `assets` from the caller and mints `shares` to `receiver`.
+///
+/// The caller is expected to approve this contract's package hash for at least `assets`.
+fn enter(receiver: Address, assets: U256, shares: U256) {
+    let caller = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    asset_token().transfer_from(caller, casper_erc20::self_package_address(), assets);
+    ERC20::default().mint(receiver, shares).unwrap_or_revert();
+}
+
+fn ret(value: U256) {
+    runtime::ret(CLValue::from_t(value).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn name() {
+    let name = ERC20::default().name();
+    runtime::ret(CLValue::from_t(name).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn symbol() {
+    let symbol = ERC20::default().symbol();
+    runtime::ret(CLValue::from_t(symbol).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn decimals() {
+    let decimals = ERC20::default().decimals();
+    runtime::ret(CLValue::from_t(decimals).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    let total_supply = ERC20::default().total_supply();
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let balance = ERC20::default().balance_of(address);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default()
+        .transfer(recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default().approve(spender, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let val = ERC20::default().allowance(owner, spender);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC20::default()
+        .transfer_from(owner, recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn asset() {
+    runtime::ret(CLValue::from_t(asset_contract()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_assets() {
+    ret(total_assets_held());
+}
+
+#[no_mangle]
+pub extern "C" fn convert_to_shares() {
+    let assets: U256 = runtime::get_named_arg(ASSETS_RUNTIME_ARG_NAME);
+    ret(to_shares(assets, Rounding::Down));
+}
+
+#[no_mangle]
+pub extern "C" fn convert_to_assets() {
+    let shares: U256 = runtime::get_named_arg(SHARES_RUNTIME_ARG_NAME);
+    ret(to_assets(shares, Rounding::Down));
+}
+
+#[no_mangle]
+pub extern "C" fn preview_deposit() {
+    let assets: U256 = runtime::get_named_arg(ASSETS_RUNTIME_ARG_NAME);
+    ret(to_shares(assets, Rounding::Down));
+}
+
+#[no_mangle]
+pub extern "C" fn preview_mint() {
+    let shares: U256 = runtime::get_named_arg(SHARES_RUNTIME_ARG_NAME);
+    ret(to_assets(shares, Rounding::Up));
+}
+
+#[no_mangle]
+pub extern "C" fn preview_withdraw() {
+    let assets: U256 = runtime::get_named_arg(ASSETS_RUNTIME_ARG_NAME);
+    ret(to_shares(assets, Rounding::Up));
+}
+
+#[no_mangle]
+pub extern "C" fn preview_redeem() {
+    let shares: U256 = runtime::get_named_arg(SHARES_RUNTIME_ARG_NAME);
+    ret(to_assets(shares, Rounding::Down));
+}
+
+#[no_mangle]
+pub extern "C" fn deposit() {
+    let assets: U256 = runtime::get_named_arg(ASSETS_RUNTIME_ARG_NAME);
+    let receiver: Address = runtime::get_named_arg(RECEIVER_RUNTIME_ARG_NAME);
+
+    let shares = to_shares(assets, Rounding::Down);
+    if shares.is_zero() {
+        runtime::revert(Error::User(ERROR_ZERO_AMOUNT));
+    }
+    enter(receiver, assets, shares);
+    ret(shares);
+}
+
+#[no_mangle]
+pub extern "C" fn mint() {
+    let shares: U256 = runtime::get_named_arg(SHARES_RUNTIME_ARG_NAME);
+    let receiver: Address = runtime::get_named_arg(RECEIVER_RUNTIME_ARG_NAME);
+
+    let assets = to_assets(shares, Rounding::Up);
+    enter(receiver, assets, shares);
+    ret(assets);
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    let assets: U256 = runtime::get_named_arg(ASSETS_RUNTIME_ARG_NAME);
+    let receiver: Address = runtime::get_named_arg(RECEIVER_RUNTIME_ARG_NAME);
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+
+    let shares = to_shares(assets, Rounding::Up);
+    exit(owner, receiver, assets, shares);
+    ret(shares);
+}
+
+#[no_mangle]
+pub extern "C" fn redeem() {
+    let shares: U256 = runtime::get_named_arg(SHARES_RUNTIME_ARG_NAME);
+    let receiver: Address = runtime::get_named_arg(RECEIVER_RUNTIME_ARG_NAME);
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+
+    let assets = to_assets(shares, Rounding::Down);
+    if assets.is_zero() {
+        runtime::revert(Error::User(ERROR_ZERO_AMOUNT));
+    }
+    exit(owner, receiver, assets, shares);
+    ret(assets);
+}
+
+/// Returns a public entry point taking a single `U256` argument and returning a `U256`.
+fn conversion_entry_point(name: &str, arg_name: &str) -> EntryPoint {
+    EntryPoint::new(
+        String::from(name),
+        vec![Parameter::new(arg_name, U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns a public entry point moving `amount_arg_name` of assets or shares from `owner`, if
+/// given, to `receiver`.
+fn vault_entry_point(name: &str, amount_arg_name: &str, with_owner: bool) -> EntryPoint {
+    let mut params = vec![
+        Parameter::new(amount_arg_name, U256::cl_type()),
+        Parameter::new(RECEIVER_RUNTIME_ARG_NAME, Address::cl_type()),
+    ];
+    if with_owner {
+        params.push(Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()));
+    }
+    EntryPoint::new(
+        String::from(name),
+        params,
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let asset_contract: ContractHash = runtime::get_named_arg(ASSET_CONTRACT_RUNTIME_ARG_NAME);
+    let name: String = runtime::get_named_arg(NAME_RUNTIME_ARG_NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
+    // Shares use the decimals of the asset, as the vault starts at one share per asset.
+    let decimals = Erc20Ref::new(asset_contract).decimals();
+
+    let mut entry_points = casper_erc20::entry_points::default();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(ASSET_ENTRY_POINT_NAME),
+        Vec::new(),
+        ContractHash::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(TOTAL_ASSETS_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(conversion_entry_point(
+        CONVERT_TO_SHARES_ENTRY_POINT_NAME,
+        ASSETS_RUNTIME_ARG_NAME,
+    ));
+    entry_points.add_entry_point(conversion_entry_point(
+        CONVERT_TO_ASSETS_ENTRY_POINT_NAME,
+        SHARES_RUNTIME_ARG_NAME,
+    ));
+    entry_points.add_entry_point(conversion_entry_point(
+        PREVIEW_DEPOSIT_ENTRY_POINT_NAME,
+        ASSETS_RUNTIME_ARG_NAME,
+    ));
+    entry_points.add_entry_point(conversion_entry_point(
+        PREVIEW_MINT_ENTRY_POINT_NAME,
+        SHARES_RUNTIME_ARG_NAME,
+    ));
+    entry_points.add_entry_point(conversion_entry_point(
+        PREVIEW_WITHDRAW_ENTRY_POINT_NAME,
+        ASSETS_RUNTIME_ARG_NAME,
+    ));
+    entry_points.add_entry_point(conversion_entry_point(
+        PREVIEW_REDEEM_ENTRY_POINT_NAME,
+        SHARES_RUNTIME_ARG_NAME,
+    ));
+    entry_points.add_entry_point(vault_entry_point(
+        DEPOSIT_ENTRY_POINT_NAME,
+        ASSETS_RUNTIME_ARG_NAME,
+        false,
+    ));
+    entry_points.add_entry_point(vault_entry_point(
+        MINT_ENTRY_POINT_NAME,
+        SHARES_RUNTIME_ARG_NAME,
+        false,
+    ));
+    entry_points.add_entry_point(vault_entry_point(
+        WITHDRAW_ENTRY_POINT_NAME,
+        ASSETS_RUNTIME_ARG_NAME,
+        true,
+    ));
+    entry_points.add_entry_point(vault_entry_point(
+        REDEEM_ENTRY_POINT_NAME,
+        SHARES_RUNTIME_ARG_NAME,
+        true,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        ASSET_CONTRACT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(asset_contract).into_read()),
+    );
+
+    // No shares exist until the first deposit.
+    ERC20::builder(name, symbol, decimals, U256::zero())
+        .with_contract_key_name(ERC20_VAULT_KEY_NAME)
+        .with_entry_points(entry_points)
+        .with_named_keys(named_keys)
+        .install()
+        .unwrap_or_revert();
+}
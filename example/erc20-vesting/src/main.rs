@@ -0,0 +1,247 @@
+//! Vesting contract which holds ERC20 tokens and releases them to beneficiaries over time.
+//!
+//! Schedules are keyed by both the funder and the beneficiary, so anyone can fund a schedule for
+//! any beneficiary without blocking schedules funded by others.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+mod schedule;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{get_uref, Address, Erc20Ref, Error};
+use casper_types::{
+    bytesrepr::ToBytes, contracts::NamedKeys, CLType, CLTyped, CLValue, ContractHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter, U256,
+};
+
+use schedule::VestingSchedule;
+
+const CREATE_SCHEDULE_ENTRY_POINT_NAME: &str = "create_schedule";
+const RELEASE_ENTRY_POINT_NAME: &str = "release";
+const REVOKE_ENTRY_POINT_NAME: &str = "revoke";
+const RELEASABLE_AMOUNT_ENTRY_POINT_NAME: &str = "releasable_amount";
+
+const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
+const FUNDER_RUNTIME_ARG_NAME: &str = "funder";
+const BENEFICIARY_RUNTIME_ARG_NAME: &str = "beneficiary";
+const TOTAL_RUNTIME_ARG_NAME: &str = "total";
+const START_RUNTIME_ARG_NAME: &str = "start";
+const CLIFF_RUNTIME_ARG_NAME: &str = "cliff";
+const DURATION_RUNTIME_ARG_NAME: &str = "duration";
+const REVOCABLE_RUNTIME_ARG_NAME: &str = "revocable";
+
+const TOKEN_CONTRACT_KEY_NAME: &str = "token_contract";
+const SCHEDULES_KEY_NAME: &str = "schedules";
+const ERC20_VESTING_KEY_NAME: &str = "erc20_vesting";
+const ERC20_VESTING_PACKAGE_KEY_NAME: &str = "erc20_vesting_package";
+
+/// Funder already created a vesting schedule for the beneficiary.
+const ERROR_SCHEDULE_ALREADY_EXISTS: u16 = 1;
+/// There is no vesting schedule created by the funder for the beneficiary.
+const ERROR_SCHEDULE_NOT_FOUND: u16 = 2;
+/// Schedule has no tokens, no duration, or a cliff past its duration.
+const ERROR_INVALID_SCHEDULE: u16 = 3;
+/// No vested tokens are waiting to be released.
+const ERROR_NOTHING_TO_RELEASE: u16 = 4;
+/// Schedule is not revocable, or was already revoked.
+const ERROR_NOT_REVOCABLE: u16 = 5;
+
+fn token() -> Erc20Ref {
+    let token_contract: ContractHash = storage::read(get_uref(TOKEN_CONTRACT_KEY_NAME))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    Erc20Ref::new(token_contract)
+}
+
+/// Creates a dictionary item key for a (funder, beneficiary) pair.
+fn make_dictionary_item_key(funder: Address, beneficiary: Address) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(&mut funder.to_bytes().unwrap_or_revert());
+    preimage.append(&mut beneficiary.to_bytes().unwrap_or_revert());
+
+    let key_bytes = runtime::blake2b(&preimage);
+    hex::encode(&key_bytes)
+}
+
+fn read_schedule(funder: Address, beneficiary: Address) -> Option<VestingSchedule> {
+    let dictionary_item_key = make_dictionary_item_key(funder, beneficiary);
+    storage::dictionary_get(get_uref(SCHEDULES_KEY_NAME), &dictionary_item_key).unwrap_or_revert()
+}
+
+fn write_schedule(funder: Address, beneficiary: Address, schedule: VestingSchedule) {
+    let dictionary_item_key = make_dictionary_item_key(funder, beneficiary);
+    storage::dictionary_put(get_uref(SCHEDULES_KEY_NAME), &dictionary_item_key, schedule);
+}
+
+fn now() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+#[no_mangle]
+pub extern "C" fn create_schedule() {
+    let beneficiary: Address = runtime::get_named_arg(BENEFICIARY_RUNTIME_ARG_NAME);
+    let total: U256 = runtime::get_named_arg(TOTAL_RUNTIME_ARG_NAME);
+    let start: u64 = runtime::get_named_arg(START_RUNTIME_ARG_NAME);
+    let cliff: u64 = runtime::get_named_arg(CLIFF_RUNTIME_ARG_NAME);
+    let duration: u64 = runtime::get_named_arg(DURATION_RUNTIME_ARG_NAME);
+    let revocable: bool = runtime::get_named_arg(REVOCABLE_RUNTIME_ARG_NAME);
+
+    if total.is_zero() || duration == 0 || cliff > duration {
+        runtime::revert(Error::User(ERROR_INVALID_SCHEDULE));
+    }
+    let funder = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    if read_schedule(funder, beneficiary).is_some() {
+        runtime::revert(Error::User(ERROR_SCHEDULE_ALREADY_EXISTS));
+    }
+
+    let schedule = VestingSchedule {
+        funder,
+        total,
+        released: U256::zero(),
+        start,
+        cliff,
+        duration,
+        revocable,
+        revoked: false,
+    };
+    write_schedule(funder, beneficiary, schedule);
+
+    // Funder is expected to approve this contract's package hash for at least `total` tokens.
+    token().transfer_from(funder, casper_erc20::self_package_address(), total);
+}
+
+#[no_mangle]
+pub extern "C" fn release() {
+    let funder: Address = runtime::get_named_arg(FUNDER_RUNTIME_ARG_NAME);
+    let beneficiary = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let mut schedule = read_schedule(funder, beneficiary)
+        .unwrap_or_revert_with(Error::User(ERROR_SCHEDULE_NOT_FOUND));
+
+    let amount = schedule.releasable_amount(now()).unwrap_or_revert();
+    if amount.is_zero() {
+        runtime::revert(Error::User(ERROR_NOTHING_TO_RELEASE));
+    }
+
+    schedule.released = schedule
+        .released
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::Overflow);
+    write_schedule(funder, beneficiary, schedule);
+
+    token().transfer(beneficiary, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn revoke() {
+    let beneficiary: Address = runtime::get_named_arg(BENEFICIARY_RUNTIME_ARG_NAME);
+    // Only the funder is able to revoke, as schedules are looked up by the caller.
+    let funder = casper_erc20::get_immediate_caller_address().unwrap_or_revert();
+    let mut schedule = read_schedule(funder, beneficiary)
+        .unwrap_or_revert_with(Error::User(ERROR_SCHEDULE_NOT_FOUND));
+
+    if !schedule.revocable || schedule.revoked {
+        runtime::revert(Error::User(ERROR_NOT_REVOCABLE));
+    }
+
+    // Tokens vested so far stay releasable by the beneficiary.
+    let vested = schedule.vested_amount(now()).unwrap_or_revert();
+    let unvested = schedule
+        .total
+        .checked_sub(vested)
+        .unwrap_or_revert_with(Error::Overflow);
+    schedule.total = vested;
+    schedule.revoked = true;
+    write_schedule(funder, beneficiary, schedule);
+
+    if !unvested.is_zero() {
+        token().transfer(funder, unvested);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn releasable_amount() {
+    let funder: Address = runtime::get_named_arg(FUNDER_RUNTIME_ARG_NAME);
+    let beneficiary: Address = runtime::get_named_arg(BENEFICIARY_RUNTIME_ARG_NAME);
+    let amount = match read_schedule(funder, beneficiary) {
+        Some(schedule) => schedule.releasable_amount(now()).unwrap_or_revert(),
+        None => U256::zero(),
+    };
+    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(CREATE_SCHEDULE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(BENEFICIARY_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOTAL_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(START_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(CLIFF_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(DURATION_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(REVOCABLE_RUNTIME_ARG_NAME, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(RELEASE_ENTRY_POINT_NAME),
+        vec![Parameter::new(FUNDER_RUNTIME_ARG_NAME, Address::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(REVOKE_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            BENEFICIARY_RUNTIME_ARG_NAME,
+            Address::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(RELEASABLE_AMOUNT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FUNDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(BENEFICIARY_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let schedules_uref = storage::new_dictionary(SCHEDULES_KEY_NAME).unwrap_or_revert();
+    runtime::remove_key(SCHEDULES_KEY_NAME);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        TOKEN_CONTRACT_KEY_NAME.to_string(),
+        Key::from(storage::new_uref(token_contract).into_read()),
+    );
+    named_keys.insert(SCHEDULES_KEY_NAME.to_string(), Key::from(schedules_uref));
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(ERC20_VESTING_PACKAGE_KEY_NAME.to_string()),
+        None,
+    );
+    runtime::put_key(ERC20_VESTING_KEY_NAME, Key::from(contract_hash));
+}
@@ -0,0 +1,118 @@
+//! Vesting schedule of a single beneficiary.
+use alloc::vec::Vec;
+
+use casper_erc20::{Address, Error};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U256,
+};
+
+/// Schedule which releases `total` tokens to its beneficiary linearly between `start` and
+/// `start + duration`, with nothing released before `start + cliff`.
+///
+/// All times are block times in milliseconds.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct VestingSchedule {
+    /// Address which funded the schedule, and receives unvested tokens on revocation.
+    pub funder: Address,
+    /// Amount of tokens vested over the whole schedule.
+    pub total: U256,
+    /// Amount of tokens already released to the beneficiary.
+    pub released: U256,
+    /// Block time at which vesting starts.
+    pub start: u64,
+    /// Time after `start` before which no tokens are vested.
+    pub cliff: u64,
+    /// Time after `start` at which all tokens are vested.
+    pub duration: u64,
+    /// Whether the funder is able to revoke the schedule.
+    pub revocable: bool,
+    /// Whether the schedule was revoked, in which case `total` only covers the tokens vested at
+    /// the time of revocation.
+    pub revoked: bool,
+}
+
+impl VestingSchedule {
+    /// Returns the amount of tokens vested at the block time `now`.
+    pub fn vested_amount(&self, now: u64) -> Result<U256, Error> {
+        if self.revoked {
+            return Ok(self.total);
+        }
+
+        let elapsed = now.saturating_sub(self.start);
+        if now < self.start || elapsed < self.cliff {
+            Ok(U256::zero())
+        } else if elapsed >= self.duration {
+            Ok(self.total)
+        } else {
+            let vested = self
+                .total
+                .checked_mul(U256::from(elapsed))
+                .ok_or(Error::Overflow)?
+                / U256::from(self.duration);
+            Ok(vested)
+        }
+    }
+
+    /// Returns the amount of tokens vested at the block time `now` which were not released yet.
+    pub fn releasable_amount(&self, now: u64) -> Result<U256, Error> {
+        let vested = self.vested_amount(now)?;
+        vested.checked_sub(self.released).ok_or(Error::Overflow)
+    }
+}
+
+impl CLTyped for VestingSchedule {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for VestingSchedule {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.funder.to_bytes()?);
+        result.append(&mut self.total.to_bytes()?);
+        result.append(&mut self.released.to_bytes()?);
+        result.append(&mut self.start.to_bytes()?);
+        result.append(&mut self.cliff.to_bytes()?);
+        result.append(&mut self.duration.to_bytes()?);
+        result.append(&mut self.revocable.to_bytes()?);
+        result.append(&mut self.revoked.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.funder.serialized_length()
+            + self.total.serialized_length()
+            + self.released.serialized_length()
+            + self.start.serialized_length()
+            + self.cliff.serialized_length()
+            + self.duration.serialized_length()
+            + self.revocable.serialized_length()
+            + self.revoked.serialized_length()
+    }
+}
+
+impl FromBytes for VestingSchedule {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (funder, remainder) = Address::from_bytes(bytes)?;
+        let (total, remainder) = U256::from_bytes(remainder)?;
+        let (released, remainder) = U256::from_bytes(remainder)?;
+        let (start, remainder) = u64::from_bytes(remainder)?;
+        let (cliff, remainder) = u64::from_bytes(remainder)?;
+        let (duration, remainder) = u64::from_bytes(remainder)?;
+        let (revocable, remainder) = bool::from_bytes(remainder)?;
+        let (revoked, remainder) = bool::from_bytes(remainder)?;
+        let schedule = VestingSchedule {
+            funder,
+            total,
+            released,
+            start,
+            cliff,
+            duration,
+            revocable,
+            revoked,
+        };
+        Ok((schedule, remainder))
+    }
+}
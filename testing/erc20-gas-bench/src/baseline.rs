@@ -0,0 +1,308 @@
+//! Checked-in gas costs, and comparison of measured costs against them.
+//!
+//! The baseline is a plain text file with one scenario per line:
+//!
+//! ```text
+//! # scenario                                gas  tolerance_percent
+//! transfer_to_new_recipient             1234567  2
+//! ```
+//!
+//! A gas cost of `-` marks a scenario which has not been measured yet.
+use std::{fmt, fs, io, path::Path};
+
+/// Tolerance given to scenarios which are not yet present in the baseline.
+pub(crate) const DEFAULT_TOLERANCE_PERCENT: u64 = 2;
+
+const UNMEASURED: &str = "-";
+
+const HEADER: &str = "\
+# Gas consumed by the example ERC20 token entry points.
+#
+# Regenerate with `make bench-gas-bless` after an intended change in costs, and keep the diff of
+# this file in the same commit as the change.
+#
+# scenario                                gas  tolerance_percent
+";
+
+/// Expected cost of a single scenario.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Entry {
+    pub(crate) scenario: String,
+    pub(crate) gas: Option<u64>,
+    pub(crate) tolerance_percent: u64,
+}
+
+/// Expected costs of all the scenarios, in the order of the baseline file.
+#[derive(Debug, Default)]
+pub(crate) struct Baseline {
+    entries: Vec<Entry>,
+}
+
+impl Baseline {
+    /// Reads the baseline from `path`, or returns an empty baseline if the file does not exist.
+    pub(crate) fn read(path: &Path) -> io::Result<Baseline> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(error) => return Err(error),
+        };
+        contents.parse().map_err(|error: ParseError| {
+            io::Error::new(io::ErrorKind::InvalidData, error.to_string())
+        })
+    }
+
+    /// Writes the baseline to `path`.
+    pub(crate) fn write(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub(crate) fn get(&self, scenario: &str) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.scenario == scenario)
+    }
+
+    /// Returns a baseline with the measured costs, keeping the tolerances of known scenarios.
+    pub(crate) fn bless(&self, measurements: &[(&str, u64)]) -> Baseline {
+        let entries = measurements
+            .iter()
+            .map(|(scenario, gas)| Entry {
+                scenario: scenario.to_string(),
+                gas: Some(*gas),
+                tolerance_percent: self
+                    .get(scenario)
+                    .map(|entry| entry.tolerance_percent)
+                    .unwrap_or(DEFAULT_TOLERANCE_PERCENT),
+            })
+            .collect();
+        Baseline { entries }
+    }
+}
+
+impl std::str::FromStr for Baseline {
+    type Err = ParseError;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let mut entries = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid_line = || ParseError {
+                line_number: index + 1,
+            };
+
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            let (scenario, gas, tolerance_percent) = match columns.as_slice() {
+                [scenario, gas, tolerance_percent] => (scenario, gas, tolerance_percent),
+                _ => return Err(invalid_line()),
+            };
+            let gas = match *gas {
+                UNMEASURED => None,
+                gas => Some(gas.parse().map_err(|_| invalid_line())?),
+            };
+            let tolerance_percent = tolerance_percent.parse().map_err(|_| invalid_line())?;
+
+            entries.push(Entry {
+                scenario: scenario.to_string(),
+                gas,
+                tolerance_percent,
+            });
+        }
+        Ok(Baseline { entries })
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(HEADER)?;
+        for entry in &self.entries {
+            let gas = entry
+                .gas
+                .map(|gas| gas.to_string())
+                .unwrap_or_else(|| UNMEASURED.to_string());
+            writeln!(
+                f,
+                "{:<32} {:>12}  {}",
+                entry.scenario, gas, entry.tolerance_percent
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Malformed line in the baseline file.
+#[derive(Debug)]
+pub(crate) struct ParseError {
+    line_number: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: expected `<scenario> <gas> <tolerance_percent>`",
+            self.line_number
+        )
+    }
+}
+
+/// Result of comparing a measured cost against the baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Comparison {
+    /// The scenario is not measured in the baseline.
+    Missing,
+    /// The cost is within the tolerance.
+    Within,
+    /// The cost is lower than the tolerance allows, and the baseline should be updated.
+    Improved,
+    /// The cost is higher than the tolerance allows.
+    Regressed,
+}
+
+impl Comparison {
+    pub(crate) fn is_failure(self) -> bool {
+        matches!(self, Comparison::Missing | Comparison::Regressed)
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Comparison::Missing => "MISSING",
+            Comparison::Within => "ok",
+            Comparison::Improved => "improved",
+            Comparison::Regressed => "REGRESSED",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Compares `measured` gas against the baseline `entry` of the same scenario.
+pub(crate) fn compare(entry: Option<&Entry>, measured: u64) -> Comparison {
+    let (expected, tolerance_percent) = match entry {
+        Some(Entry {
+            gas: Some(gas),
+            tolerance_percent,
+            ..
+        }) => (u128::from(*gas), u128::from(*tolerance_percent)),
+        _ => return Comparison::Missing,
+    };
+    let measured = u128::from(measured);
+    let allowed_difference = expected * tolerance_percent / 100;
+
+    if measured > expected + allowed_difference {
+        Comparison::Regressed
+    } else if measured + allowed_difference < expected {
+        Comparison::Improved
+    } else {
+        Comparison::Within
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECKED_IN_BASELINE: &str = include_str!("../gas_baseline.txt");
+
+    fn entry(scenario: &str, gas: Option<u64>, tolerance_percent: u64) -> Entry {
+        Entry {
+            scenario: scenario.to_string(),
+            gas,
+            tolerance_percent,
+        }
+    }
+
+    #[test]
+    fn should_parse_measured_and_unmeasured_entries() {
+        let contents = "\
+# comment
+
+transfer   1000  5
+approve       -  2
+";
+        let baseline: Baseline = contents.parse().expect("should parse");
+        assert_eq!(
+            baseline.entries,
+            vec![entry("transfer", Some(1000), 5), entry("approve", None, 2)]
+        );
+        assert_eq!(baseline.get("approve"), Some(&entry("approve", None, 2)));
+        assert_eq!(baseline.get("allowance"), None);
+    }
+
+    #[test]
+    fn should_report_line_of_malformed_entry() {
+        for malformed in &["transfer 1000", "transfer many 2", "transfer 1000 2 3"] {
+            let contents = format!("# comment\n{}\n", malformed);
+            let error = contents.parse::<Baseline>().unwrap_err();
+            assert_eq!(error.line_number, 2, "{}", malformed);
+        }
+    }
+
+    #[test]
+    fn should_keep_checked_in_baseline_in_written_format() {
+        let baseline: Baseline = CHECKED_IN_BASELINE.parse().expect("should parse");
+        assert_eq!(baseline.to_string(), CHECKED_IN_BASELINE);
+    }
+
+    #[test]
+    fn should_read_missing_file_as_empty_baseline() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("missing_gas_baseline.txt");
+        let baseline = Baseline::read(&path).expect("should read");
+        assert!(baseline.entries.is_empty());
+    }
+
+    #[test]
+    fn should_write_parseable_baseline() {
+        let baseline = Baseline {
+            entries: vec![entry("transfer", Some(1000), 5), entry("approve", None, 2)],
+        };
+        let reparsed: Baseline = baseline.to_string().parse().expect("should parse");
+        assert_eq!(reparsed.entries, baseline.entries);
+    }
+
+    #[test]
+    fn should_compare_against_tolerance() {
+        let measured = entry("transfer", Some(1000), 5);
+        assert_eq!(compare(Some(&measured), 1000), Comparison::Within);
+        assert_eq!(compare(Some(&measured), 1050), Comparison::Within);
+        assert_eq!(compare(Some(&measured), 950), Comparison::Within);
+        assert_eq!(compare(Some(&measured), 1051), Comparison::Regressed);
+        assert_eq!(compare(Some(&measured), 949), Comparison::Improved);
+
+        let exact = entry("transfer", Some(1000), 0);
+        assert_eq!(compare(Some(&exact), 1000), Comparison::Within);
+        assert_eq!(compare(Some(&exact), 1001), Comparison::Regressed);
+        assert_eq!(compare(Some(&exact), 999), Comparison::Improved);
+    }
+
+    #[test]
+    fn should_fail_only_missing_and_regressed_comparisons() {
+        let unmeasured = entry("transfer", None, 5);
+        assert_eq!(compare(Some(&unmeasured), 1000), Comparison::Missing);
+        assert_eq!(compare(None, 1000), Comparison::Missing);
+
+        assert!(Comparison::Missing.is_failure());
+        assert!(Comparison::Regressed.is_failure());
+        assert!(!Comparison::Within.is_failure());
+        assert!(!Comparison::Improved.is_failure());
+    }
+
+    #[test]
+    fn should_bless_measurements_keeping_known_tolerances() {
+        let baseline = Baseline {
+            entries: vec![
+                entry("transfer", Some(1000), 5),
+                entry("removed", Some(10), 7),
+            ],
+        };
+        let blessed = baseline.bless(&[("approve", 400), ("transfer", 1200)]);
+        assert_eq!(
+            blessed.entries,
+            vec![
+                entry("approve", Some(400), DEFAULT_TOLERANCE_PERCENT),
+                entry("transfer", Some(1200), 5),
+            ]
+        );
+    }
+}
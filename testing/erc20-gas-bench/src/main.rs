@@ -0,0 +1,274 @@
+//! Measures gas consumed by each entry point of the example ERC20 token, and compares it against
+//! the checked-in baseline in `gas_baseline.txt`.
+//!
+//! Exits with a failure if any scenario costs more than its tolerance allows, or is missing from
+//! the baseline. Run with `--bless` to write the measured costs into the baseline instead, keeping
+//! the tolerances of known scenarios.
+//!
+//! The token wasm is read from the workspace target directory, so `make build-contracts` has to be
+//! run first.
+mod baseline;
+
+use std::{env, path::PathBuf, process};
+
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_erc20::constants::{
+    ADDRESS_RUNTIME_ARG_NAME, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT_RUNTIME_ARG_NAME,
+    APPROVE_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, DECIMALS_ENTRY_POINT_NAME,
+    DECIMALS_RUNTIME_ARG_NAME, ERC20_TOKEN_CONTRACT_KEY_NAME, NAME_ENTRY_POINT_NAME,
+    NAME_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+    SPENDER_RUNTIME_ARG_NAME, SYMBOL_ENTRY_POINT_NAME, SYMBOL_RUNTIME_ARG_NAME,
+    TOTAL_SUPPLY_ENTRY_POINT_NAME, TOTAL_SUPPLY_RUNTIME_ARG_NAME, TRANSFER_ENTRY_POINT_NAME,
+    TRANSFER_FROM_ENTRY_POINT_NAME,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash, runtime_args, system::mint, ContractHash, Key, PublicKey, RuntimeArgs,
+    SecretKey, U256,
+};
+
+use baseline::{Baseline, Comparison};
+
+const EXAMPLE_ERC20_TOKEN_WASM: &str = "erc20_token.wasm";
+const CONTRACT_TARGET_DIR: &str = "../../target/wasm32-unknown-unknown/release";
+const BASELINE_FILE_NAME: &str = "gas_baseline.txt";
+const BLESS_FLAG: &str = "--bless";
+
+const TOKEN_NAME: &str = "CasperTest";
+const TOKEN_SYMBOL: &str = "CSPRT";
+const TOKEN_DECIMALS: u8 = 8;
+const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+
+const TRANSFER_AMOUNT: u64 = 200_001;
+const ALLOWANCE_AMOUNT: u64 = 456_789;
+
+static ACCOUNT_1_SECRET_KEY: Lazy<SecretKey> =
+    Lazy::new(|| SecretKey::secp256k1_from_bytes(&[221u8; 32]).unwrap());
+static ACCOUNT_1_ADDR: Lazy<AccountHash> =
+    Lazy::new(|| PublicKey::from(&*ACCOUNT_1_SECRET_KEY).to_account_hash());
+
+static ACCOUNT_2_SECRET_KEY: Lazy<SecretKey> =
+    Lazy::new(|| SecretKey::secp256k1_from_bytes(&[212u8; 32]).unwrap());
+static ACCOUNT_2_ADDR: Lazy<AccountHash> =
+    Lazy::new(|| PublicKey::from(&*ACCOUNT_2_SECRET_KEY).to_account_hash());
+
+/// Token installed on a fresh chain, recording gas consumed by each measured deploy.
+struct Bench {
+    builder: InMemoryWasmTestBuilder,
+    token: Option<ContractHash>,
+    measurements: Vec<(&'static str, u64)>,
+}
+
+impl Bench {
+    fn new() -> Self {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+        for account in [*ACCOUNT_1_ADDR, *ACCOUNT_2_ADDR] {
+            let id: Option<u64> = None;
+            let transfer_args = runtime_args! {
+                mint::ARG_TARGET => account,
+                mint::ARG_AMOUNT => MINIMUM_ACCOUNT_CREATION_BALANCE,
+                mint::ARG_ID => id,
+            };
+            let transfer_request =
+                ExecuteRequestBuilder::transfer(*DEFAULT_ACCOUNT_ADDR, transfer_args).build();
+            builder.exec(transfer_request).expect_success().commit();
+        }
+
+        Bench {
+            builder,
+            token: None,
+            measurements: Vec::new(),
+        }
+    }
+
+    fn measure(&mut self, scenario: &'static str, exec_request: ExecuteRequest) {
+        self.builder.exec(exec_request).expect_success().commit();
+        let gas = self.builder.last_exec_gas_cost().value().as_u64();
+        self.measurements.push((scenario, gas));
+    }
+
+    fn install(&mut self) {
+        let token_wasm = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join(CONTRACT_TARGET_DIR)
+            .join(EXAMPLE_ERC20_TOKEN_WASM);
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            token_wasm.to_str().expect("should have utf-8 path"),
+            runtime_args! {
+                NAME_RUNTIME_ARG_NAME => TOKEN_NAME,
+                SYMBOL_RUNTIME_ARG_NAME => TOKEN_SYMBOL,
+                DECIMALS_RUNTIME_ARG_NAME => TOKEN_DECIMALS,
+                TOTAL_SUPPLY_RUNTIME_ARG_NAME => U256::from(TOKEN_TOTAL_SUPPLY),
+            },
+        )
+        .build();
+        self.measure("install", install_request);
+
+        let token = self
+            .builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("should have account")
+            .named_keys()
+            .get(ERC20_TOKEN_CONTRACT_KEY_NAME)
+            .and_then(|key| key.into_hash())
+            .map(ContractHash::new)
+            .expect("should have token contract hash");
+        self.token = Some(token);
+    }
+
+    fn call(
+        &mut self,
+        scenario: &'static str,
+        sender: AccountHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) {
+        let token = self.token.expect("should install the token first");
+        let exec_request =
+            ExecuteRequestBuilder::contract_call_by_hash(sender, token, entry_point, args).build();
+        self.measure(scenario, exec_request);
+    }
+
+    /// Runs every scenario once, in an order where each one sets up the state for the next.
+    fn run(&mut self) {
+        let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+        let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+        let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+        let transfer_amount = U256::from(TRANSFER_AMOUNT);
+        let allowance_amount = U256::from(ALLOWANCE_AMOUNT);
+
+        self.install();
+
+        for (scenario, entry_point) in [
+            ("name", NAME_ENTRY_POINT_NAME),
+            ("symbol", SYMBOL_ENTRY_POINT_NAME),
+            ("decimals", DECIMALS_ENTRY_POINT_NAME),
+            ("total_supply", TOTAL_SUPPLY_ENTRY_POINT_NAME),
+        ] {
+            self.call(
+                scenario,
+                *DEFAULT_ACCOUNT_ADDR,
+                entry_point,
+                RuntimeArgs::default(),
+            );
+        }
+
+        self.call(
+            "balance_of",
+            *DEFAULT_ACCOUNT_ADDR,
+            BALANCE_OF_ENTRY_POINT_NAME,
+            runtime_args! {
+                ADDRESS_RUNTIME_ARG_NAME => owner,
+            },
+        );
+
+        // Creating a dictionary item costs more than updating one, so both paths are measured.
+        for scenario in ["transfer_to_new_recipient", "transfer_to_existing_recipient"] {
+            self.call(
+                scenario,
+                *DEFAULT_ACCOUNT_ADDR,
+                TRANSFER_ENTRY_POINT_NAME,
+                runtime_args! {
+                    RECIPIENT_RUNTIME_ARG_NAME => account_1,
+                    AMOUNT_RUNTIME_ARG_NAME => transfer_amount,
+                },
+            );
+        }
+
+        for scenario in ["approve_new_allowance", "approve_existing_allowance"] {
+            self.call(
+                scenario,
+                *DEFAULT_ACCOUNT_ADDR,
+                APPROVE_ENTRY_POINT_NAME,
+                runtime_args! {
+                    SPENDER_RUNTIME_ARG_NAME => account_1,
+                    AMOUNT_RUNTIME_ARG_NAME => allowance_amount,
+                },
+            );
+        }
+
+        self.call(
+            "allowance",
+            *DEFAULT_ACCOUNT_ADDR,
+            ALLOWANCE_ENTRY_POINT_NAME,
+            runtime_args! {
+                OWNER_RUNTIME_ARG_NAME => owner,
+                SPENDER_RUNTIME_ARG_NAME => account_1,
+            },
+        );
+
+        self.call(
+            "transfer_from",
+            *ACCOUNT_1_ADDR,
+            TRANSFER_FROM_ENTRY_POINT_NAME,
+            runtime_args! {
+                OWNER_RUNTIME_ARG_NAME => owner,
+                RECIPIENT_RUNTIME_ARG_NAME => account_2,
+                AMOUNT_RUNTIME_ARG_NAME => transfer_amount,
+            },
+        );
+    }
+}
+
+fn main() {
+    let bless = env::args().skip(1).any(|arg| arg == BLESS_FLAG);
+    let baseline_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(BASELINE_FILE_NAME);
+    let baseline = Baseline::read(&baseline_path).unwrap_or_else(|error| {
+        eprintln!("failed to read {}: {}", baseline_path.display(), error);
+        process::exit(2);
+    });
+
+    let mut bench = Bench::new();
+    bench.run();
+
+    println!(
+        "{:<32} {:>12} {:>12} {:>9}  result",
+        "scenario", "baseline", "measured", "delta"
+    );
+    let mut failures = 0;
+    for (scenario, measured) in &bench.measurements {
+        let entry = baseline.get(scenario);
+        let comparison = baseline::compare(entry, *measured);
+        let (expected, delta) = match entry.and_then(|entry| entry.gas) {
+            Some(expected) => {
+                let delta = (*measured as f64 - expected as f64) / expected as f64 * 100.0;
+                (expected.to_string(), format!("{:+.2}%", delta))
+            }
+            None => ("-".to_string(), "-".to_string()),
+        };
+        println!(
+            "{:<32} {:>12} {:>12} {:>9}  {}",
+            scenario, expected, measured, delta, comparison
+        );
+        if comparison.is_failure() {
+            failures += 1;
+        }
+        if comparison == Comparison::Improved && !bless {
+            println!("    cheaper than the baseline allows; run with {}", BLESS_FLAG);
+        }
+    }
+
+    if bless {
+        baseline
+            .bless(&bench.measurements)
+            .write(&baseline_path)
+            .unwrap_or_else(|error| {
+                eprintln!("failed to write {}: {}", baseline_path.display(), error);
+                process::exit(2);
+            });
+        println!("wrote {}", baseline_path.display());
+    } else if failures > 0 {
+        eprintln!(
+            "{} scenario(s) exceed the gas baseline; run with {} if the change is intended",
+            failures, BLESS_FLAG
+        );
+        process::exit(1);
+    }
+}
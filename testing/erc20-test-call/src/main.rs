@@ -6,6 +6,7 @@ extern crate alloc;
 use alloc::{
     string::{String, ToString},
     vec,
+    vec::Vec,
 };
 
 use casper_contract::{
@@ -14,14 +15,15 @@ use casper_contract::{
 };
 use casper_erc20::{
     constants::{
-        AMOUNT_RUNTIME_ARG_NAME, APPROVE_ENTRY_POINT_NAME, RECIPIENT_RUNTIME_ARG_NAME,
-        TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+        ADDRESSES_RUNTIME_ARG_NAME, ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME,
+        INDEX_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME, PAIRS_RUNTIME_ARG_NAME,
+        RECIPIENT_RUNTIME_ARG_NAME, ROLE_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME,
     },
-    Address,
+    Address, Erc20Ref,
 };
 use casper_types::{
-    bytesrepr::ToBytes, runtime_args, CLTyped, ContractHash, EntryPoint, EntryPointAccess,
-    EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
+    bytesrepr::ToBytes, CLTyped, ContractHash, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Key, Parameter, U256,
 };
 
 const CHECK_TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "check_total_supply";
@@ -30,10 +32,12 @@ const TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_as_stored_c
 const APPROVE_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "approve_as_stored_contract";
 const TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_from_as_stored_contract";
 const CHECK_ALLOWANCE_OF_ENTRY_POINT_NAME: &str = "check_allowance_of";
+const CHECK_FORCE_TRANSFER_RECORD_ENTRY_POINT_NAME: &str = "check_force_transfer_record";
+const CHECK_HOLDER_AT_ENTRY_POINT_NAME: &str = "check_holder_at";
+const CHECK_BALANCES_OF_ENTRY_POINT_NAME: &str = "check_balances_of";
+const CHECK_ALLOWANCES_OF_ENTRY_POINT_NAME: &str = "check_allowances_of";
+const CHECK_HAS_ROLE_ENTRY_POINT_NAME: &str = "check_has_role";
 const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
-const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
-const OWNER_RUNTIME_ARG_NAME: &str = "owner";
-const SPENDER_RUNTIME_ARG_NAME: &str = "spender";
 const RESULT_KEY: &str = "result";
 const ERC20_TEST_CALL_KEY: &str = "erc20_test_call";
 
@@ -51,11 +55,7 @@ fn store_result<T: CLTyped + ToBytes>(result: T) {
 #[no_mangle]
 extern "C" fn check_total_supply() {
     let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
-    let total_supply: U256 = runtime::call_contract(
-        token_contract,
-        casper_erc20::constants::TOTAL_SUPPLY_ENTRY_POINT_NAME,
-        RuntimeArgs::default(),
-    );
+    let total_supply = Erc20Ref::new(token_contract).total_supply();
     store_result(total_supply);
 }
 
@@ -64,14 +64,7 @@ extern "C" fn check_balance_of() {
     let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
     let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
 
-    let balance_args = runtime_args! {
-        casper_erc20::constants::ADDRESS_RUNTIME_ARG_NAME => address,
-    };
-    let result: U256 = runtime::call_contract(
-        token_contract,
-        casper_erc20::constants::BALANCE_OF_ENTRY_POINT_NAME,
-        balance_args,
-    );
+    let result = Erc20Ref::new(token_contract).balance_of(address);
 
     store_result(result);
 }
@@ -82,15 +75,58 @@ extern "C" fn check_allowance_of() {
     let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
     let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
 
-    let allowance_args = runtime_args! {
-        casper_erc20::constants::OWNER_RUNTIME_ARG_NAME => owner,
-        casper_erc20::constants::SPENDER_RUNTIME_ARG_NAME => spender,
-    };
-    let result: U256 = runtime::call_contract(
-        token_contract,
-        casper_erc20::constants::ALLOWANCE_ENTRY_POINT_NAME,
-        allowance_args,
-    );
+    let result = Erc20Ref::new(token_contract).allowance(owner, spender);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_balances_of() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let addresses: Vec<Address> = runtime::get_named_arg(ADDRESSES_RUNTIME_ARG_NAME);
+
+    let result = Erc20Ref::new(token_contract).balances_of(addresses);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_allowances_of() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let pairs: Vec<(Address, Address)> = runtime::get_named_arg(PAIRS_RUNTIME_ARG_NAME);
+
+    let result = Erc20Ref::new(token_contract).allowances_of(pairs);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_has_role() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+
+    let result = Erc20Ref::new(token_contract).has_role(&role, address);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_force_transfer_record() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+
+    let result = Erc20Ref::new(token_contract).force_transfer_record(index);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_holder_at() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+
+    let result = Erc20Ref::new(token_contract).holder_at(index);
 
     store_result(result);
 }
@@ -101,12 +137,7 @@ extern "C" fn transfer_as_stored_contract() {
     let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
 
-    let transfer_args = runtime_args! {
-        RECIPIENT_RUNTIME_ARG_NAME => recipient,
-        AMOUNT_RUNTIME_ARG_NAME => amount,
-    };
-
-    runtime::call_contract::<()>(token_contract, TRANSFER_ENTRY_POINT_NAME, transfer_args);
+    Erc20Ref::new(token_contract).transfer(recipient, amount);
 }
 
 #[no_mangle]
@@ -116,17 +147,7 @@ extern "C" fn transfer_from_as_stored_contract() {
     let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
 
-    let transfer_from_args = runtime_args! {
-        OWNER_RUNTIME_ARG_NAME => owner,
-        RECIPIENT_RUNTIME_ARG_NAME => recipient,
-        AMOUNT_RUNTIME_ARG_NAME => amount,
-    };
-
-    runtime::call_contract::<()>(
-        token_contract,
-        TRANSFER_FROM_ENTRY_POINT_NAME,
-        transfer_from_args,
-    );
+    Erc20Ref::new(token_contract).transfer_from(owner, recipient, amount);
 }
 
 #[no_mangle]
@@ -135,12 +156,7 @@ extern "C" fn approve_as_stored_contract() {
     let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
 
-    let approve_args = runtime_args! {
-        SPENDER_RUNTIME_ARG_NAME => spender,
-        AMOUNT_RUNTIME_ARG_NAME => amount,
-    };
-
-    runtime::call_contract::<()>(token_contract, APPROVE_ENTRY_POINT_NAME, approve_args);
+    Erc20Ref::new(token_contract).approve(spender, amount);
 }
 
 #[no_mangle]
@@ -177,6 +193,57 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_balances_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_BALANCES_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESSES_RUNTIME_ARG_NAME, Vec::<Address>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_allowances_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_ALLOWANCES_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(PAIRS_RUNTIME_ARG_NAME, Vec::<(Address, Address)>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_has_role_entrypoint = EntryPoint::new(
+        String::from(CHECK_HAS_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_force_transfer_record_entrypoint = EntryPoint::new(
+        String::from(CHECK_FORCE_TRANSFER_RECORD_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_holder_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_HOLDER_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
 
     let transfer_as_stored_contract_entrypoint = EntryPoint::new(
         String::from(TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
@@ -218,6 +285,11 @@ pub extern "C" fn call() {
     entry_points.add_entry_point(check_total_supply_entrypoint);
     entry_points.add_entry_point(check_balance_of_entrypoint);
     entry_points.add_entry_point(check_allowance_of_entrypoint);
+    entry_points.add_entry_point(check_balances_of_entrypoint);
+    entry_points.add_entry_point(check_allowances_of_entrypoint);
+    entry_points.add_entry_point(check_has_role_entrypoint);
+    entry_points.add_entry_point(check_force_transfer_record_entrypoint);
+    entry_points.add_entry_point(check_holder_at_entrypoint);
     entry_points.add_entry_point(transfer_as_stored_contract_entrypoint);
     entry_points.add_entry_point(approve_as_stored_contract_entrypoint);
     entry_points.add_entry_point(transfer_from_as_stored_contract_entrypoint);
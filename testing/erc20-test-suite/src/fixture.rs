@@ -0,0 +1,303 @@
+//! Installed token together with the accounts and contracts used to exercise it.
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_erc20::constants::{
+    ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, APPROVE_ENTRY_POINT_NAME,
+    OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME,
+    TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+};
+use casper_execution_engine::core::{
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::FromBytes, contracts::NamedKeys, runtime_args, system::mint,
+    ApiError, CLTyped, ContractHash, ContractPackageHash, Key, PublicKey, RuntimeArgs, SecretKey,
+    U256,
+};
+
+use crate::Erc20TestSuite;
+
+const CHECK_TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "check_total_supply";
+const CHECK_BALANCE_OF_ENTRY_POINT_NAME: &str = "check_balance_of";
+const CHECK_ALLOWANCE_OF_ENTRY_POINT_NAME: &str = "check_allowance_of";
+const TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_as_stored_contract";
+const APPROVE_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "approve_as_stored_contract";
+const TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_from_as_stored_contract";
+const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
+const RESULT_KEY_NAME: &str = "result";
+const ERC20_TEST_CALL_KEY_NAME: &str = "erc20_test_call";
+
+static ACCOUNT_1_SECRET_KEY: Lazy<SecretKey> =
+    Lazy::new(|| SecretKey::secp256k1_from_bytes(&[221u8; 32]).unwrap());
+/// First of the accounts funded by [`new_builder`].
+pub static ACCOUNT_1_ADDR: Lazy<AccountHash> =
+    Lazy::new(|| PublicKey::from(&*ACCOUNT_1_SECRET_KEY).to_account_hash());
+
+static ACCOUNT_2_SECRET_KEY: Lazy<SecretKey> =
+    Lazy::new(|| SecretKey::secp256k1_from_bytes(&[212u8; 32]).unwrap());
+/// Second of the accounts funded by [`new_builder`].
+pub static ACCOUNT_2_ADDR: Lazy<AccountHash> =
+    Lazy::new(|| PublicKey::from(&*ACCOUNT_2_SECRET_KEY).to_account_hash());
+
+/// Contract address which never calls the token, and only receives tokens.
+const PASSIVE_CONTRACT_ADDRESS: Key = Key::Hash([42; 32]);
+
+/// Returns a builder past genesis, in which [`ACCOUNT_1_ADDR`] and [`ACCOUNT_2_ADDR`] are able to
+/// send deploys.
+pub fn new_builder() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+    for account in [*ACCOUNT_1_ADDR, *ACCOUNT_2_ADDR] {
+        let id: Option<u64> = None;
+        let transfer_args = runtime_args! {
+            mint::ARG_TARGET => account,
+            mint::ARG_AMOUNT => MINIMUM_ACCOUNT_CREATION_BALANCE,
+            mint::ARG_ID => id,
+        };
+        let transfer_request =
+            ExecuteRequestBuilder::transfer(*DEFAULT_ACCOUNT_ADDR, transfer_args).build();
+        builder.exec(transfer_request).expect_success().commit();
+    }
+
+    builder
+}
+
+/// Installed token under test, together with the contract used to call it as a contract.
+pub struct Fixture {
+    builder: InMemoryWasmTestBuilder,
+    token: ContractHash,
+    test_call: ContractPackageHash,
+}
+
+impl Fixture {
+    /// Installs the token under test, together with the contract used to call it as a contract.
+    pub(crate) fn install(suite: &Erc20TestSuite) -> Fixture {
+        let mut builder = new_builder();
+
+        let install_token_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &suite.token_wasm,
+            suite.install_args.clone(),
+        )
+        .build();
+        let install_test_call_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            &suite.test_call_wasm,
+            RuntimeArgs::default(),
+        )
+        .build();
+        builder
+            .exec(install_token_request)
+            .expect_success()
+            .commit();
+        builder
+            .exec(install_test_call_request)
+            .expect_success()
+            .commit();
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("should have account");
+        let token = account
+            .named_keys()
+            .get(&suite.contract_key_name)
+            .and_then(|key| key.into_hash())
+            .map(ContractHash::new)
+            .expect("should have token contract hash");
+        let test_call = account
+            .named_keys()
+            .get(ERC20_TEST_CALL_KEY_NAME)
+            .and_then(|key| key.into_hash())
+            .map(ContractPackageHash::new)
+            .expect("should have test call contract package hash");
+
+        Fixture {
+            builder,
+            token,
+            test_call,
+        }
+    }
+
+    /// Builder holding the global state, for requests the fixture has no helper for.
+    pub fn builder(&mut self) -> &mut InMemoryWasmTestBuilder {
+        &mut self.builder
+    }
+
+    /// Contract hash of the token under test.
+    pub fn token(&self) -> ContractHash {
+        self.token
+    }
+
+    /// Account which installed the token, and is expected to hold tokens afterwards.
+    pub fn installer(&self) -> Key {
+        Key::Account(*DEFAULT_ACCOUNT_ADDR)
+    }
+
+    /// Named keys of the installer's account after the install.
+    pub fn installer_named_keys(&self) -> NamedKeys {
+        self.builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("should have account")
+            .named_keys()
+            .clone()
+    }
+
+    /// Accounts which are able to send deploys, and hold no tokens after install.
+    pub fn accounts(&self) -> [Key; 2] {
+        [Key::Account(*ACCOUNT_1_ADDR), Key::Account(*ACCOUNT_2_ADDR)]
+    }
+
+    /// Contract which is able to call the token, and holds no tokens after install.
+    pub fn calling_contract(&self) -> Key {
+        Key::Hash(self.test_call.value())
+    }
+
+    /// Contract which holds no tokens after install.
+    pub fn passive_contract(&self) -> Key {
+        PASSIVE_CONTRACT_ADDRESS
+    }
+
+    /// Executes and commits `exec_request`, returning the error it reverted with if any.
+    pub fn exec(&mut self, exec_request: ExecuteRequest) -> Result<(), ApiError> {
+        self.builder.exec(exec_request).commit();
+        match self.builder.get_error() {
+            None => Ok(()),
+            Some(CoreError::Exec(ExecError::Revert(api_error))) => Err(api_error),
+            Some(error) => panic!("unexpected execution error: {:?}", error),
+        }
+    }
+
+    fn query<T: CLTyped + FromBytes>(&mut self, entry_point: &str, args: RuntimeArgs) -> T {
+        let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            self.test_call,
+            None,
+            entry_point,
+            args,
+        )
+        .build();
+        self.builder.exec(exec_request).expect_success().commit();
+
+        let contract_package = self
+            .builder
+            .get_contract_package(self.test_call)
+            .expect("should have contract package");
+        let (_version, contract_hash) = contract_package
+            .enabled_versions()
+            .iter()
+            .rev()
+            .next()
+            .expect("should have latest version");
+        self.builder.get_value(*contract_hash, RESULT_KEY_NAME)
+    }
+
+    pub fn total_supply(&mut self) -> U256 {
+        let args = runtime_args! {
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME => self.token,
+        };
+        self.query(CHECK_TOTAL_SUPPLY_ENTRY_POINT_NAME, args)
+    }
+
+    pub fn balance_of(&mut self, address: Key) -> U256 {
+        let args = runtime_args! {
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME => self.token,
+            ADDRESS_RUNTIME_ARG_NAME => address,
+        };
+        self.query(CHECK_BALANCE_OF_ENTRY_POINT_NAME, args)
+    }
+
+    pub fn allowance(&mut self, owner: Key, spender: Key) -> U256 {
+        let args = runtime_args! {
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME => self.token,
+            OWNER_RUNTIME_ARG_NAME => owner,
+            SPENDER_RUNTIME_ARG_NAME => spender,
+        };
+        self.query(CHECK_ALLOWANCE_OF_ENTRY_POINT_NAME, args)
+    }
+
+    /// Calls `entry_point` of the token either directly from an account, or through the calling
+    /// contract.
+    fn call_as(
+        &mut self,
+        caller: Key,
+        entry_point: &str,
+        as_stored_contract_entry_point: &str,
+        mut args: RuntimeArgs,
+    ) -> Result<(), ApiError> {
+        let exec_request = match caller {
+            Key::Account(account_hash) => ExecuteRequestBuilder::contract_call_by_hash(
+                account_hash,
+                self.token,
+                entry_point,
+                args,
+            )
+            .build(),
+            Key::Hash(contract_package_hash) if contract_package_hash == self.test_call.value() => {
+                args.insert(TOKEN_CONTRACT_RUNTIME_ARG_NAME, self.token)
+                    .expect("should insert token contract");
+                ExecuteRequestBuilder::versioned_contract_call_by_hash(
+                    *DEFAULT_ACCOUNT_ADDR,
+                    self.test_call,
+                    None,
+                    as_stored_contract_entry_point,
+                    args,
+                )
+                .build()
+            }
+            _ => panic!("{:?} is not able to call the token", caller),
+        };
+        self.exec(exec_request)
+    }
+
+    pub fn transfer(&mut self, sender: Key, recipient: Key, amount: U256) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            RECIPIENT_RUNTIME_ARG_NAME => recipient,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+        };
+        self.call_as(
+            sender,
+            TRANSFER_ENTRY_POINT_NAME,
+            TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME,
+            args,
+        )
+    }
+
+    pub fn approve(&mut self, owner: Key, spender: Key, amount: U256) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            SPENDER_RUNTIME_ARG_NAME => spender,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+        };
+        self.call_as(
+            owner,
+            APPROVE_ENTRY_POINT_NAME,
+            APPROVE_AS_STORED_CONTRACT_ENTRY_POINT_NAME,
+            args,
+        )
+    }
+
+    pub fn transfer_from(
+        &mut self,
+        spender: Key,
+        owner: Key,
+        recipient: Key,
+        amount: U256,
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            OWNER_RUNTIME_ARG_NAME => owner,
+            RECIPIENT_RUNTIME_ARG_NAME => recipient,
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+        };
+        self.call_as(
+            spender,
+            TRANSFER_FROM_ENTRY_POINT_NAME,
+            TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME,
+            args,
+        )
+    }
+}
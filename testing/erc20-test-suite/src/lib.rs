@@ -0,0 +1,350 @@
+//! Conformance test suite for ERC20 tokens built with the `casper-erc20` library.
+//!
+//! Runs the standard ERC20 behavior matrix against any token wasm: transfers between account and
+//! contract senders and recipients, allowances, zero amounts, self-transfers and error codes.
+//!
+//! The suite calls the token as a contract through the `erc20_test_call.wasm` contract, which is
+//! built from `testing/erc20-test-call` in this repository and has to be available to the test
+//! runner, same as the token wasm itself.
+//!
+//! ```ignore
+//! #[test]
+//! fn should_conform_to_erc20() {
+//!     Erc20TestSuite::new(
+//!         "my_token.wasm",
+//!         runtime_args! {
+//!             "name" => "MyToken",
+//!             "symbol" => "MTK",
+//!             "decimals" => 8u8,
+//!             "total_supply" => U256::from(1_000_000_000u64),
+//!         },
+//!     )
+//!     .run();
+//! }
+//! ```
+//!
+//! Every check is also public on its own, so that calling each from a separate test reports every
+//! failing check instead of only the first one.
+//!
+//! The token is installed by `DEFAULT_ACCOUNT_ADDR`, which is expected to hold at least
+//! [`MIN_INSTALLER_BALANCE`] tokens afterwards.
+//!
+//! Tests of token specific behavior are able to reuse the same setup through
+//! [`Erc20TestSuite::install`], which returns the installed [`fixture::Fixture`].
+
+pub mod fixture;
+
+use casper_erc20::{
+    constants::{ALLOWANCES_KEY_NAME, BALANCES_KEY_NAME, ERC20_TOKEN_CONTRACT_KEY_NAME},
+    Error,
+};
+use casper_types::{account::AccountHash, ApiError, Key, RuntimeArgs, U256};
+
+use fixture::Fixture;
+
+/// Name of the contract used by the suite to call the token as a contract.
+pub const DEFAULT_TEST_CALL_WASM: &str = "erc20_test_call.wasm";
+
+/// Least amount of tokens the installer is expected to hold right after the install.
+pub const MIN_INSTALLER_BALANCE: u64 = 1_000_000;
+
+const FUNDING_AMOUNT: u64 = 100_000;
+const TRANSFER_AMOUNT: u64 = 20_001;
+const ALLOWANCE_AMOUNT_1: u64 = 45_678;
+const ALLOWANCE_AMOUNT_2: u64 = 8_765;
+
+/// Runs the ERC20 conformance checks against a token wasm.
+///
+/// Every check installs a fresh copy of the token, and panics on the first deviation from the
+/// standard.
+pub struct Erc20TestSuite {
+    token_wasm: String,
+    install_args: RuntimeArgs,
+    contract_key_name: String,
+    test_call_wasm: String,
+}
+
+impl Erc20TestSuite {
+    /// Creates a suite installing `token_wasm` with `install_args`.
+    ///
+    /// `token_wasm` is either a file name looked up the same way as other test contracts, or a
+    /// path to the wasm file.
+    pub fn new(token_wasm: &str, install_args: RuntimeArgs) -> Self {
+        Erc20TestSuite {
+            token_wasm: token_wasm.to_string(),
+            install_args,
+            contract_key_name: ERC20_TOKEN_CONTRACT_KEY_NAME.to_string(),
+            test_call_wasm: DEFAULT_TEST_CALL_WASM.to_string(),
+        }
+    }
+
+    /// Sets the name of the installer's named key under which the token stores its contract hash.
+    ///
+    /// Defaults to [`ERC20_TOKEN_CONTRACT_KEY_NAME`].
+    pub fn with_contract_key_name(mut self, contract_key_name: &str) -> Self {
+        self.contract_key_name = contract_key_name.to_string();
+        self
+    }
+
+    /// Sets the contract used to call the token as a contract.
+    ///
+    /// Defaults to [`DEFAULT_TEST_CALL_WASM`].
+    pub fn with_test_call_wasm(mut self, test_call_wasm: &str) -> Self {
+        self.test_call_wasm = test_call_wasm.to_string();
+        self
+    }
+
+    /// Runs all of the checks.
+    pub fn run(&self) {
+        self.check_installer_balance();
+        self.check_installer_named_keys();
+        self.check_transfers();
+        self.check_full_balance_transfers();
+        self.check_self_transfers();
+        self.check_zero_amount_transfers();
+        self.check_insufficient_balance();
+        self.check_allowances();
+        self.check_insufficient_allowance();
+    }
+
+    /// Installs a fresh copy of the token, for checks beyond the ones of the suite.
+    pub fn install(&self) -> Fixture {
+        Fixture::install(self)
+    }
+
+    /// Checks that the installer holds tokens, and that nobody else does.
+    pub fn check_installer_balance(&self) {
+        let mut fixture = self.install();
+
+        let installer = fixture.installer();
+        let total_supply = fixture.total_supply();
+        let installer_balance = fixture.balance_of(installer);
+        assert!(installer_balance >= U256::from(MIN_INSTALLER_BALANCE));
+        assert!(installer_balance <= total_supply);
+
+        for address in [
+            fixture.accounts()[0],
+            fixture.accounts()[1],
+            fixture.calling_contract(),
+            fixture.passive_contract(),
+            invert_address(installer),
+        ] {
+            assert_eq!(fixture.balance_of(address), U256::zero(), "{:?}", address);
+        }
+    }
+
+    /// Checks that the token keeps its balances and allowances to itself, rather than under the
+    /// installer's named keys.
+    pub fn check_installer_named_keys(&self) {
+        let fixture = self.install();
+
+        let named_keys = fixture.installer_named_keys();
+        assert!(
+            !named_keys.contains_key(BALANCES_KEY_NAME),
+            "{:?}",
+            named_keys
+        );
+        assert!(
+            !named_keys.contains_key(ALLOWANCES_KEY_NAME),
+            "{:?}",
+            named_keys
+        );
+    }
+
+    /// Checks transfers between every combination of account and contract senders and recipients.
+    pub fn check_transfers(&self) {
+        let senders = |fixture: &Fixture| [fixture.accounts()[0], fixture.calling_contract()];
+        let recipients = |fixture: &Fixture| [fixture.accounts()[1], fixture.passive_contract()];
+
+        for sender_index in 0..2 {
+            for recipient_index in 0..2 {
+                let mut fixture = self.install();
+                let sender = senders(&fixture)[sender_index];
+                let recipient = recipients(&fixture)[recipient_index];
+                let total_supply = fixture.total_supply();
+                let amount = U256::from(TRANSFER_AMOUNT);
+
+                fund(&mut fixture, sender);
+                fixture
+                    .transfer(sender, recipient, amount)
+                    .unwrap_or_else(|error| panic!("{:?} -> {:?}: {:?}", sender, recipient, error));
+
+                assert_eq!(
+                    fixture.balance_of(sender),
+                    U256::from(FUNDING_AMOUNT) - amount
+                );
+                assert_eq!(fixture.balance_of(recipient), amount);
+                // Balances are keyed by the whole address, and not only by its hash.
+                assert_eq!(fixture.balance_of(invert_address(recipient)), U256::zero());
+                assert_eq!(fixture.total_supply(), total_supply);
+            }
+        }
+    }
+
+    /// Checks that a sender is able to transfer its whole balance, and to get it back.
+    pub fn check_full_balance_transfers(&self) {
+        let mut fixture = self.install();
+        let [sender, recipient] = fixture.accounts();
+        let amount = U256::from(FUNDING_AMOUNT);
+
+        fund(&mut fixture, sender);
+        fixture.transfer(sender, recipient, amount).unwrap();
+        assert_eq!(fixture.balance_of(sender), U256::zero());
+        assert_eq!(fixture.balance_of(recipient), amount);
+
+        fixture.transfer(recipient, sender, amount).unwrap();
+        assert_eq!(fixture.balance_of(sender), amount);
+        assert_eq!(fixture.balance_of(recipient), U256::zero());
+    }
+
+    /// Checks that transfers to self, both direct and through an allowance, keep the balance.
+    pub fn check_self_transfers(&self) {
+        let mut fixture = self.install();
+        let owner = fixture.accounts()[0];
+        let spender = fixture.calling_contract();
+        let amount = U256::from(TRANSFER_AMOUNT);
+
+        fund(&mut fixture, owner);
+        fixture.transfer(owner, owner, amount).unwrap();
+        assert_eq!(fixture.balance_of(owner), U256::from(FUNDING_AMOUNT));
+
+        fixture.approve(owner, spender, amount).unwrap();
+        fixture
+            .transfer_from(spender, owner, owner, amount)
+            .unwrap();
+        assert_eq!(fixture.balance_of(owner), U256::from(FUNDING_AMOUNT));
+        assert_eq!(fixture.allowance(owner, spender), U256::zero());
+    }
+
+    /// Checks that zero amount transfers succeed without a balance or an allowance.
+    pub fn check_zero_amount_transfers(&self) {
+        let mut fixture = self.install();
+        let [sender, recipient] = fixture.accounts();
+        let spender = fixture.calling_contract();
+
+        fixture.transfer(sender, recipient, U256::zero()).unwrap();
+        fixture.transfer(spender, recipient, U256::zero()).unwrap();
+        fixture
+            .transfer_from(spender, sender, recipient, U256::zero())
+            .unwrap();
+
+        assert_eq!(fixture.balance_of(sender), U256::zero());
+        assert_eq!(fixture.balance_of(recipient), U256::zero());
+        assert_eq!(fixture.balance_of(spender), U256::zero());
+    }
+
+    /// Checks that spending above the balance fails with [`Error::InsufficientBalance`].
+    pub fn check_insufficient_balance(&self) {
+        let mut fixture = self.install();
+        let [sender, recipient] = fixture.accounts();
+        let contract_sender = fixture.calling_contract();
+
+        for sender in [sender, contract_sender] {
+            fund(&mut fixture, sender);
+            let amount = U256::from(FUNDING_AMOUNT) + U256::one();
+            assert_eq!(
+                fixture.transfer(sender, recipient, amount),
+                Err(ApiError::from(Error::InsufficientBalance)),
+                "{:?}",
+                sender
+            );
+            assert_eq!(fixture.balance_of(sender), U256::from(FUNDING_AMOUNT));
+            assert_eq!(fixture.balance_of(recipient), U256::zero());
+        }
+    }
+
+    /// Checks approvals with account and contract owners and spenders, and spending them by every
+    /// spender able to call the token.
+    pub fn check_allowances(&self) {
+        let pairs = |fixture: &Fixture| {
+            [
+                (fixture.accounts()[0], fixture.accounts()[1]),
+                (fixture.accounts()[0], fixture.calling_contract()),
+                (fixture.calling_contract(), fixture.accounts()[1]),
+                (fixture.calling_contract(), fixture.passive_contract()),
+            ]
+        };
+
+        for pair_index in 0..4 {
+            let mut fixture = self.install();
+            let (owner, spender) = pairs(&fixture)[pair_index];
+            let recipient = fixture.passive_contract();
+            let amount_1 = U256::from(ALLOWANCE_AMOUNT_1);
+            let amount_2 = U256::from(ALLOWANCE_AMOUNT_2);
+            let transfer_amount = U256::from(TRANSFER_AMOUNT) / 4;
+
+            fund(&mut fixture, owner);
+            assert_eq!(fixture.allowance(owner, spender), U256::zero());
+
+            fixture.approve(owner, spender, amount_1).unwrap();
+            assert_eq!(fixture.allowance(owner, spender), amount_1);
+            assert_eq!(fixture.allowance(spender, owner), U256::zero());
+            assert_eq!(
+                fixture.allowance(owner, invert_address(spender)),
+                U256::zero()
+            );
+
+            // Approve overwrites the existing allowance rather than increasing it.
+            fixture.approve(owner, spender, amount_2).unwrap();
+            assert_eq!(fixture.allowance(owner, spender), amount_2);
+
+            // The passive contract never calls the token, so it can only be approved.
+            if spender == fixture.passive_contract() {
+                continue;
+            }
+
+            fixture
+                .transfer_from(spender, owner, recipient, transfer_amount)
+                .unwrap_or_else(|error| panic!("{:?} by {:?}: {:?}", owner, spender, error));
+            assert_eq!(
+                fixture.allowance(owner, spender),
+                amount_2 - transfer_amount
+            );
+            assert_eq!(
+                fixture.balance_of(owner),
+                U256::from(FUNDING_AMOUNT) - transfer_amount
+            );
+            assert_eq!(fixture.balance_of(recipient), transfer_amount);
+            assert_eq!(fixture.balance_of(spender), U256::zero());
+        }
+    }
+
+    /// Checks that spending above the allowance fails with [`Error::InsufficientAllowance`].
+    pub fn check_insufficient_allowance(&self) {
+        let mut fixture = self.install();
+        let [owner, recipient] = fixture.accounts();
+        let spender = fixture.calling_contract();
+        let amount = U256::from(TRANSFER_AMOUNT);
+
+        fund(&mut fixture, owner);
+        assert_eq!(
+            fixture.transfer_from(spender, owner, recipient, amount),
+            Err(ApiError::from(Error::InsufficientAllowance))
+        );
+
+        fixture.approve(owner, spender, amount).unwrap();
+        assert_eq!(
+            fixture.transfer_from(spender, owner, recipient, amount + U256::one()),
+            Err(ApiError::from(Error::InsufficientAllowance))
+        );
+        assert_eq!(fixture.allowance(owner, spender), amount);
+        assert_eq!(fixture.balance_of(owner), U256::from(FUNDING_AMOUNT));
+    }
+}
+
+/// Transfers `FUNDING_AMOUNT` of tokens from the installer to `address`.
+fn fund(fixture: &mut Fixture, address: Key) {
+    let installer = fixture.installer();
+    fixture
+        .transfer(installer, address, U256::from(FUNDING_AMOUNT))
+        .unwrap_or_else(|error| panic!("should fund {:?}: {:?}", address, error));
+}
+
+/// Converts hash addr of Account into Hash, and Hash into Account.
+fn invert_address(address: Key) -> Key {
+    match address {
+        Key::Account(account_hash) => Key::Hash(account_hash.value()),
+        Key::Hash(contract_hash) => Key::Account(AccountHash::new(contract_hash)),
+        _ => panic!("Unsupported Key variant"),
+    }
+}
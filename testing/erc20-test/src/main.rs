@@ -6,22 +6,70 @@ extern crate alloc;
 use alloc::{
     string::{String, ToString},
     vec,
+    vec::Vec,
 };
 use core::ops::{Deref, DerefMut};
 
-use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    ext_ffi,
+    unwrap_or_revert::UnwrapOrRevert,
+};
 use casper_erc20::{
-    constants::{ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME},
-    Address, Error, ERC20,
+    constants::{
+        ADDRESSES_RUNTIME_ARG_NAME, ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME,
+        BASIS_POINTS_RUNTIME_ARG_NAME, DECIMALS_RUNTIME_ARG_NAME, EXEMPT_RUNTIME_ARG_NAME,
+        FEE_RECIPIENT_RUNTIME_ARG_NAME, INDEX_RUNTIME_ARG_NAME, MAX_FEE_RUNTIME_ARG_NAME,
+        OWNER_RUNTIME_ARG_NAME, PAIRS_RUNTIME_ARG_NAME, REASON_RUNTIME_ARG_NAME,
+        RECIPIENT_RUNTIME_ARG_NAME, ROLE_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME,
+        TOTAL_SUPPLY_RUNTIME_ARG_NAME,
+    },
+    Address, EmissionPeriod, EmissionSchedule, Error, TransferFee, ERC20,
 };
 use casper_types::{
-    account::AccountHash, CLType, CLTyped, CLValue, ContractPackageHash, EntryPoint,
-    EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
+    account::AccountHash,
+    api_error,
+    bytesrepr::{self, FromBytes},
+    contracts::NamedKeys,
+    ApiError, CLType, CLTyped, CLValue, ContractPackageHash, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, U256,
 };
 
 const MINT_ENTRY_POINT_NAME: &str = "mint";
 const BURN_ENTRY_POINT_NAME: &str = "burn";
 
+/// Optional install argument which enables allowlist mode.
+const ALLOWLIST_RUNTIME_ARG_NAME: &str = "allowlist";
+/// Optional install argument which enables transfer fees, used together with `max_fee`,
+/// `fee_recipient` and `fee_exempt`.
+const TRANSFER_FEE_BASIS_POINTS_RUNTIME_ARG_NAME: &str = "transfer_fee_basis_points";
+const FEE_EXEMPT_RUNTIME_ARG_NAME: &str = "fee_exempt";
+/// Optional install argument which enables holder tracking when set to `true`.
+const HOLDER_TRACKING_RUNTIME_ARG_NAME: &str = "holder_tracking";
+/// Optional install argument with the address receiving the initial supply and the admin role.
+const INITIAL_OWNER_RUNTIME_ARG_NAME: &str = "initial_owner";
+/// Optional install argument splitting the initial supply between several addresses.
+const GENESIS_DISTRIBUTION_RUNTIME_ARG_NAME: &str = "genesis_distribution";
+/// Optional install argument which caps the total supply.
+const MAX_SUPPLY_RUNTIME_ARG_NAME: &str = "max_supply";
+/// Optional install argument which enables emission, with `(start, rate)` of each period, used
+/// together with `emission_recipient` and `emission_time_unit`.
+const EMISSION_PERIODS_RUNTIME_ARG_NAME: &str = "emission_periods";
+const EMISSION_RECIPIENT_RUNTIME_ARG_NAME: &str = "emission_recipient";
+const EMISSION_TIME_UNIT_RUNTIME_ARG_NAME: &str = "emission_time_unit";
+/// Optional install argument which enables burn-on-transfer, used together with
+/// `max_burn_basis_points` and `burn_exempt`.
+const BURN_BASIS_POINTS_RUNTIME_ARG_NAME: &str = "burn_basis_points";
+const MAX_BURN_BASIS_POINTS_RUNTIME_ARG_NAME: &str = "max_burn_basis_points";
+const BURN_EXEMPT_RUNTIME_ARG_NAME: &str = "burn_exempt";
+/// Optional install argument which enables rebasing when set to `true`.
+const REBASING_RUNTIME_ARG_NAME: &str = "rebasing";
+/// Optional install argument which skips validation of the name, symbol and decimals when set to
+/// `false`. The decimals themselves can be overridden with the optional `decimals` argument.
+const METADATA_VALIDATION_RUNTIME_ARG_NAME: &str = "metadata_validation";
+/// Optional install argument naming an extra named key added to the contract.
+const EXTRA_NAMED_KEY_RUNTIME_ARG_NAME: &str = "extra_named_key";
+
 /// "erc20" is not mentioned here intentionally as the functionality is not compatible with ERC20
 /// token standard.
 const TEST_CONTRACT_KEY_NAME: &str = "test_contract";
@@ -35,6 +83,39 @@ const TOKEN_OWNER_AMOUNT_1: u64 = 1_000_000;
 const TOKEN_OWNER_ADDRESS_2: Address = Address::Contract(ContractPackageHash::new([42; 32]));
 const TOKEN_OWNER_AMOUNT_2: u64 = 2_000_000;
 
+/// Returns the value of an optional named argument, or `None` if it was not passed.
+fn get_optional_named_arg<T: FromBytes>(name: &str) -> Option<T> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(()) => {}
+        Err(ApiError::MissingArgument) => return None,
+        Err(error) => runtime::revert(error),
+    }
+
+    let mut arg_bytes = vec![0u8; arg_size];
+    if arg_size > 0 {
+        let ret = unsafe {
+            ext_ffi::casper_get_named_arg(
+                name.as_bytes().as_ptr(),
+                name.len(),
+                arg_bytes.as_mut_ptr(),
+                arg_size,
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+    }
+
+    let value = bytesrepr::deserialize(arg_bytes).unwrap_or_revert_with(ApiError::InvalidArgument);
+    Some(value)
+}
+
 #[derive(Default)]
 struct TestToken {
     erc20: ERC20,
@@ -44,7 +125,7 @@ impl TestToken {
     pub fn install() -> Result<TestToken, Error> {
         let name: String = TOKEN_NAME.to_string();
         let symbol: String = TOKEN_SYMBOL.to_string();
-        let decimals = TOKEN_DECIMALS;
+        let decimals = get_optional_named_arg(DECIMALS_RUNTIME_ARG_NAME).unwrap_or(TOKEN_DECIMALS);
         let total_supply = U256::from(TOKEN_TOTAL_SUPPLY);
 
         let mut entry_points = EntryPoints::new();
@@ -76,23 +157,118 @@ impl TestToken {
 
         entry_points.add_entry_point(casper_erc20::entry_points::total_supply());
         entry_points.add_entry_point(casper_erc20::entry_points::balance_of());
+        entry_points.add_entry_point(casper_erc20::entry_points::transfer());
+        entry_points.add_entry_point(casper_erc20::entry_points::approve());
+        entry_points.add_entry_point(casper_erc20::entry_points::allowance());
+        entry_points.add_entry_point(casper_erc20::entry_points::transfer_from());
         entry_points.add_entry_point(mint_entrypoint);
         entry_points.add_entry_point(burn_entrypoint);
+        entry_points.add_entry_point(casper_erc20::entry_points::has_role());
+        entry_points.add_entry_point(casper_erc20::entry_points::grant_role());
+        entry_points.add_entry_point(casper_erc20::entry_points::revoke_role());
+        entry_points.add_entry_point(casper_erc20::entry_points::is_frozen());
+        entry_points.add_entry_point(casper_erc20::entry_points::freeze());
+        entry_points.add_entry_point(casper_erc20::entry_points::unfreeze());
+        entry_points.add_entry_point(casper_erc20::entry_points::wipe_frozen_balance());
+        entry_points.add_entry_point(casper_erc20::entry_points::is_allowlisted());
+        entry_points.add_entry_point(casper_erc20::entry_points::add_to_allowlist());
+        entry_points.add_entry_point(casper_erc20::entry_points::remove_from_allowlist());
+        entry_points.add_entry_point(casper_erc20::entry_points::force_transfer());
+        entry_points.add_entry_point(casper_erc20::entry_points::force_transfer_count());
+        entry_points.add_entry_point(casper_erc20::entry_points::force_transfer_record());
+        entry_points.add_entry_point(casper_erc20::entry_points::calculate_fee());
+        entry_points.add_entry_point(casper_erc20::entry_points::set_transfer_fee());
+        entry_points.add_entry_point(casper_erc20::entry_points::set_fee_exempt());
+        entry_points.add_entry_point(casper_erc20::entry_points::holder_count());
+        entry_points.add_entry_point(casper_erc20::entry_points::holder_at());
+        entry_points.add_entry_point(casper_erc20::entry_points::balances_of());
+        entry_points.add_entry_point(casper_erc20::entry_points::allowances_of());
+        entry_points.add_entry_point(casper_erc20::entry_points::max_supply());
+        entry_points.add_entry_point(casper_erc20::entry_points::emit());
+        entry_points.add_entry_point(casper_erc20::entry_points::total_burned());
+        entry_points.add_entry_point(casper_erc20::entry_points::set_burn_basis_points());
+        entry_points.add_entry_point(casper_erc20::entry_points::set_burn_exempt());
+        entry_points.add_entry_point(casper_erc20::entry_points::total_shares());
+        entry_points.add_entry_point(casper_erc20::entry_points::shares_of());
+        entry_points.add_entry_point(casper_erc20::entry_points::rebase());
 
-        // Caution: This test uses `install_custom` without providing default entrypoints as
+        // Caution: This test installs the token without providing default entrypoints as
         // described by ERC20 token standard.
         //
         // This is unsafe and this test contract is not a ERC20 token standard-compliant token.
         // Contract developers should use example/erc20 contract instead as a template for writing
         // their own tokens.
-        let erc20 = ERC20::install_custom(
-            name,
-            symbol,
-            decimals,
-            total_supply,
-            TEST_CONTRACT_KEY_NAME,
-            entry_points,
-        )?;
+        let mut builder = ERC20::builder(name, symbol, decimals, total_supply)
+            .with_contract_key_name(TEST_CONTRACT_KEY_NAME)
+            .with_entry_points(entry_points);
+
+        if let Some(allowlist) = get_optional_named_arg(ALLOWLIST_RUNTIME_ARG_NAME) {
+            builder = builder.with_allowlist(allowlist);
+        }
+
+        if let Some(basis_points) =
+            get_optional_named_arg(TRANSFER_FEE_BASIS_POINTS_RUNTIME_ARG_NAME)
+        {
+            let transfer_fee = TransferFee {
+                basis_points,
+                max_fee: runtime::get_named_arg(MAX_FEE_RUNTIME_ARG_NAME),
+                recipient: runtime::get_named_arg(FEE_RECIPIENT_RUNTIME_ARG_NAME),
+            };
+            let exempt: Vec<Address> = runtime::get_named_arg(FEE_EXEMPT_RUNTIME_ARG_NAME);
+            builder = builder.with_transfer_fee(transfer_fee, exempt);
+        }
+
+        if get_optional_named_arg(HOLDER_TRACKING_RUNTIME_ARG_NAME).unwrap_or_default() {
+            builder = builder.with_holder_tracking();
+        }
+
+        if let Some(initial_owner) = get_optional_named_arg(INITIAL_OWNER_RUNTIME_ARG_NAME) {
+            builder = builder.with_initial_owner(initial_owner);
+        }
+
+        if let Some(distribution) = get_optional_named_arg(GENESIS_DISTRIBUTION_RUNTIME_ARG_NAME) {
+            builder = builder.with_genesis_distribution(distribution);
+        }
+
+        if let Some(max_supply) = get_optional_named_arg(MAX_SUPPLY_RUNTIME_ARG_NAME) {
+            builder = builder.with_max_supply(max_supply);
+        }
+
+        if let Some(periods) =
+            get_optional_named_arg::<Vec<(u64, U256)>>(EMISSION_PERIODS_RUNTIME_ARG_NAME)
+        {
+            let emission_schedule = EmissionSchedule {
+                recipient: runtime::get_named_arg(EMISSION_RECIPIENT_RUNTIME_ARG_NAME),
+                time_unit: runtime::get_named_arg(EMISSION_TIME_UNIT_RUNTIME_ARG_NAME),
+                periods: periods
+                    .into_iter()
+                    .map(|(start, rate)| EmissionPeriod { start, rate })
+                    .collect(),
+            };
+            builder = builder.with_emission_schedule(emission_schedule);
+        }
+
+        if let Some(basis_points) = get_optional_named_arg(BURN_BASIS_POINTS_RUNTIME_ARG_NAME) {
+            let max_basis_points = runtime::get_named_arg(MAX_BURN_BASIS_POINTS_RUNTIME_ARG_NAME);
+            let exempt: Vec<Address> = runtime::get_named_arg(BURN_EXEMPT_RUNTIME_ARG_NAME);
+            builder = builder.with_transfer_burn(basis_points, max_basis_points, exempt);
+        }
+
+        if get_optional_named_arg(REBASING_RUNTIME_ARG_NAME).unwrap_or_default() {
+            builder = builder.with_rebasing();
+        }
+
+        if !get_optional_named_arg(METADATA_VALIDATION_RUNTIME_ARG_NAME).unwrap_or(true) {
+            builder = builder.without_metadata_validation();
+        }
+
+        if let Some(name) = get_optional_named_arg::<String>(EXTRA_NAMED_KEY_RUNTIME_ARG_NAME) {
+            let mut named_keys = NamedKeys::new();
+            named_keys.insert(name, Key::from(storage::new_uref(()).into_read()));
+            builder = builder.with_named_keys(named_keys);
+        }
+
+        let erc20 = builder.install()?;
         Ok(TestToken { erc20 })
     }
 }
@@ -124,6 +300,42 @@ pub extern "C" fn balance_of() {
     runtime::ret(CLValue::from_t(val).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .transfer(recipient, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .approve(spender, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let val = TestToken::default().allowance(owner, spender);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .transfer_from(owner, recipient, amount)
+        .unwrap_or_revert();
+}
+
 #[no_mangle]
 pub extern "C" fn mint() {
     let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
@@ -138,6 +350,219 @@ pub extern "C" fn burn() {
     TestToken::default().burn(owner, amount).unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn has_role() {
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = TestToken::default().has_role(&role, address);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn grant_role() {
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .grant_role(&role, address)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .revoke_role(&role, address)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn is_frozen() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = TestToken::default().is_frozen(address);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn freeze() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default().freeze(address).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn unfreeze() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default().unfreeze(address).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn wipe_frozen_balance() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .wipe_frozen_balance(address)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn is_allowlisted() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = TestToken::default().is_allowlisted(address);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn add_to_allowlist() {
+    let addresses: Vec<Address> = runtime::get_named_arg(ADDRESSES_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .add_to_allowlist(addresses)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remove_from_allowlist() {
+    let addresses: Vec<Address> = runtime::get_named_arg(ADDRESSES_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .remove_from_allowlist(addresses)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn force_transfer() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let reason: String = runtime::get_named_arg(REASON_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .force_transfer(owner, recipient, amount, reason)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn force_transfer_count() {
+    let val = TestToken::default().force_transfer_count();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn force_transfer_record() {
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+    let val = TestToken::default()
+        .force_transfer_record(index)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn calculate_fee() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let val = TestToken::default()
+        .calculate_fee(amount)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_transfer_fee() {
+    let transfer_fee = TransferFee {
+        basis_points: runtime::get_named_arg(BASIS_POINTS_RUNTIME_ARG_NAME),
+        max_fee: runtime::get_named_arg(MAX_FEE_RUNTIME_ARG_NAME),
+        recipient: runtime::get_named_arg(FEE_RECIPIENT_RUNTIME_ARG_NAME),
+    };
+    TestToken::default()
+        .set_transfer_fee(transfer_fee)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_fee_exempt() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let exempt: bool = runtime::get_named_arg(EXEMPT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .set_fee_exempt(address, exempt)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn holder_count() {
+    let val = TestToken::default().holder_count().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn holder_at() {
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+    let val = TestToken::default().holder_at(index).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balances_of() {
+    let addresses: Vec<Address> = runtime::get_named_arg(ADDRESSES_RUNTIME_ARG_NAME);
+    let val = TestToken::default().balances_of(addresses);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn allowances_of() {
+    let pairs: Vec<(Address, Address)> = runtime::get_named_arg(PAIRS_RUNTIME_ARG_NAME);
+    let val = TestToken::default().allowances_of(pairs);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn max_supply() {
+    let val = TestToken::default().max_supply();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn emit() {
+    let val = TestToken::default().emit().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_burned() {
+    let val = TestToken::default().total_burned().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_burn_basis_points() {
+    let basis_points: u32 = runtime::get_named_arg(BASIS_POINTS_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .set_burn_basis_points(basis_points)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_burn_exempt() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let exempt: bool = runtime::get_named_arg(EXEMPT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .set_burn_exempt(address, exempt)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn total_shares() {
+    let val = TestToken::default().total_shares().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn shares_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = TestToken::default().shares_of(address).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn rebase() {
+    let total_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY_RUNTIME_ARG_NAME);
+    TestToken::default().rebase(total_supply).unwrap_or_revert();
+}
+
 #[no_mangle]
 fn call() {
     let mut test_token = TestToken::install().unwrap_or_revert();
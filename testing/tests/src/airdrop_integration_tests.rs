@@ -0,0 +1,184 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+use erc20_airdrop_merkle::{Claim, MerkleTree};
+
+use crate::utils::{
+    self, assert_user_error, check_balance_of, ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, TOKEN_NAME,
+    TOKEN_SYMBOL,
+};
+
+const EXAMPLE_ERC20_AIRDROP: &str = "erc20_airdrop.wasm";
+const ERC20_AIRDROP_KEY: &str = "erc20_airdrop";
+
+const ARG_TOKEN_CONTRACT: &str = "token_contract";
+const ARG_MERKLE_ROOT: &str = "merkle_root";
+const ARG_AMOUNT: &str = "amount";
+const ARG_INDEX: &str = "index";
+const ARG_ACCOUNT: &str = "account";
+const ARG_PROOF: &str = "proof";
+
+const METHOD_CLAIM: &str = "claim";
+
+const ERROR_ALREADY_CLAIMED: u16 = 1;
+const ERROR_INVALID_PROOF: u16 = 2;
+
+const CLAIM_AMOUNT_1: u64 = 100;
+const CLAIM_AMOUNT_2: u64 = 200;
+const CLAIM_AMOUNT_3: u64 = 300;
+const CONTRACT_RECIPIENT: [u8; 32] = [7u8; 32];
+
+#[derive(Copy, Clone)]
+struct TestContext {
+    erc20_token: ContractHash,
+    erc20_airdrop: ContractHash,
+}
+
+fn build_tree() -> MerkleTree {
+    let csv = format!(
+        "address,amount\n{},{}\n{},{}\n{},{}\n",
+        Key::Account(*ACCOUNT_1_ADDR).to_formatted_string(),
+        CLAIM_AMOUNT_1,
+        Key::Account(*ACCOUNT_2_ADDR).to_formatted_string(),
+        CLAIM_AMOUNT_2,
+        Key::Hash(CONTRACT_RECIPIENT).to_formatted_string(),
+        CLAIM_AMOUNT_3,
+    );
+    MerkleTree::from_csv(&csv).expect("should build tree")
+}
+
+fn setup() -> (InMemoryWasmTestBuilder, TestContext, MerkleTree) {
+    let mut builder = utils::setup();
+    let erc20_token = utils::install_token(&mut builder, TOKEN_NAME, TOKEN_SYMBOL);
+
+    let tree = build_tree();
+    utils::install_contract(
+        &mut builder,
+        EXAMPLE_ERC20_AIRDROP,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => erc20_token,
+            ARG_MERKLE_ROOT => tree.root(),
+        },
+    );
+
+    let erc20_airdrop = utils::get_contract_hash(&builder, ERC20_AIRDROP_KEY);
+    let erc20_airdrop_package = builder
+        .get_contract(erc20_airdrop)
+        .expect("should have airdrop contract")
+        .contract_package_hash();
+
+    // Funds the airdrop with exactly the sum of all claims.
+    let total_claims = CLAIM_AMOUNT_1 + CLAIM_AMOUNT_2 + CLAIM_AMOUNT_3;
+    utils::transfer(
+        &mut builder,
+        erc20_token,
+        Key::Hash(erc20_airdrop_package.value()),
+        U256::from(total_claims),
+    );
+
+    let test_context = TestContext {
+        erc20_token,
+        erc20_airdrop,
+    };
+
+    (builder, test_context, tree)
+}
+
+fn make_claim_request(
+    sender: AccountHash,
+    test_context: &TestContext,
+    tree: &MerkleTree,
+    claim: Claim,
+) -> ExecuteRequest {
+    let proof = tree.proof(claim.index).expect("should have proof");
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        test_context.erc20_airdrop,
+        METHOD_CLAIM,
+        runtime_args! {
+            ARG_INDEX => claim.index,
+            ARG_ACCOUNT => Key::from(claim.account),
+            ARG_AMOUNT => claim.amount,
+            ARG_PROOF => proof,
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_claim_once_with_valid_proof() {
+    let (mut builder, test_context, tree) = setup();
+    let claims = tree.claims();
+
+    let claim_request = make_claim_request(*ACCOUNT_1_ADDR, &test_context, &tree, claims[0]);
+    builder.exec(claim_request).expect_success().commit();
+
+    // Claims can be submitted on behalf of their account, the tokens still go to the account.
+    let claim_request = make_claim_request(*DEFAULT_ACCOUNT_ADDR, &test_context, &tree, claims[1]);
+    builder.exec(claim_request).expect_success().commit();
+
+    // Last leaf of an odd level is promoted without a sibling.
+    let claim_request = make_claim_request(*DEFAULT_ACCOUNT_ADDR, &test_context, &tree, claims[2]);
+    builder.exec(claim_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::from(CLAIM_AMOUNT_1)
+    );
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_2_ADDR)
+        ),
+        U256::from(CLAIM_AMOUNT_2)
+    );
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Hash(CONTRACT_RECIPIENT)
+        ),
+        U256::from(CLAIM_AMOUNT_3)
+    );
+
+    let claim_request = make_claim_request(*ACCOUNT_1_ADDR, &test_context, &tree, claims[0]);
+    builder.exec(claim_request).commit();
+    assert_user_error(&builder, ERROR_ALREADY_CLAIMED);
+}
+
+#[test]
+fn should_not_claim_with_invalid_proof() {
+    let (mut builder, test_context, tree) = setup();
+    let claims = tree.claims();
+
+    let inflated_claim = Claim {
+        amount: claims[0].amount + 1,
+        ..claims[0]
+    };
+    let redirected_claim = Claim {
+        account: claims[1].account,
+        ..claims[0]
+    };
+    for claim in &[inflated_claim, redirected_claim] {
+        let claim_request = make_claim_request(*ACCOUNT_1_ADDR, &test_context, &tree, *claim);
+        builder.exec(claim_request).commit();
+        assert_user_error(&builder, ERROR_INVALID_PROOF);
+    }
+
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::zero()
+    );
+}
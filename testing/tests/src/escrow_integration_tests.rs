@@ -0,0 +1,337 @@
+use casper_engine_test_support::internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utils::{self, assert_user_error, check_balance_of, ACCOUNT_1_ADDR, ACCOUNT_2_ADDR};
+
+const EXAMPLE_ERC20_ESCROW: &str = "erc20_escrow.wasm";
+const ERC20_ESCROW_KEY: &str = "erc20_escrow";
+const ERC20_ESCROW_PACKAGE_KEY: &str = "erc20_escrow_package";
+
+const ARG_SPENDER: &str = "spender";
+const ARG_AMOUNT: &str = "amount";
+const ARG_ORDER_ID: &str = "order_id";
+const ARG_SELL_TOKEN: &str = "sell_token";
+const ARG_SELL_AMOUNT: &str = "sell_amount";
+const ARG_BUY_TOKEN: &str = "buy_token";
+const ARG_BUY_AMOUNT: &str = "buy_amount";
+const ARG_TAKER: &str = "taker";
+const ARG_EXPIRY: &str = "expiry";
+
+const METHOD_APPROVE: &str = "approve";
+const METHOD_CREATE_ORDER: &str = "create_order";
+const METHOD_FILL_ORDER: &str = "fill_order";
+const METHOD_CANCEL_ORDER: &str = "cancel_order";
+
+const ERROR_ORDER_CLOSED: u16 = 3;
+const ERROR_ORDER_EXPIRED: u16 = 4;
+const ERROR_ORDER_NOT_EXPIRED: u16 = 5;
+const ERROR_NOT_MAKER: u16 = 6;
+const ERROR_NOT_TAKER: u16 = 7;
+
+const TOKEN_A_NAME: &str = "CasperA";
+const TOKEN_A_SYMBOL: &str = "CSPRA";
+const TOKEN_B_NAME: &str = "CasperB";
+const TOKEN_B_SYMBOL: &str = "CSPRB";
+
+const SELL_AMOUNT: u64 = 1_000;
+const BUY_AMOUNT: u64 = 2_500;
+const CREATE_TIME: u64 = 1_700_000_000_000;
+const EXPIRY: u64 = CREATE_TIME + 60_000;
+/// Id of the first order created on a fresh escrow.
+const FIRST_ORDER_ID: u64 = 0;
+
+#[derive(Copy, Clone)]
+struct TestContext {
+    token_a: ContractHash,
+    token_b: ContractHash,
+    erc20_escrow: ContractHash,
+    erc20_escrow_package: ContractPackageHash,
+}
+
+fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_token_a(|builder| utils::install_token(builder, TOKEN_A_NAME, TOKEN_A_SYMBOL))
+}
+
+/// Installs token A with `install_token_a` and token B, and gives `SELL_AMOUNT` of A to the maker
+/// and `BUY_AMOUNT` of B to the taker, which are accounts 1 and 2 respectively.
+fn setup_with_token_a(
+    install_token_a: fn(&mut InMemoryWasmTestBuilder) -> ContractHash,
+) -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut builder = utils::setup();
+
+    let token_a = install_token_a(&mut builder);
+    let token_b = utils::install_token(&mut builder, TOKEN_B_NAME, TOKEN_B_SYMBOL);
+    utils::transfer(
+        &mut builder,
+        token_a,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(SELL_AMOUNT),
+    );
+    utils::transfer(
+        &mut builder,
+        token_b,
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(BUY_AMOUNT),
+    );
+
+    utils::install_contract(&mut builder, EXAMPLE_ERC20_ESCROW, RuntimeArgs::default());
+    let erc20_escrow = utils::get_contract_hash(&builder, ERC20_ESCROW_KEY);
+    let erc20_escrow_package = utils::get_contract_package_hash(&builder, ERC20_ESCROW_PACKAGE_KEY);
+
+    let test_context = TestContext {
+        token_a,
+        token_b,
+        erc20_escrow,
+        erc20_escrow_package,
+    };
+
+    (builder, test_context)
+}
+
+fn approve_escrow(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    sender: AccountHash,
+    token_contract: ContractHash,
+    amount: u64,
+) {
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        token_contract,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => Key::Hash(test_context.erc20_escrow_package.value()),
+            ARG_AMOUNT => U256::from(amount),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+}
+
+/// Has the maker sell all of its A for B, creating the first order of the escrow.
+fn create_order(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    taker: Option<AccountHash>,
+) {
+    approve_escrow(
+        builder,
+        test_context,
+        *ACCOUNT_1_ADDR,
+        test_context.token_a,
+        SELL_AMOUNT,
+    );
+    let create_order_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.erc20_escrow,
+        METHOD_CREATE_ORDER,
+        runtime_args! {
+            ARG_SELL_TOKEN => test_context.token_a,
+            ARG_SELL_AMOUNT => U256::from(SELL_AMOUNT),
+            ARG_BUY_TOKEN => test_context.token_b,
+            ARG_BUY_AMOUNT => U256::from(BUY_AMOUNT),
+            ARG_TAKER => taker.map(Key::Account),
+            ARG_EXPIRY => EXPIRY,
+        },
+    )
+    .with_block_time(CREATE_TIME)
+    .build();
+    builder.exec(create_order_request).expect_success().commit();
+}
+
+fn make_order_request(
+    sender: AccountHash,
+    test_context: &TestContext,
+    entry_point: &str,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        test_context.erc20_escrow,
+        entry_point,
+        runtime_args! {
+            ARG_ORDER_ID => FIRST_ORDER_ID,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_settle_filled_order() {
+    let (mut builder, test_context) = setup();
+    create_order(&mut builder, &test_context, None);
+
+    let escrow = Key::Hash(test_context.erc20_escrow_package.value());
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, escrow),
+        U256::from(SELL_AMOUNT)
+    );
+
+    approve_escrow(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        test_context.token_b,
+        BUY_AMOUNT,
+    );
+    let fill_request = make_order_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        METHOD_FILL_ORDER,
+        CREATE_TIME + 1,
+    );
+    builder.exec(fill_request).expect_success().commit();
+
+    let maker = Key::Account(*ACCOUNT_1_ADDR);
+    let taker = Key::Account(*ACCOUNT_2_ADDR);
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, escrow),
+        U256::zero()
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, taker),
+        U256::from(SELL_AMOUNT)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_b, maker),
+        U256::from(BUY_AMOUNT)
+    );
+
+    let fill_request = make_order_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        METHOD_FILL_ORDER,
+        CREATE_TIME + 2,
+    );
+    builder.exec(fill_request).commit();
+    assert_user_error(&builder, ERROR_ORDER_CLOSED);
+}
+
+#[test]
+fn should_only_cancel_expired_order_as_maker() {
+    let (mut builder, test_context) = setup();
+    create_order(&mut builder, &test_context, None);
+
+    let cancel_request = make_order_request(
+        *ACCOUNT_1_ADDR,
+        &test_context,
+        METHOD_CANCEL_ORDER,
+        EXPIRY - 1,
+    );
+    builder.exec(cancel_request).commit();
+    assert_user_error(&builder, ERROR_ORDER_NOT_EXPIRED);
+
+    let cancel_request =
+        make_order_request(*ACCOUNT_2_ADDR, &test_context, METHOD_CANCEL_ORDER, EXPIRY);
+    builder.exec(cancel_request).commit();
+    assert_user_error(&builder, ERROR_NOT_MAKER);
+
+    approve_escrow(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        test_context.token_b,
+        BUY_AMOUNT,
+    );
+    let fill_request =
+        make_order_request(*ACCOUNT_2_ADDR, &test_context, METHOD_FILL_ORDER, EXPIRY);
+    builder.exec(fill_request).commit();
+    assert_user_error(&builder, ERROR_ORDER_EXPIRED);
+
+    let cancel_request =
+        make_order_request(*ACCOUNT_1_ADDR, &test_context, METHOD_CANCEL_ORDER, EXPIRY);
+    builder.exec(cancel_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.token_a,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::from(SELL_AMOUNT)
+    );
+}
+
+#[test]
+fn should_only_fill_order_as_designated_taker() {
+    let (mut builder, test_context) = setup();
+    create_order(&mut builder, &test_context, Some(*ACCOUNT_2_ADDR));
+
+    // The installer holds enough B to fill the order, but isn't the designated taker.
+    approve_escrow(
+        &mut builder,
+        &test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.token_b,
+        BUY_AMOUNT,
+    );
+    let fill_request = make_order_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        METHOD_FILL_ORDER,
+        CREATE_TIME + 1,
+    );
+    builder.exec(fill_request).commit();
+    assert_user_error(&builder, ERROR_NOT_TAKER);
+
+    approve_escrow(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        test_context.token_b,
+        BUY_AMOUNT,
+    );
+    let fill_request = make_order_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        METHOD_FILL_ORDER,
+        CREATE_TIME + 1,
+    );
+    builder.exec(fill_request).expect_success().commit();
+}
+
+#[test]
+fn should_only_sell_tokens_received_by_escrow() {
+    let (mut builder, test_context) = setup_with_token_a(utils::install_fee_token);
+    create_order(&mut builder, &test_context, None);
+
+    // Depositing is charged a fee, so the order sells less than the maker paid in.
+    let escrow = Key::Hash(test_context.erc20_escrow_package.value());
+    let deposited = SELL_AMOUNT - utils::transfer_fee(SELL_AMOUNT);
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, escrow),
+        U256::from(deposited)
+    );
+
+    approve_escrow(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        test_context.token_b,
+        BUY_AMOUNT,
+    );
+    let fill_request = make_order_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        METHOD_FILL_ORDER,
+        CREATE_TIME + 1,
+    );
+    builder.exec(fill_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, escrow),
+        U256::zero()
+    );
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.token_a,
+            Key::Account(*ACCOUNT_2_ADDR)
+        ),
+        U256::from(deposited - utils::transfer_fee(deposited))
+    );
+}
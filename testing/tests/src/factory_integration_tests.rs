@@ -0,0 +1,199 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utils::{
+    self, assert_user_error, check_balance_of, ACCOUNT_1_ADDR, TOKEN_DECIMALS, TOKEN_NAME,
+    TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+};
+
+const EXAMPLE_ERC20_FACTORY: &str = "erc20_factory.wasm";
+const ERC20_FACTORY_KEY: &str = "erc20_factory";
+const TOKEN_CONTRACT_KEY_PREFIX: &str = "erc20_token_contract_";
+const TOKEN_COUNT_KEY: &str = "token_count";
+const REGISTRY_KEY: &str = "registry";
+
+const ARG_NAME: &str = "name";
+const ARG_SYMBOL: &str = "symbol";
+const ARG_DECIMALS: &str = "decimals";
+const ARG_TOTAL_SUPPLY: &str = "total_supply";
+const ARG_OWNER: &str = "owner";
+
+const METHOD_CREATE_TOKEN: &str = "create_token";
+
+const ERROR_PERMISSION_DENIED: u16 = u16::MAX - 4;
+const ERROR_SYMBOL_ALREADY_REGISTERED: u16 = 1;
+
+const OTHER_TOKEN_NAME: &str = "CasperOther";
+const OTHER_TOKEN_SYMBOL: &str = "CSPRO";
+
+#[derive(Copy, Clone)]
+struct TestContext {
+    erc20_factory: ContractHash,
+}
+
+fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut builder = utils::setup();
+    utils::install_contract(&mut builder, EXAMPLE_ERC20_FACTORY, RuntimeArgs::default());
+
+    let test_context = TestContext {
+        erc20_factory: utils::get_contract_hash(&builder, ERC20_FACTORY_KEY),
+    };
+
+    (builder, test_context)
+}
+
+fn make_create_token_request(
+    sender: AccountHash,
+    test_context: &TestContext,
+    name: &str,
+    symbol: &str,
+    owner: Key,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        test_context.erc20_factory,
+        METHOD_CREATE_TOKEN,
+        runtime_args! {
+            ARG_NAME => name,
+            ARG_SYMBOL => symbol,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            ARG_OWNER => owner,
+        },
+    )
+    .build()
+}
+
+/// Looks up a created token in the factory's registry, which is keyed by the hex encoded blake2b
+/// hash of the symbol.
+fn get_token(
+    builder: &InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    symbol: &str,
+) -> ContractHash {
+    let registry_uref = builder
+        .get_contract(test_context.erc20_factory)
+        .expect("should have factory contract")
+        .named_keys()
+        .get(REGISTRY_KEY)
+        .and_then(|key| key.into_uref())
+        .expect("should have registry");
+    let dictionary_item_key: String = erc20_airdrop_merkle::blake2b(symbol.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    builder
+        .query(
+            None,
+            Key::dictionary(registry_uref, dictionary_item_key.as_bytes()),
+            &[],
+        )
+        .expect("should have token contract hash")
+        .as_cl_value()
+        .cloned()
+        .expect("should be cl value")
+        .into_t()
+        .expect("should be contract hash")
+}
+
+#[test]
+fn should_create_tokens_owned_by_another_address() {
+    let (mut builder, test_context) = setup();
+
+    let owner = Key::Account(*ACCOUNT_1_ADDR);
+    let create_request = make_create_token_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        TOKEN_NAME,
+        TOKEN_SYMBOL,
+        owner,
+    );
+    builder.exec(create_request).expect_success().commit();
+    let create_other_token_request = make_create_token_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        OTHER_TOKEN_NAME,
+        OTHER_TOKEN_SYMBOL,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    );
+    builder
+        .exec(create_other_token_request)
+        .expect_success()
+        .commit();
+
+    let token_count: u64 = builder.get_value(test_context.erc20_factory, TOKEN_COUNT_KEY);
+    assert_eq!(token_count, 2);
+
+    let erc20_token = get_token(&builder, &test_context, TOKEN_SYMBOL);
+    let other_erc20_token = get_token(&builder, &test_context, OTHER_TOKEN_SYMBOL);
+    assert_ne!(erc20_token, other_erc20_token);
+
+    // Only the registry keeps track of the tokens, not the factory's named keys.
+    let factory = builder
+        .get_contract(test_context.erc20_factory)
+        .expect("should have factory contract");
+    assert!(!factory
+        .named_keys()
+        .keys()
+        .any(|name| name.starts_with(TOKEN_CONTRACT_KEY_PREFIX)));
+
+    assert_eq!(
+        check_balance_of(&mut builder, erc20_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            erc20_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_register_symbol_twice() {
+    let (mut builder, test_context) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let create_request = make_create_token_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        TOKEN_NAME,
+        TOKEN_SYMBOL,
+        owner,
+    );
+    builder.exec(create_request).expect_success().commit();
+
+    let create_request = make_create_token_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        OTHER_TOKEN_NAME,
+        TOKEN_SYMBOL,
+        owner,
+    );
+    builder.exec(create_request).commit();
+    assert_user_error(&builder, ERROR_SYMBOL_ALREADY_REGISTERED);
+
+    let token_count: u64 = builder.get_value(test_context.erc20_factory, TOKEN_COUNT_KEY);
+    assert_eq!(token_count, 1);
+}
+
+#[test]
+fn should_only_allow_admin_to_create_tokens() {
+    let (mut builder, test_context) = setup();
+
+    let create_request = make_create_token_request(
+        *ACCOUNT_1_ADDR,
+        &test_context,
+        TOKEN_NAME,
+        TOKEN_SYMBOL,
+        Key::Account(*ACCOUNT_1_ADDR),
+    );
+    builder.exec(create_request).commit();
+    assert_user_error(&builder, ERROR_PERMISSION_DENIED);
+}
@@ -0,0 +1,328 @@
+//! Property-based tests comparing the token against a reference model after random sequences of
+//! operations.
+use std::collections::BTreeMap;
+
+use proptest::prelude::*;
+
+use casper_engine_test_support::{internal::ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_erc20::Error;
+use casper_erc20_test_suite::{fixture::Fixture, Erc20TestSuite};
+use casper_types::{account::AccountHash, runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+const CONTRACT_ERC20_TEST: &str = "erc20_test.wasm";
+const TEST_CONTRACT_KEY: &str = "test_contract";
+
+const METHOD_MINT: &str = "mint";
+const METHOD_BURN: &str = "burn";
+
+const ARG_OWNER: &str = "owner";
+const ARG_AMOUNT: &str = "amount";
+
+/// Balances of the test token right after install, see `testing/erc20-test`.
+const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+const TOKEN_OWNER_ADDRESS_1: Key = Key::Account(AccountHash::new([42; 32]));
+const TOKEN_OWNER_AMOUNT_1: u64 = 1_000_000;
+const TOKEN_OWNER_ADDRESS_2: Key = Key::Hash([42; 32]);
+const TOKEN_OWNER_AMOUNT_2: u64 = 2_000_000;
+
+/// Upper bound of a generated amount, big enough to make some of the operations fail.
+const MAX_AMOUNT: u64 = 3_000_000;
+const MAX_OPERATIONS: usize = 12;
+const CASES: u32 = 16;
+
+/// Number of holders able to call the token: three accounts and the test call contract.
+const ACTOR_COUNT: usize = 4;
+/// Number of all holders: actors, and two addresses which only ever receive tokens.
+const HOLDER_COUNT: usize = 6;
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Transfer {
+        sender: usize,
+        recipient: usize,
+        amount: u64,
+    },
+    Approve {
+        owner: usize,
+        spender: usize,
+        amount: u64,
+    },
+    TransferFrom {
+        spender: usize,
+        owner: usize,
+        recipient: usize,
+        amount: u64,
+    },
+    Mint {
+        owner: usize,
+        amount: u64,
+    },
+    Burn {
+        owner: usize,
+        amount: u64,
+    },
+}
+
+fn amount() -> impl Strategy<Value = u64> {
+    prop_oneof![Just(0), 1..1_000u64, 1..MAX_AMOUNT]
+}
+
+fn operation() -> impl Strategy<Value = Operation> {
+    let actor = 0..ACTOR_COUNT;
+    let holder = 0..HOLDER_COUNT;
+    prop_oneof![
+        (actor.clone(), holder.clone(), amount()).prop_map(|(sender, recipient, amount)| {
+            Operation::Transfer {
+                sender,
+                recipient,
+                amount,
+            }
+        }),
+        (actor.clone(), holder.clone(), amount()).prop_map(|(owner, spender, amount)| {
+            Operation::Approve {
+                owner,
+                spender,
+                amount,
+            }
+        }),
+        (actor, holder.clone(), holder.clone(), amount()).prop_map(
+            |(spender, owner, recipient, amount)| Operation::TransferFrom {
+                spender,
+                owner,
+                recipient,
+                amount,
+            }
+        ),
+        (holder.clone(), amount()).prop_map(|(owner, amount)| Operation::Mint { owner, amount }),
+        (holder, amount()).prop_map(|(owner, amount)| Operation::Burn { owner, amount }),
+    ]
+}
+
+/// Reference model of the token state.
+struct Model {
+    balances: [U256; HOLDER_COUNT],
+    allowances: BTreeMap<(usize, usize), U256>,
+    total_supply: U256,
+}
+
+impl Model {
+    fn new() -> Self {
+        let mut balances = [U256::zero(); HOLDER_COUNT];
+        balances[0] = U256::from(TOKEN_TOTAL_SUPPLY);
+        balances[4] = U256::from(TOKEN_OWNER_AMOUNT_1);
+        balances[5] = U256::from(TOKEN_OWNER_AMOUNT_2);
+        let total_supply = balances
+            .iter()
+            .fold(U256::zero(), |sum, balance| sum + *balance);
+        Model {
+            balances,
+            allowances: BTreeMap::new(),
+            total_supply,
+        }
+    }
+
+    fn allowance(&self, owner: usize, spender: usize) -> U256 {
+        self.allowances
+            .get(&(owner, spender))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn transfer_balance(
+        &mut self,
+        sender: usize,
+        recipient: usize,
+        amount: U256,
+    ) -> Result<(), Error> {
+        if sender == recipient || amount.is_zero() {
+            return Ok(());
+        }
+        if self.balances[sender] < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        self.balances[sender] -= amount;
+        self.balances[recipient] += amount;
+        Ok(())
+    }
+
+    /// Applies `operation`, leaving the model untouched when it fails.
+    fn apply(&mut self, operation: &Operation) -> Result<(), Error> {
+        match *operation {
+            Operation::Transfer {
+                sender,
+                recipient,
+                amount,
+            } => self.transfer_balance(sender, recipient, U256::from(amount)),
+            Operation::Approve {
+                owner,
+                spender,
+                amount,
+            } => {
+                self.allowances.insert((owner, spender), U256::from(amount));
+                Ok(())
+            }
+            Operation::TransferFrom {
+                spender,
+                owner,
+                recipient,
+                amount,
+            } => {
+                let amount = U256::from(amount);
+                if amount.is_zero() {
+                    return Ok(());
+                }
+                let allowance = self.allowance(owner, spender);
+                if allowance < amount {
+                    return Err(Error::InsufficientAllowance);
+                }
+                self.transfer_balance(owner, recipient, amount)?;
+                self.allowances.insert((owner, spender), allowance - amount);
+                Ok(())
+            }
+            Operation::Mint { owner, amount } => {
+                self.balances[owner] += U256::from(amount);
+                self.total_supply += U256::from(amount);
+                Ok(())
+            }
+            Operation::Burn { owner, amount } => {
+                let amount = U256::from(amount);
+                if self.balances[owner] < amount {
+                    return Err(Error::InsufficientBalance);
+                }
+                self.balances[owner] -= amount;
+                self.total_supply -= amount;
+                Ok(())
+            }
+        }
+    }
+}
+
+struct TestContext {
+    fixture: Fixture,
+}
+
+impl TestContext {
+    fn setup() -> Self {
+        let fixture = Erc20TestSuite::new(CONTRACT_ERC20_TEST, RuntimeArgs::default())
+            .with_contract_key_name(TEST_CONTRACT_KEY)
+            .install();
+        TestContext { fixture }
+    }
+
+    fn holder(&self, index: usize) -> Key {
+        let [account_1, account_2] = self.fixture.accounts();
+        [
+            self.fixture.installer(),
+            account_1,
+            account_2,
+            self.fixture.calling_contract(),
+            TOKEN_OWNER_ADDRESS_1,
+            TOKEN_OWNER_ADDRESS_2,
+        ][index]
+    }
+
+    fn apply(&mut self, operation: &Operation) -> Result<(), ApiError> {
+        match *operation {
+            Operation::Transfer {
+                sender,
+                recipient,
+                amount,
+            } => self.fixture.transfer(
+                self.holder(sender),
+                self.holder(recipient),
+                U256::from(amount),
+            ),
+            Operation::Approve {
+                owner,
+                spender,
+                amount,
+            } => self
+                .fixture
+                .approve(self.holder(owner), self.holder(spender), U256::from(amount)),
+            Operation::TransferFrom {
+                spender,
+                owner,
+                recipient,
+                amount,
+            } => self.fixture.transfer_from(
+                self.holder(spender),
+                self.holder(owner),
+                self.holder(recipient),
+                U256::from(amount),
+            ),
+            Operation::Mint { owner, amount } => {
+                self.call_supply_method(METHOD_MINT, owner, amount)
+            }
+            Operation::Burn { owner, amount } => {
+                self.call_supply_method(METHOD_BURN, owner, amount)
+            }
+        }
+    }
+
+    /// Calls the unguarded `mint` or `burn` entry point of the test token.
+    fn call_supply_method(
+        &mut self,
+        method: &str,
+        owner: usize,
+        amount: u64,
+    ) -> Result<(), ApiError> {
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            self.fixture.token(),
+            method,
+            runtime_args! {
+                ARG_OWNER => self.holder(owner),
+                ARG_AMOUNT => U256::from(amount),
+            },
+        )
+        .build();
+        self.fixture.exec(exec_request)
+    }
+
+    fn total_supply(&mut self) -> U256 {
+        self.fixture.total_supply()
+    }
+
+    fn balance_of(&mut self, index: usize) -> U256 {
+        let address = self.holder(index);
+        self.fixture.balance_of(address)
+    }
+
+    fn allowance(&mut self, owner: usize, spender: usize) -> U256 {
+        let (owner, spender) = (self.holder(owner), self.holder(spender));
+        self.fixture.allowance(owner, spender)
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[test]
+    fn should_match_reference_model(
+        operations in prop::collection::vec(operation(), 1..MAX_OPERATIONS)
+    ) {
+        let mut test_context = TestContext::setup();
+        let mut model = Model::new();
+
+        for operation in &operations {
+            let expected = model.apply(operation).map_err(ApiError::from);
+            let actual = test_context.apply(operation);
+            prop_assert_eq!(actual, expected, "{:?}", operation);
+        }
+
+        let mut sum_of_balances = U256::zero();
+        for (index, expected_balance) in model.balances.iter().enumerate() {
+            let balance = test_context.balance_of(index);
+            prop_assert_eq!(balance, *expected_balance, "holder {}", index);
+            sum_of_balances += balance;
+        }
+
+        let total_supply = test_context.total_supply();
+        prop_assert_eq!(total_supply, model.total_supply);
+        prop_assert_eq!(sum_of_balances, total_supply);
+
+        for (&(owner, spender), &allowance) in &model.allowances {
+            prop_assert_eq!(test_context.allowance(owner, spender), allowance);
+        }
+    }
+}
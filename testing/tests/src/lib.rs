@@ -1,2 +1,28 @@
 #[cfg(test)]
+mod airdrop_integration_tests;
+#[cfg(test)]
+mod escrow_integration_tests;
+#[cfg(test)]
+mod factory_integration_tests;
+#[cfg(test)]
+mod invariant_tests;
+#[cfg(test)]
 mod lib_integration_tests;
+#[cfg(test)]
+mod pool_integration_tests;
+#[cfg(test)]
+mod sale_integration_tests;
+#[cfg(test)]
+mod staking_integration_tests;
+#[cfg(test)]
+mod stream_integration_tests;
+#[cfg(test)]
+mod subscriptions_integration_tests;
+#[cfg(test)]
+mod test_suite_integration_tests;
+#[cfg(test)]
+mod utils;
+#[cfg(test)]
+mod vault_integration_tests;
+#[cfg(test)]
+mod vesting_integration_tests;
@@ -4,6 +4,7 @@ use casper_engine_test_support::{
     internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
     DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
+use casper_erc20::ForceTransferRecord;
 use casper_execution_engine::core::{
     engine_state::{Error as CoreError, ExecuteRequest},
     execution::Error as ExecError,
@@ -21,8 +22,6 @@ const SYMBOL_KEY: &str = "symbol";
 const ERC20_TOKEN_CONTRACT_KEY: &str = "erc20_token_contract";
 const DECIMALS_KEY: &str = "decimals";
 const TOTAL_SUPPLY_KEY: &str = "total_supply";
-const BALANCES_KEY: &str = "balances";
-const ALLOWANCES_KEY: &str = "allowances";
 
 const ARG_NAME: &str = "name";
 const ARG_SYMBOL: &str = "symbol";
@@ -33,12 +32,25 @@ const TEST_CONTRACT_KEY: &str = "test_contract";
 
 const _ERROR_INVALID_CONTEXT: u16 = u16::MAX;
 const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 1;
-const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
 const ERROR_OVERFLOW: u16 = u16::MAX - 3;
+const ERROR_PERMISSION_DENIED: u16 = u16::MAX - 4;
+const ERROR_ACCOUNT_FROZEN: u16 = u16::MAX - 5;
+const ERROR_ACCOUNT_NOT_FROZEN: u16 = u16::MAX - 6;
+const ERROR_NOT_ALLOWLISTED: u16 = u16::MAX - 7;
+const ERROR_FEATURE_DISABLED: u16 = u16::MAX - 8;
+const ERROR_INVALID_FEE: u16 = u16::MAX - 9;
+const ERROR_INVALID_DISTRIBUTION: u16 = u16::MAX - 10;
+const ERROR_INVALID_NAME: u16 = u16::MAX - 11;
+const ERROR_INVALID_SYMBOL: u16 = u16::MAX - 12;
+const ERROR_INVALID_DECIMALS: u16 = u16::MAX - 13;
+const ERROR_SUPPLY_CAP_EXCEEDED: u16 = u16::MAX - 14;
+const ERROR_INVALID_BURN_RATE: u16 = u16::MAX - 16;
+const ERROR_INVALID_REBASE: u16 = u16::MAX - 17;
+const ERROR_RESERVED_KEY_NAME: u16 = u16::MAX - 18;
 
 const TOKEN_NAME: &str = "CasperTest";
 const TOKEN_SYMBOL: &str = "CSPRT";
-const TOKEN_DECIMALS: u8 = 100;
+const TOKEN_DECIMALS: u8 = 8;
 const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
 
 const METHOD_TRANSFER: &str = "transfer";
@@ -49,11 +61,14 @@ const METHOD_APPROVE: &str = "approve";
 const ARG_OWNER: &str = "owner";
 const ARG_SPENDER: &str = "spender";
 
-const METHOD_TRANSFER_FROM: &str = "transfer_from";
-
 const CHECK_TOTAL_SUPPLY_ENTRYPOINT: &str = "check_total_supply";
 const CHECK_BALANCE_OF_ENTRYPOINT: &str = "check_balance_of";
-const CHECK_ALLOWANCE_OF_ENTRYPOINT: &str = "check_allowance_of";
+const CHECK_HAS_ROLE_ENTRYPOINT: &str = "check_has_role";
+const CHECK_FORCE_TRANSFER_RECORD_ENTRYPOINT: &str = "check_force_transfer_record";
+const CHECK_HOLDER_AT_ENTRYPOINT: &str = "check_holder_at";
+const CHECK_BALANCES_OF_ENTRYPOINT: &str = "check_balances_of";
+const CHECK_ALLOWANCES_OF_ENTRYPOINT: &str = "check_allowances_of";
+const ARG_PAIRS: &str = "pairs";
 const ARG_TOKEN_CONTRACT: &str = "token_contract";
 const ARG_ADDRESS: &str = "address";
 const RESULT_KEY: &str = "result";
@@ -72,13 +87,10 @@ static ACCOUNT_2_PUBLIC_KEY: Lazy<PublicKey> =
 static ACCOUNT_2_ADDR: Lazy<AccountHash> = Lazy::new(|| ACCOUNT_2_PUBLIC_KEY.to_account_hash());
 
 const TRANSFER_AMOUNT_1: u64 = 200_001;
-const TRANSFER_AMOUNT_2: u64 = 19_999;
 const ALLOWANCE_AMOUNT_1: u64 = 456_789;
-const ALLOWANCE_AMOUNT_2: u64 = 87_654;
 
 const METHOD_TRANSFER_AS_STORED_CONTRACT: &str = "transfer_as_stored_contract";
 const METHOD_APPROVE_AS_STORED_CONTRACT: &str = "approve_as_stored_contract";
-const METHOD_FROM_AS_STORED_CONTRACT: &str = "transfer_from_as_stored_contract";
 
 const TOKEN_OWNER_ADDRESS_1: Key = Key::Account(AccountHash::new([42; 32]));
 const TOKEN_OWNER_AMOUNT_1: u64 = 1_000_000;
@@ -87,6 +99,66 @@ const TOKEN_OWNER_AMOUNT_2: u64 = 2_000_000;
 
 const METHOD_MINT: &str = "mint";
 const METHOD_BURN: &str = "burn";
+const METHOD_FREEZE: &str = "freeze";
+const METHOD_UNFREEZE: &str = "unfreeze";
+const METHOD_WIPE_FROZEN_BALANCE: &str = "wipe_frozen_balance";
+const METHOD_ADD_TO_ALLOWLIST: &str = "add_to_allowlist";
+const METHOD_REMOVE_FROM_ALLOWLIST: &str = "remove_from_allowlist";
+const ARG_ADDRESSES: &str = "addresses";
+const ARG_ALLOWLIST: &str = "allowlist";
+const METHOD_GRANT_ROLE: &str = "grant_role";
+const METHOD_REVOKE_ROLE: &str = "revoke_role";
+const METHOD_FORCE_TRANSFER: &str = "force_transfer";
+const ARG_ROLE: &str = "role";
+const ARG_REASON: &str = "reason";
+const ARG_INDEX: &str = "index";
+const ADMIN_ROLE: &str = "admin";
+const FORCE_TRANSFER_ROLE: &str = "force_transfer";
+const FORCE_TRANSFER_COUNT_KEY: &str = "force_transfer_count";
+const FORCE_TRANSFER_REASON: &str = "Court order #42";
+const FORCE_TRANSFER_BLOCK_TIME: u64 = 1_700_000_000_000;
+const TRANSFER_FEE_KEY: &str = "transfer_fee";
+const METHOD_SET_TRANSFER_FEE: &str = "set_transfer_fee";
+const ARG_TRANSFER_FEE_BASIS_POINTS: &str = "transfer_fee_basis_points";
+const ARG_BASIS_POINTS: &str = "basis_points";
+const ARG_MAX_FEE: &str = "max_fee";
+const ARG_FEE_RECIPIENT: &str = "fee_recipient";
+const ARG_FEE_EXEMPT: &str = "fee_exempt";
+const TRANSFER_FEE_BASIS_POINTS: u32 = 250;
+const TRANSFER_FEE_MAX_FEE: u64 = 10_000;
+const TRANSFER_FEE_LOWER_MAX_FEE: u64 = 1_000;
+const METHOD_HOLDER_COUNT: &str = "holder_count";
+const ARG_HOLDER_TRACKING: &str = "holder_tracking";
+const HOLDER_COUNT_KEY: &str = "holder_count";
+const ARG_INITIAL_OWNER: &str = "initial_owner";
+const ARG_GENESIS_DISTRIBUTION: &str = "genesis_distribution";
+const GENESIS_AMOUNT_1: u64 = 600_000_000;
+const GENESIS_AMOUNT_2: u64 = 400_000_000;
+const METHOD_EMIT: &str = "emit";
+const ARG_MAX_SUPPLY: &str = "max_supply";
+const ARG_EMISSION_PERIODS: &str = "emission_periods";
+const ARG_EMISSION_RECIPIENT: &str = "emission_recipient";
+const ARG_EMISSION_TIME_UNIT: &str = "emission_time_unit";
+/// Total supply of the test contract once installed, including the tokens minted by its `call()`.
+const TEST_CONTRACT_SUPPLY: u64 = TOKEN_TOTAL_SUPPLY + TOKEN_OWNER_AMOUNT_1 + TOKEN_OWNER_AMOUNT_2;
+const EMISSION_START: u64 = 1_700_000_000_000;
+const EMISSION_HALVING: u64 = 10_000;
+const EMISSION_TIME_UNIT: u64 = 1_000;
+const EMISSION_RATE: u64 = 1_000;
+const EMISSION_CAP: u64 = 15_000;
+const METHOD_SET_BURN_BASIS_POINTS: &str = "set_burn_basis_points";
+const ARG_BURN_BASIS_POINTS: &str = "burn_basis_points";
+const ARG_MAX_BURN_BASIS_POINTS: &str = "max_burn_basis_points";
+const ARG_BURN_EXEMPT: &str = "burn_exempt";
+const METHOD_REBASE: &str = "rebase";
+const ARG_REBASING: &str = "rebasing";
+const ARG_METADATA_VALIDATION: &str = "metadata_validation";
+const ARG_EXTRA_NAMED_KEY: &str = "extra_named_key";
+const EXTRA_NAMED_KEY: &str = "extra";
+const TOTAL_SHARES_KEY: &str = "total_shares";
+const TOTAL_BURNED_KEY: &str = "total_burned";
+const BURN_BASIS_POINTS: u32 = 100;
+const MAX_BURN_BASIS_POINTS: u32 = 500;
 
 /// Converts hash addr of Account into Hash, and Hash into Account
 ///
@@ -108,6 +180,12 @@ struct TestContext {
 }
 
 fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_test_contract_args(RuntimeArgs::default())
+}
+
+fn setup_with_test_contract_args(
+    test_contract_args: RuntimeArgs,
+) -> (InMemoryWasmTestBuilder, TestContext) {
     let mut builder = InMemoryWasmTestBuilder::default();
     builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
 
@@ -142,7 +220,7 @@ fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
     let install_request_2 = ExecuteRequestBuilder::standard(
         *DEFAULT_ACCOUNT_ADDR,
         CONTRACT_ERC20_TEST,
-        RuntimeArgs::default(),
+        test_contract_args,
     )
     .build();
     let install_request_3 = ExecuteRequestBuilder::standard(
@@ -274,108 +352,6 @@ fn erc20_check_balance_of(
     get_test_result(builder, erc20_test_contract_hash)
 }
 
-fn erc20_check_allowance_of(
-    builder: &mut InMemoryWasmTestBuilder,
-    owner: Key,
-    spender: Key,
-) -> U256 {
-    let account = builder
-        .get_account(*DEFAULT_ACCOUNT_ADDR)
-        .expect("should have account");
-    let erc20_contract_hash = account
-        .named_keys()
-        .get(ERC20_TOKEN_CONTRACT_KEY)
-        .and_then(|key| key.into_hash())
-        .map(ContractHash::new)
-        .expect("should have test contract hash");
-    let erc20_test_contract_hash = account
-        .named_keys()
-        .get(ERC20_TEST_CALL_KEY)
-        .and_then(|key| key.into_hash())
-        .map(ContractPackageHash::new)
-        .expect("should have test contract hash");
-
-    let check_balance_args = runtime_args! {
-        ARG_TOKEN_CONTRACT => erc20_contract_hash,
-        ARG_OWNER => owner,
-        ARG_SPENDER => spender,
-    };
-    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
-        *DEFAULT_ACCOUNT_ADDR,
-        erc20_test_contract_hash,
-        None,
-        CHECK_ALLOWANCE_OF_ENTRYPOINT,
-        check_balance_args,
-    )
-    .build();
-    builder.exec(exec_request).expect_success().commit();
-
-    get_test_result(builder, erc20_test_contract_hash)
-}
-
-fn test_erc20_transfer(
-    builder: &mut InMemoryWasmTestBuilder,
-    test_context: &TestContext,
-    sender1: Key,
-    recipient1: Key,
-    sender2: Key,
-    recipient2: Key,
-) {
-    let TestContext { erc20_token, .. } = test_context;
-
-    let transfer_amount_1 = U256::from(TRANSFER_AMOUNT_1);
-    let transfer_amount_2 = U256::from(TRANSFER_AMOUNT_2);
-
-    let sender_balance_before = erc20_check_balance_of(builder, erc20_token, sender1);
-    assert_ne!(sender_balance_before, U256::zero());
-
-    let account_1_balance_before = erc20_check_balance_of(builder, erc20_token, recipient1);
-    assert_eq!(account_1_balance_before, U256::zero());
-
-    let account_2_balance_before = erc20_check_balance_of(builder, erc20_token, recipient1);
-    assert_eq!(account_2_balance_before, U256::zero());
-
-    let token_transfer_request_1 =
-        make_erc20_transfer_request(sender1, erc20_token, recipient1, transfer_amount_1);
-
-    builder
-        .exec(token_transfer_request_1)
-        .expect_success()
-        .commit();
-
-    let account_1_balance_after = erc20_check_balance_of(builder, erc20_token, recipient1);
-    assert_eq!(account_1_balance_after, transfer_amount_1);
-    let account_1_balance_before = account_1_balance_after;
-
-    let sender_balance_after = erc20_check_balance_of(builder, erc20_token, sender1);
-    assert_eq!(
-        sender_balance_after,
-        sender_balance_before - transfer_amount_1
-    );
-    let sender_balance_before = sender_balance_after;
-
-    let token_transfer_request_2 =
-        make_erc20_transfer_request(sender2, erc20_token, recipient2, transfer_amount_2);
-
-    builder
-        .exec(token_transfer_request_2)
-        .expect_success()
-        .commit();
-
-    let sender_balance_after = erc20_check_balance_of(builder, erc20_token, sender1);
-    assert_eq!(sender_balance_after, sender_balance_before);
-
-    let account_1_balance_after = erc20_check_balance_of(builder, erc20_token, recipient1);
-    assert!(account_1_balance_after < account_1_balance_before);
-    assert_eq!(
-        account_1_balance_after,
-        transfer_amount_1 - transfer_amount_2
-    );
-
-    let account_2_balance_after = erc20_check_balance_of(builder, erc20_token, recipient2);
-    assert_eq!(account_2_balance_after, transfer_amount_2);
-}
-
 fn make_erc20_transfer_request(
     sender: Key,
     erc20_token: &ContractHash,
@@ -442,53 +418,6 @@ fn make_erc20_approve_request(
     }
 }
 
-fn test_approve_for(
-    builder: &mut InMemoryWasmTestBuilder,
-    test_context: &TestContext,
-    sender: Key,
-    owner: Key,
-    spender: Key,
-) {
-    let TestContext { erc20_token, .. } = test_context;
-    let initial_supply = U256::from(TOKEN_TOTAL_SUPPLY);
-    let allowance_amount_1 = U256::from(ALLOWANCE_AMOUNT_1);
-    let allowance_amount_2 = U256::from(ALLOWANCE_AMOUNT_2);
-
-    let spender_allowance_before = erc20_check_allowance_of(builder, owner, spender);
-    assert_eq!(spender_allowance_before, U256::zero());
-
-    let approve_request_1 =
-        make_erc20_approve_request(sender, erc20_token, spender, allowance_amount_1);
-    let approve_request_2 =
-        make_erc20_approve_request(sender, erc20_token, spender, allowance_amount_2);
-
-    builder.exec(approve_request_1).expect_success().commit();
-
-    {
-        let account_1_allowance_after = erc20_check_allowance_of(builder, owner, spender);
-        assert_eq!(account_1_allowance_after, allowance_amount_1);
-
-        let total_supply: U256 = builder.get_value(*erc20_token, TOTAL_SUPPLY_KEY);
-        assert_eq!(total_supply, initial_supply);
-    }
-
-    // Approve overwrites existing amount rather than increase it
-
-    builder.exec(approve_request_2).expect_success().commit();
-
-    let account_1_allowance_after = erc20_check_allowance_of(builder, owner, spender);
-    assert_eq!(account_1_allowance_after, allowance_amount_2);
-
-    // Swap Key::Account into Hash and other way
-    let inverted_spender_key = invert_erc20_address(spender);
-
-    let inverted_spender_allowance = erc20_check_allowance_of(builder, owner, inverted_spender_key);
-    assert_eq!(inverted_spender_allowance, U256::zero());
-
-    let total_supply: U256 = builder.get_value(*erc20_token, TOTAL_SUPPLY_KEY);
-    assert_eq!(total_supply, initial_supply);
-}
-
 #[test]
 fn should_have_queryable_properties() {
     let (mut builder, TestContext { erc20_token, .. }) = setup();
@@ -523,188 +452,121 @@ fn should_have_queryable_properties() {
 }
 
 #[test]
-fn should_not_store_balances_or_allowances_under_account_after_install() {
-    let (builder, _contract_hash) = setup();
-
-    let account = builder
-        .get_account(*DEFAULT_ACCOUNT_ADDR)
-        .expect("should have account");
-
-    let named_keys = account.named_keys();
-    assert!(!named_keys.contains_key(BALANCES_KEY), "{:?}", named_keys);
-    assert!(!named_keys.contains_key(ALLOWANCES_KEY), "{:?}", named_keys);
-}
+fn test_mint_and_burn_tokens() {
+    let mint_amount = U256::one();
 
-#[test]
-fn should_transfer_account_to_account() {
-    let (mut builder, test_context) = setup();
-    let sender1 = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-    let recipient1 = Key::Account(*ACCOUNT_1_ADDR);
-    let sender2 = Key::Account(*ACCOUNT_1_ADDR);
-    let recipient2 = Key::Account(*ACCOUNT_2_ADDR);
-
-    test_erc20_transfer(
-        &mut builder,
-        &test_context,
-        sender1,
-        recipient1,
-        sender2,
-        recipient2,
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+    assert_eq!(
+        erc20_check_balance_of(
+            &mut builder,
+            &test_contract,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY),
     );
-}
-
-#[test]
-fn should_transfer_account_to_contract() {
-    let (mut builder, test_context) = setup();
-
-    let sender1 = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-    let recipient1 = Key::Account(*ACCOUNT_1_ADDR);
-    let sender2 = Key::Account(*ACCOUNT_1_ADDR);
-    let recipient2 = Key::Hash(test_context.test_contract.value());
-
-    test_erc20_transfer(
-        &mut builder,
-        &test_context,
-        sender1,
-        recipient1,
-        sender2,
-        recipient2,
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        U256::from(TOKEN_OWNER_AMOUNT_1)
     );
-}
-
-#[test]
-fn should_transfer_contract_to_contract() {
-    let (mut builder, test_context) = setup();
-    let TestContext {
-        erc20_test_call, ..
-    } = test_context;
-
-    let sender1 = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-    let recipient1 = Key::Hash(erc20_test_call.value());
-    let sender2 = Key::Hash(erc20_test_call.value());
-    let recipient2 = Key::Hash([42; 32]);
-
-    test_erc20_transfer(
-        &mut builder,
-        &test_context,
-        sender1,
-        recipient1,
-        sender2,
-        recipient2,
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_2),
+        U256::from(TOKEN_OWNER_AMOUNT_2)
     );
-}
-
-#[test]
-fn should_transfer_contract_to_account() {
-    let (mut builder, test_context) = setup();
-    let TestContext {
-        erc20_test_call, ..
-    } = test_context;
+    let total_supply_before_mint = erc20_check_total_supply(&mut builder, &test_contract);
 
-    let sender1 = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-    let recipient1 = Key::Hash(erc20_test_call.value());
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
 
-    let sender2 = Key::Hash(erc20_test_call.value());
-    let recipient2 = Key::Account(*ACCOUNT_1_ADDR);
+    builder.exec(mint_request).expect_success().commit();
 
-    test_erc20_transfer(
-        &mut builder,
-        &test_context,
-        sender1,
-        recipient1,
-        sender2,
-        recipient2,
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        U256::from(TOKEN_OWNER_AMOUNT_1) + mint_amount,
     );
-}
-
-#[test]
-fn should_transfer_full_owned_amount() {
-    let (mut builder, TestContext { erc20_token, .. }) = setup();
-
-    let initial_supply = U256::from(TOKEN_TOTAL_SUPPLY);
-    let transfer_amount_1 = initial_supply;
-
-    let transfer_1_sender = *DEFAULT_ACCOUNT_ADDR;
-    let erc20_transfer_1_args = runtime_args! {
-        ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
-        ARG_AMOUNT => transfer_amount_1,
-    };
-
-    let owner_balance_before = erc20_check_balance_of(
-        &mut builder,
-        &erc20_token,
-        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_2),
+        U256::from(TOKEN_OWNER_AMOUNT_2)
     );
-    assert_eq!(owner_balance_before, initial_supply);
 
-    let account_1_balance_before =
-        erc20_check_balance_of(&mut builder, &erc20_token, Key::Account(*ACCOUNT_1_ADDR));
-    assert_eq!(account_1_balance_before, U256::zero());
+    let total_supply_after_mint = erc20_check_total_supply(&mut builder, &test_contract);
+    assert_eq!(
+        total_supply_after_mint,
+        total_supply_before_mint + mint_amount,
+    );
+    let total_supply_before_burn = total_supply_after_mint;
 
-    let token_transfer_request_1 = ExecuteRequestBuilder::contract_call_by_hash(
-        transfer_1_sender,
-        erc20_token,
-        METHOD_TRANSFER,
-        erc20_transfer_1_args,
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
     )
     .build();
 
-    builder
-        .exec(token_transfer_request_1)
-        .expect_success()
-        .commit();
-
-    let account_1_balance_after =
-        erc20_check_balance_of(&mut builder, &erc20_token, Key::Account(*ACCOUNT_1_ADDR));
-    assert_eq!(account_1_balance_after, transfer_amount_1);
+    builder.exec(burn_request).expect_success().commit();
 
-    let owner_balance_after = erc20_check_balance_of(
-        &mut builder,
-        &erc20_token,
-        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        U256::from(TOKEN_OWNER_AMOUNT_1),
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_2),
+        U256::from(TOKEN_OWNER_AMOUNT_2)
+    );
+    let total_supply_after_burn = erc20_check_total_supply(&mut builder, &test_contract);
+    assert_eq!(
+        total_supply_after_burn,
+        total_supply_before_burn - mint_amount,
     );
-    assert_eq!(owner_balance_after, U256::zero());
 
-    let total_supply: U256 = builder.get_value(erc20_token, TOTAL_SUPPLY_KEY);
-    assert_eq!(total_supply, initial_supply);
+    assert_eq!(total_supply_after_burn, total_supply_before_mint);
 }
 
 #[test]
-fn should_not_transfer_more_than_owned_balance() {
-    let (mut builder, TestContext { erc20_token, .. }) = setup();
-
-    let initial_supply = U256::from(TOKEN_TOTAL_SUPPLY);
-    let transfer_amount = initial_supply + U256::one();
-
-    let transfer_1_sender = *DEFAULT_ACCOUNT_ADDR;
-    let transfer_1_recipient = *ACCOUNT_1_ADDR;
-
-    let erc20_transfer_1_args = runtime_args! {
-        ARG_RECIPIENT => Key::Account(transfer_1_recipient),
-        ARG_AMOUNT => transfer_amount,
-    };
+fn test_should_not_burn_above_balance() {
+    let mint_amount = U256::MAX;
 
-    let owner_balance_before = erc20_check_balance_of(
-        &mut builder,
-        &erc20_token,
-        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+    assert_eq!(
+        erc20_check_balance_of(
+            &mut builder,
+            &test_contract,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY),
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        U256::from(TOKEN_OWNER_AMOUNT_1)
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_2),
+        U256::from(TOKEN_OWNER_AMOUNT_2)
     );
-    assert_eq!(owner_balance_before, initial_supply);
-    assert!(transfer_amount > owner_balance_before);
-
-    let account_1_balance_before =
-        erc20_check_balance_of(&mut builder, &erc20_token, Key::Account(*ACCOUNT_1_ADDR));
-    assert_eq!(account_1_balance_before, U256::zero());
 
-    let token_transfer_request_1 = ExecuteRequestBuilder::contract_call_by_hash(
-        transfer_1_sender,
-        erc20_token,
-        METHOD_TRANSFER,
-        erc20_transfer_1_args,
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
     )
     .build();
 
-    builder.exec(token_transfer_request_1).commit();
+    builder.exec(mint_request).commit();
 
     let error = builder.get_error().expect("should have error");
     assert!(
@@ -712,613 +574,1622 @@ fn should_not_transfer_more_than_owned_balance() {
         "{:?}",
         error
     );
-
-    let account_1_balance_after = erc20_check_balance_of(
-        &mut builder,
-        &erc20_token,
-        Key::Account(transfer_1_recipient),
-    );
-    assert_eq!(account_1_balance_after, account_1_balance_before);
-
-    let owner_balance_after =
-        erc20_check_balance_of(&mut builder, &erc20_token, Key::Account(transfer_1_sender));
-    assert_eq!(owner_balance_after, initial_supply);
-
-    let total_supply: U256 = builder.get_value(erc20_token, TOTAL_SUPPLY_KEY);
-    assert_eq!(total_supply, initial_supply);
 }
 
 #[test]
-fn should_approve_funds_account_to_account() {
-    let (mut builder, test_context) = setup();
+fn test_should_not_mint_above_limits() {
+    let mint_amount = U256::MAX;
 
-    test_approve_for(
-        &mut builder,
-        &test_context,
-        Key::Account(*DEFAULT_ACCOUNT_ADDR),
-        Key::Account(*DEFAULT_ACCOUNT_ADDR),
-        Key::Account(*ACCOUNT_1_ADDR),
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        U256::from(TOKEN_OWNER_AMOUNT_1)
     );
-}
 
-#[test]
-fn should_approve_funds_account_to_contract() {
-    let (mut builder, test_context) = setup();
-    test_approve_for(
-        &mut builder,
-        &test_context,
-        Key::Account(*DEFAULT_ACCOUNT_ADDR),
-        Key::Account(*DEFAULT_ACCOUNT_ADDR),
-        Key::Hash([42; 32]),
-    );
-}
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
 
-#[test]
-fn should_approve_funds_contract_to_account() {
-    let (mut builder, test_context) = setup();
-    let TestContext {
-        erc20_test_call, ..
-    } = test_context;
+    builder.exec(mint_request).commit();
 
-    test_approve_for(
-        &mut builder,
-        &test_context,
-        Key::Hash(erc20_test_call.value()),
-        Key::Hash(erc20_test_call.value()),
-        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_OVERFLOW),
+        "{:?}",
+        error
     );
 }
 
 #[test]
-fn should_approve_funds_contract_to_contract() {
-    let (mut builder, test_context) = setup();
-    let TestContext {
-        erc20_test_call, ..
-    } = test_context;
-
-    test_approve_for(
-        &mut builder,
-        &test_context,
-        Key::Hash(erc20_test_call.value()),
-        Key::Hash(erc20_test_call.value()),
-        Key::Hash([42; 32]),
-    );
-}
+fn should_not_transfer_to_or_from_frozen_account() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
 
-#[test]
-fn should_not_transfer_from_without_enough_allowance() {
-    let (mut builder, TestContext { erc20_token, .. }) = setup();
-
-    let allowance_amount_1 = U256::from(ALLOWANCE_AMOUNT_1);
-    let transfer_from_amount_1 = allowance_amount_1 + U256::one();
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
 
-    let sender = *DEFAULT_ACCOUNT_ADDR;
-    let owner = sender;
-    let recipient = *ACCOUNT_1_ADDR;
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ADDRESS => recipient,
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
 
-    let erc20_approve_args = runtime_args! {
-        ARG_OWNER => Key::Account(owner),
-        ARG_SPENDER => Key::Account(recipient),
-        ARG_AMOUNT => allowance_amount_1,
-    };
-    let erc20_transfer_from_args = runtime_args! {
-        ARG_OWNER => Key::Account(owner),
-        ARG_RECIPIENT => Key::Account(recipient),
-        ARG_AMOUNT => transfer_from_amount_1,
-    };
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).commit();
 
-    let spender_allowance_before =
-        erc20_check_allowance_of(&mut builder, Key::Account(owner), Key::Account(recipient));
-    assert_eq!(spender_allowance_before, U256::zero());
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+        "{:?}",
+        error
+    );
 
-    let approve_request_1 = ExecuteRequestBuilder::contract_call_by_hash(
-        sender,
-        erc20_token,
-        METHOD_APPROVE,
-        erc20_approve_args,
+    let unfreeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_UNFREEZE,
+        runtime_args! {
+            ARG_ADDRESS => recipient,
+        },
     )
     .build();
+    builder.exec(unfreeze_request).expect_success().commit();
 
-    let transfer_from_request_1 = ExecuteRequestBuilder::contract_call_by_hash(
-        sender,
-        erc20_token,
-        METHOD_TRANSFER_FROM,
-        erc20_transfer_from_args,
-    )
-    .build();
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
 
-    builder.exec(approve_request_1).expect_success().commit();
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        transfer_amount
+    );
 
-    let account_1_allowance_after =
-        erc20_check_allowance_of(&mut builder, Key::Account(owner), Key::Account(recipient));
-    assert_eq!(account_1_allowance_after, allowance_amount_1);
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ADDRESS => recipient,
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
 
-    builder.exec(transfer_from_request_1).commit();
+    let transfer_back_request =
+        make_erc20_transfer_request(recipient, &test_contract, sender, transfer_amount);
+    builder.exec(transfer_back_request).commit();
 
     let error = builder.get_error().expect("should have error");
     assert!(
-        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
         "{:?}",
         error
     );
 }
 
 #[test]
-fn should_transfer_from_from_account_to_account() {
-    let (mut builder, TestContext { erc20_token, .. }) = setup();
+fn should_not_freeze_without_admin_role() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
 
-    let initial_supply = U256::from(TOKEN_TOTAL_SUPPLY);
-    let allowance_amount_1 = U256::from(ALLOWANCE_AMOUNT_1);
-    let transfer_from_amount_1 = allowance_amount_1;
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_contract,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ADDRESS => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        },
+    )
+    .build();
+    builder.exec(freeze_request).commit();
 
-    let owner = *DEFAULT_ACCOUNT_ADDR;
-    let spender = *ACCOUNT_1_ADDR;
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PERMISSION_DENIED),
+        "{:?}",
+        error
+    );
+}
 
-    let erc20_approve_args = runtime_args! {
-        ARG_OWNER => Key::Account(owner),
-        ARG_SPENDER => Key::Account(spender),
-        ARG_AMOUNT => allowance_amount_1,
-    };
-    let erc20_transfer_from_args = runtime_args! {
-        ARG_OWNER => Key::Account(owner),
-        ARG_RECIPIENT => Key::Account(spender),
-        ARG_AMOUNT => transfer_from_amount_1,
-    };
+#[test]
+fn should_wipe_frozen_balance() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
 
-    let spender_allowance_before =
-        erc20_check_allowance_of(&mut builder, Key::Account(owner), Key::Account(spender));
-    assert_eq!(spender_allowance_before, U256::zero());
+    let total_supply_before = erc20_check_total_supply(&mut builder, &test_contract);
 
-    let approve_request_1 = ExecuteRequestBuilder::contract_call_by_hash(
-        owner,
-        erc20_token,
-        METHOD_APPROVE,
-        erc20_approve_args,
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ADDRESS => TOKEN_OWNER_ADDRESS_1,
+        },
     )
     .build();
+    builder.exec(freeze_request).expect_success().commit();
 
-    let transfer_from_request_1 = ExecuteRequestBuilder::contract_call_by_hash(
-        spender,
-        erc20_token,
-        METHOD_TRANSFER_FROM,
-        erc20_transfer_from_args,
+    let wipe_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_WIPE_FROZEN_BALANCE,
+        runtime_args! {
+            ARG_ADDRESS => TOKEN_OWNER_ADDRESS_1,
+        },
     )
     .build();
+    builder.exec(wipe_request).expect_success().commit();
 
-    builder.exec(approve_request_1).expect_success().commit();
-
-    let account_1_balance_before =
-        erc20_check_balance_of(&mut builder, &erc20_token, Key::Account(owner));
-    assert_eq!(account_1_balance_before, initial_supply);
-
-    let account_1_allowance_before =
-        erc20_check_allowance_of(&mut builder, Key::Account(owner), Key::Account(spender));
-    assert_eq!(account_1_allowance_before, allowance_amount_1);
-
-    builder
-        .exec(transfer_from_request_1)
-        .expect_success()
-        .commit();
-
-    let account_1_allowance_after =
-        erc20_check_allowance_of(&mut builder, Key::Account(owner), Key::Account(spender));
     assert_eq!(
-        account_1_allowance_after,
-        account_1_allowance_before - transfer_from_amount_1
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        U256::zero()
     );
-
-    let account_1_balance_after =
-        erc20_check_balance_of(&mut builder, &erc20_token, Key::Account(owner));
     assert_eq!(
-        account_1_balance_after,
-        account_1_balance_before - transfer_from_amount_1
+        erc20_check_total_supply(&mut builder, &test_contract),
+        total_supply_before - U256::from(TOKEN_OWNER_AMOUNT_1)
     );
 }
 
 #[test]
-fn should_transfer_from_account_by_contract() {
-    let (
-        mut builder,
-        TestContext {
-            erc20_token,
-            erc20_test_call,
-            ..
+fn should_not_wipe_balance_of_unfrozen_account() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let wipe_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_WIPE_FROZEN_BALANCE,
+        runtime_args! {
+            ARG_ADDRESS => TOKEN_OWNER_ADDRESS_1,
         },
-    ) = setup();
+    )
+    .build();
+    builder.exec(wipe_request).commit();
 
-    let initial_supply = U256::from(TOKEN_TOTAL_SUPPLY);
-    let allowance_amount_1 = U256::from(ALLOWANCE_AMOUNT_1);
-    let transfer_from_amount_1 = allowance_amount_1;
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_NOT_FROZEN),
+        "{:?}",
+        error
+    );
+}
 
-    let owner = *DEFAULT_ACCOUNT_ADDR;
+fn erc20_check_has_role(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    role: &str,
+    address: Key,
+) -> bool {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
 
-    let spender = Key::Hash(erc20_test_call.value());
-    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let erc20_test_contract_hash = account
+        .named_keys()
+        .get(ERC20_TEST_CALL_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
 
-    let erc20_approve_args = runtime_args! {
-        ARG_OWNER => Key::Account(owner),
-        ARG_SPENDER => spender,
-        ARG_AMOUNT => allowance_amount_1,
-    };
-    let erc20_transfer_from_args = runtime_args! {
-        ARG_TOKEN_CONTRACT => erc20_token,
-        ARG_OWNER => Key::Account(owner),
-        ARG_RECIPIENT => recipient,
-        ARG_AMOUNT => transfer_from_amount_1,
+    let check_has_role_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+        ARG_ROLE => role,
+        ARG_ADDRESS => address,
     };
-
-    let spender_allowance_before =
-        erc20_check_allowance_of(&mut builder, Key::Account(owner), spender);
-    assert_eq!(spender_allowance_before, U256::zero());
-
-    let approve_request_1 = ExecuteRequestBuilder::contract_call_by_hash(
-        owner,
-        erc20_token,
-        METHOD_APPROVE,
-        erc20_approve_args,
-    )
-    .build();
-
-    let transfer_from_request_1 = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
         *DEFAULT_ACCOUNT_ADDR,
-        erc20_test_call,
+        erc20_test_contract_hash,
         None,
-        METHOD_FROM_AS_STORED_CONTRACT,
-        erc20_transfer_from_args,
+        CHECK_HAS_ROLE_ENTRYPOINT,
+        check_has_role_args,
     )
     .build();
+    builder.exec(exec_request).expect_success().commit();
 
-    builder.exec(approve_request_1).expect_success().commit();
-
-    let owner_balance_before =
-        erc20_check_balance_of(&mut builder, &erc20_token, Key::Account(owner));
-    assert_eq!(owner_balance_before, initial_supply);
-
-    let spender_allowance_before =
-        erc20_check_allowance_of(&mut builder, Key::Account(owner), spender);
-    assert_eq!(spender_allowance_before, allowance_amount_1);
-
-    builder
-        .exec(transfer_from_request_1)
-        .expect_success()
-        .commit();
-
-    let spender_allowance_after =
-        erc20_check_allowance_of(&mut builder, Key::Account(owner), spender);
-    assert_eq!(
-        spender_allowance_after,
-        spender_allowance_before - transfer_from_amount_1
-    );
-
-    let owner_balance_after =
-        erc20_check_balance_of(&mut builder, &erc20_token, Key::Account(owner));
-    assert_eq!(
-        owner_balance_after,
-        owner_balance_before - transfer_from_amount_1
-    );
+    get_test_result(builder, erc20_test_contract_hash)
 }
 
 #[test]
-fn test_mint_and_burn_tokens() {
-    let mint_amount = U256::one();
-
+fn should_grant_and_revoke_role() {
     let (mut builder, TestContext { test_contract, .. }) = setup();
-    assert_eq!(
-        erc20_check_balance_of(
-            &mut builder,
-            &test_contract,
-            Key::Account(*DEFAULT_ACCOUNT_ADDR)
-        ),
-        U256::from(TOKEN_TOTAL_SUPPLY),
-    );
-    assert_eq!(
-        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
-        U256::from(TOKEN_OWNER_AMOUNT_1)
-    );
-    assert_eq!(
-        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_2),
-        U256::from(TOKEN_OWNER_AMOUNT_2)
-    );
-    let total_supply_before_mint = erc20_check_total_supply(&mut builder, &test_contract);
 
-    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+    let installer = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let admin = Key::Account(*ACCOUNT_1_ADDR);
+
+    assert!(erc20_check_has_role(
+        &mut builder,
+        &test_contract,
+        ADMIN_ROLE,
+        installer
+    ));
+    assert!(!erc20_check_has_role(
+        &mut builder,
+        &test_contract,
+        ADMIN_ROLE,
+        admin
+    ));
+
+    let grant_role_request = ExecuteRequestBuilder::contract_call_by_hash(
         *DEFAULT_ACCOUNT_ADDR,
         test_contract,
-        METHOD_MINT,
+        METHOD_GRANT_ROLE,
         runtime_args! {
-            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
-            ARG_AMOUNT => mint_amount,
+            ARG_ROLE => ADMIN_ROLE,
+            ARG_ADDRESS => admin,
         },
     )
     .build();
+    builder.exec(grant_role_request).expect_success().commit();
 
-    builder.exec(mint_request).expect_success().commit();
-
-    assert_eq!(
-        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
-        U256::from(TOKEN_OWNER_AMOUNT_1) + mint_amount,
-    );
-    assert_eq!(
-        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_2),
-        U256::from(TOKEN_OWNER_AMOUNT_2)
-    );
-
-    let total_supply_after_mint = erc20_check_total_supply(&mut builder, &test_contract);
-    assert_eq!(
-        total_supply_after_mint,
-        total_supply_before_mint + mint_amount,
-    );
-    let total_supply_before_burn = total_supply_after_mint;
-
-    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
-        *DEFAULT_ACCOUNT_ADDR,
+    assert!(erc20_check_has_role(
+        &mut builder,
+        &test_contract,
+        ADMIN_ROLE,
+        admin
+    ));
+
+    // The new admin is able to revoke the role from the installer.
+    let revoke_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
         test_contract,
-        METHOD_BURN,
+        METHOD_REVOKE_ROLE,
         runtime_args! {
-            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
-            ARG_AMOUNT => mint_amount,
+            ARG_ROLE => ADMIN_ROLE,
+            ARG_ADDRESS => installer,
         },
     )
     .build();
+    builder.exec(revoke_role_request).expect_success().commit();
 
-    builder.exec(burn_request).expect_success().commit();
-
-    assert_eq!(
-        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
-        U256::from(TOKEN_OWNER_AMOUNT_1),
-    );
-    assert_eq!(
-        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_2),
-        U256::from(TOKEN_OWNER_AMOUNT_2)
-    );
-    let total_supply_after_burn = erc20_check_total_supply(&mut builder, &test_contract);
-    assert_eq!(
-        total_supply_after_burn,
-        total_supply_before_burn - mint_amount,
-    );
-
-    assert_eq!(total_supply_after_burn, total_supply_before_mint);
-}
-
-#[test]
-fn test_should_not_burn_above_balance() {
-    let mint_amount = U256::MAX;
-
-    let (mut builder, TestContext { test_contract, .. }) = setup();
-    assert_eq!(
-        erc20_check_balance_of(
-            &mut builder,
-            &test_contract,
-            Key::Account(*DEFAULT_ACCOUNT_ADDR)
-        ),
-        U256::from(TOKEN_TOTAL_SUPPLY),
-    );
-    assert_eq!(
-        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
-        U256::from(TOKEN_OWNER_AMOUNT_1)
-    );
-    assert_eq!(
-        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_2),
-        U256::from(TOKEN_OWNER_AMOUNT_2)
-    );
+    assert!(!erc20_check_has_role(
+        &mut builder,
+        &test_contract,
+        ADMIN_ROLE,
+        installer
+    ));
 
-    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
         *DEFAULT_ACCOUNT_ADDR,
         test_contract,
-        METHOD_BURN,
+        METHOD_FREEZE,
         runtime_args! {
-            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
-            ARG_AMOUNT => mint_amount,
+            ARG_ADDRESS => TOKEN_OWNER_ADDRESS_1,
         },
     )
     .build();
-
-    builder.exec(mint_request).commit();
+    builder.exec(freeze_request).commit();
 
     let error = builder.get_error().expect("should have error");
     assert!(
-        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_BALANCE),
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PERMISSION_DENIED),
         "{:?}",
         error
     );
 }
 
 #[test]
-fn test_should_not_mint_above_limits() {
-    let mint_amount = U256::MAX;
-
+fn should_not_revoke_role_without_admin_role() {
     let (mut builder, TestContext { test_contract, .. }) = setup();
-    assert_eq!(
-        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
-        U256::from(TOKEN_OWNER_AMOUNT_1)
-    );
 
-    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
-        *DEFAULT_ACCOUNT_ADDR,
+    let installer = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let revoke_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
         test_contract,
-        METHOD_MINT,
+        METHOD_REVOKE_ROLE,
         runtime_args! {
-            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
-            ARG_AMOUNT => mint_amount,
+            ARG_ROLE => ADMIN_ROLE,
+            ARG_ADDRESS => installer,
         },
     )
     .build();
-
-    builder.exec(mint_request).commit();
+    builder.exec(revoke_role_request).commit();
 
     let error = builder.get_error().expect("should have error");
     assert!(
-        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_OVERFLOW),
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PERMISSION_DENIED),
         "{:?}",
         error
     );
+    assert!(erc20_check_has_role(
+        &mut builder,
+        &test_contract,
+        ADMIN_ROLE,
+        installer
+    ));
+}
+
+fn setup_with_allowlist() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_test_contract_args(runtime_args! {
+        ARG_ALLOWLIST => vec![
+            Key::Account(*ACCOUNT_1_ADDR),
+            TOKEN_OWNER_ADDRESS_1,
+            TOKEN_OWNER_ADDRESS_2,
+        ],
+    })
 }
 
 #[test]
-fn should_have_correct_balance_after_own_transfer() {
-    let (mut builder, TestContext { erc20_token, .. }) = setup();
+fn should_only_transfer_between_allowlisted_addresses() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_allowlist();
 
     let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-    let recipient = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-
+    let allowlisted_recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
     let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
 
-    let sender_balance_before = erc20_check_balance_of(&mut builder, &erc20_token, sender);
-    let recipient_balance_before = erc20_check_balance_of(&mut builder, &erc20_token, recipient);
-
-    assert_eq!(sender_balance_before, recipient_balance_before);
+    let transfer_request = make_erc20_transfer_request(
+        sender,
+        &test_contract,
+        allowlisted_recipient,
+        transfer_amount,
+    );
+    builder.exec(transfer_request).expect_success().commit();
 
-    let token_transfer_request_1 =
-        make_erc20_transfer_request(sender, &erc20_token, recipient, transfer_amount);
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).commit();
 
-    builder
-        .exec(token_transfer_request_1)
-        .expect_success()
-        .commit();
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_ALLOWLISTED),
+        "{:?}",
+        error
+    );
 
-    let sender_balance_after = erc20_check_balance_of(&mut builder, &erc20_token, sender);
-    assert_eq!(sender_balance_before, sender_balance_after);
+    let add_to_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_ADD_TO_ALLOWLIST,
+        runtime_args! {
+            ARG_ADDRESSES => vec![recipient],
+        },
+    )
+    .build();
+    builder
+        .exec(add_to_allowlist_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        transfer_amount
+    );
+
+    let remove_from_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_REMOVE_FROM_ALLOWLIST,
+        runtime_args! {
+            ARG_ADDRESSES => vec![recipient],
+        },
+    )
+    .build();
+    builder
+        .exec(remove_from_allowlist_request)
+        .expect_success()
+        .commit();
 
-    let recipient_balance_after = erc20_check_balance_of(&mut builder, &erc20_token, recipient);
-    assert_eq!(recipient_balance_before, recipient_balance_after);
+    let transfer_request =
+        make_erc20_transfer_request(recipient, &test_contract, sender, transfer_amount);
+    builder.exec(transfer_request).commit();
 
-    assert_eq!(sender_balance_after, recipient_balance_after);
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_ALLOWLISTED),
+        "{:?}",
+        error
+    );
 }
 
 #[test]
-fn should_have_correct_balance_after_own_transfer_from() {
-    let (mut builder, TestContext { erc20_token, .. }) = setup();
+fn should_not_mint_outside_of_allowlist() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_allowlist();
 
-    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-    let spender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-    let recipient = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
 
-    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
-    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_ALLOWLISTED),
+        "{:?}",
+        error
+    );
+}
 
-    let approve_request =
-        make_erc20_approve_request(sender, &erc20_token, spender, allowance_amount);
+#[test]
+fn should_wipe_frozen_balance_outside_of_allowlist() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_allowlist();
 
-    builder.exec(approve_request).expect_success().commit();
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ADDRESS => TOKEN_OWNER_ADDRESS_1,
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let remove_from_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_REMOVE_FROM_ALLOWLIST,
+        runtime_args! {
+            ARG_ADDRESSES => vec![TOKEN_OWNER_ADDRESS_1],
+        },
+    )
+    .build();
+    builder
+        .exec(remove_from_allowlist_request)
+        .expect_success()
+        .commit();
 
-    let spender_allowance_before = erc20_check_allowance_of(&mut builder, owner, spender);
+    let wipe_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_WIPE_FROZEN_BALANCE,
+        runtime_args! {
+            ARG_ADDRESS => TOKEN_OWNER_ADDRESS_1,
+        },
+    )
+    .build();
+    builder.exec(wipe_request).expect_success().commit();
 
-    let sender_balance_before = erc20_check_balance_of(&mut builder, &erc20_token, sender);
-    let recipient_balance_before = erc20_check_balance_of(&mut builder, &erc20_token, recipient);
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        U256::zero()
+    );
+}
 
-    assert_eq!(sender_balance_before, recipient_balance_before);
+#[test]
+fn should_not_manage_allowlist_when_disabled() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
 
-    let transfer_from_request = {
-        let erc20_transfer_from_args = runtime_args! {
-            ARG_OWNER => owner,
-            ARG_RECIPIENT => recipient,
-            ARG_AMOUNT => transfer_amount,
-        };
-        ExecuteRequestBuilder::contract_call_by_hash(
-            sender.into_account().unwrap(),
-            erc20_token,
-            METHOD_TRANSFER_FROM,
-            erc20_transfer_from_args,
-        )
-        .build()
+    let add_to_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_ADD_TO_ALLOWLIST,
+        runtime_args! {
+            ARG_ADDRESSES => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .build();
+    builder.exec(add_to_allowlist_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_FEATURE_DISABLED),
+        "{:?}",
+        error
+    );
+}
+
+fn erc20_check_force_transfer_record(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    index: u64,
+) -> ForceTransferRecord {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let erc20_test_contract_hash = account
+        .named_keys()
+        .get(ERC20_TEST_CALL_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_record_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+        ARG_INDEX => index,
     };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_contract_hash,
+        None,
+        CHECK_FORCE_TRANSFER_RECORD_ENTRYPOINT,
+        check_record_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_contract_hash)
+}
+
+#[test]
+fn should_force_transfer_and_record_audit_entry() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let operator = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let grant_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_GRANT_ROLE,
+        runtime_args! {
+            ARG_ROLE => FORCE_TRANSFER_ROLE,
+            ARG_ADDRESS => operator,
+        },
+    )
+    .build();
+    builder.exec(grant_role_request).expect_success().commit();
 
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_contract,
+        METHOD_FORCE_TRANSFER,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => transfer_amount,
+            ARG_REASON => FORCE_TRANSFER_REASON,
+        },
+    )
+    .with_block_time(FORCE_TRANSFER_BLOCK_TIME)
+    .build();
     builder
-        .exec(transfer_from_request)
+        .exec(force_transfer_request)
         .expect_success()
         .commit();
 
-    let sender_balance_after = erc20_check_balance_of(&mut builder, &erc20_token, sender);
-    assert_eq!(sender_balance_before, sender_balance_after);
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        U256::from(TOKEN_OWNER_AMOUNT_1) - transfer_amount
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        transfer_amount
+    );
+
+    let force_transfer_count: u64 = builder.get_value(test_contract, FORCE_TRANSFER_COUNT_KEY);
+    assert_eq!(force_transfer_count, 1);
 
-    let recipient_balance_after = erc20_check_balance_of(&mut builder, &erc20_token, recipient);
-    assert_eq!(recipient_balance_before, recipient_balance_after);
+    let record = erc20_check_force_transfer_record(&mut builder, &test_contract, 0);
+    assert_eq!(Key::from(record.operator), operator);
+    assert_eq!(Key::from(record.owner), TOKEN_OWNER_ADDRESS_1);
+    assert_eq!(Key::from(record.recipient), recipient);
+    assert_eq!(record.amount, transfer_amount);
+    assert_eq!(record.reason, FORCE_TRANSFER_REASON);
+    assert_eq!(record.timestamp, FORCE_TRANSFER_BLOCK_TIME);
+}
 
-    assert_eq!(sender_balance_after, recipient_balance_after);
+#[test]
+fn should_not_force_transfer_without_role() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
 
-    let spender_allowance_after = erc20_check_allowance_of(&mut builder, owner, spender);
-    assert_eq!(
-        spender_allowance_after,
-        spender_allowance_before - transfer_amount
+    // Being an admin does not imply being allowed to force transfers.
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_FORCE_TRANSFER,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_RECIPIENT => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_AMOUNT => U256::one(),
+            ARG_REASON => FORCE_TRANSFER_REASON,
+        },
+    )
+    .build();
+    builder.exec(force_transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PERMISSION_DENIED),
+        "{:?}",
+        error
     );
 }
 
+fn setup_with_transfer_fee() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_test_contract_args(runtime_args! {
+        ARG_TRANSFER_FEE_BASIS_POINTS => TRANSFER_FEE_BASIS_POINTS,
+        ARG_MAX_FEE => U256::from(TRANSFER_FEE_MAX_FEE),
+        ARG_FEE_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+        ARG_FEE_EXEMPT => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    })
+}
+
 #[test]
-fn should_verify_zero_amount_transfer_is_noop() {
-    let (mut builder, TestContext { erc20_token, .. }) = setup();
+fn should_route_transfer_fee_to_fee_recipient() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_transfer_fee();
 
     let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = TOKEN_OWNER_ADDRESS_1;
+    let fee_recipient = Key::Account(*ACCOUNT_2_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
 
-    let transfer_amount = U256::zero();
+    let sender_balance_before = erc20_check_balance_of(&mut builder, &test_contract, sender);
 
-    let sender_balance_before = erc20_check_balance_of(&mut builder, &erc20_token, sender);
-    let recipient_balance_before = erc20_check_balance_of(&mut builder, &erc20_token, recipient);
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
 
-    let token_transfer_request_1 =
-        make_erc20_transfer_request(sender, &erc20_token, recipient, transfer_amount);
+    // 2.5% of 200_001 tokens, rounded down.
+    let expected_fee = U256::from(5_000);
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, sender),
+        sender_balance_before - transfer_amount
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        U256::from(TOKEN_OWNER_AMOUNT_1) + transfer_amount - expected_fee
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, fee_recipient),
+        expected_fee
+    );
 
+    let set_transfer_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_SET_TRANSFER_FEE,
+        runtime_args! {
+            ARG_BASIS_POINTS => TRANSFER_FEE_BASIS_POINTS,
+            ARG_MAX_FEE => U256::from(TRANSFER_FEE_LOWER_MAX_FEE),
+            ARG_FEE_RECIPIENT => fee_recipient,
+        },
+    )
+    .build();
     builder
-        .exec(token_transfer_request_1)
+        .exec(set_transfer_fee_request)
         .expect_success()
         .commit();
 
-    let sender_balance_after = erc20_check_balance_of(&mut builder, &erc20_token, sender);
-    assert_eq!(sender_balance_before, sender_balance_after);
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
 
-    let recipient_balance_after = erc20_check_balance_of(&mut builder, &erc20_token, recipient);
-    assert_eq!(recipient_balance_before, recipient_balance_after);
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, fee_recipient),
+        expected_fee + U256::from(TRANSFER_FEE_LOWER_MAX_FEE)
+    );
 }
 
 #[test]
-fn should_verify_zero_amount_transfer_from_is_noop() {
-    let (mut builder, TestContext { erc20_token, .. }) = setup();
+fn should_not_charge_transfer_fee_to_exempt_address() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_transfer_fee();
 
-    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
-    let spender = Key::Account(*ACCOUNT_1_ADDR);
-    let sender = Key::Account(*ACCOUNT_1_ADDR);
-    let recipient = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let exempt_recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let fee_recipient = Key::Account(*ACCOUNT_2_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
 
-    let allowance_amount = U256::from(1);
-    let transfer_amount = U256::zero();
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, exempt_recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
 
-    let approve_request =
-        make_erc20_approve_request(sender, &erc20_token, spender, allowance_amount);
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, exempt_recipient),
+        transfer_amount
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, fee_recipient),
+        U256::zero()
+    );
+}
 
-    builder.exec(approve_request).expect_success().commit();
+#[test]
+fn should_not_transfer_more_than_balance_with_transfer_fee() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_transfer_fee();
 
-    let spender_allowance_before = erc20_check_allowance_of(&mut builder, owner, spender);
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = TOKEN_OWNER_ADDRESS_1;
+    let fee_recipient = Key::Account(*ACCOUNT_2_ADDR);
 
-    let sender_balance_before = erc20_check_balance_of(&mut builder, &erc20_token, sender);
-    let recipient_balance_before = erc20_check_balance_of(&mut builder, &erc20_token, recipient);
+    let sender_balance = erc20_check_balance_of(&mut builder, &test_contract, sender);
 
-    let transfer_from_request = {
-        let erc20_transfer_from_args = runtime_args! {
-            ARG_OWNER => owner,
-            ARG_RECIPIENT => recipient,
-            ARG_AMOUNT => transfer_amount,
-        };
-        ExecuteRequestBuilder::contract_call_by_hash(
-            sender.into_account().unwrap(),
-            erc20_token,
-            METHOD_TRANSFER_FROM,
-            erc20_transfer_from_args,
-        )
-        .build()
-    };
+    let transfer_request = make_erc20_transfer_request(
+        sender,
+        &test_contract,
+        recipient,
+        sender_balance + U256::one(),
+    );
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_BALANCE),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, sender),
+        sender_balance
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, fee_recipient),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_route_transfer_fee_to_frozen_fee_recipient() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_transfer_fee();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = TOKEN_OWNER_ADDRESS_1;
+    let fee_recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_FREEZE,
+        runtime_args! {
+            ARG_ADDRESS => fee_recipient,
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
 
+    let transfer_request = make_erc20_transfer_request(
+        sender,
+        &test_contract,
+        recipient,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_route_transfer_fee_outside_of_allowlist() {
+    let (mut builder, TestContext { test_contract, .. }) =
+        setup_with_test_contract_args(runtime_args! {
+            ARG_ALLOWLIST => vec![TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2],
+            ARG_TRANSFER_FEE_BASIS_POINTS => TRANSFER_FEE_BASIS_POINTS,
+            ARG_MAX_FEE => U256::from(TRANSFER_FEE_MAX_FEE),
+            ARG_FEE_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_FEE_EXEMPT => Vec::<Key>::new(),
+        });
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = TOKEN_OWNER_ADDRESS_1;
+    let fee_recipient = Key::Account(*ACCOUNT_2_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_ALLOWLISTED),
+        "{:?}",
+        error
+    );
+
+    let add_to_allowlist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_ADD_TO_ALLOWLIST,
+        runtime_args! {
+            ARG_ADDRESSES => vec![fee_recipient],
+        },
+    )
+    .build();
     builder
-        .exec(transfer_from_request)
+        .exec(add_to_allowlist_request)
         .expect_success()
         .commit();
 
-    let sender_balance_after = erc20_check_balance_of(&mut builder, &erc20_token, sender);
-    assert_eq!(sender_balance_before, sender_balance_after);
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
 
-    let recipient_balance_after = erc20_check_balance_of(&mut builder, &erc20_token, recipient);
-    assert_eq!(recipient_balance_before, recipient_balance_after);
+    assert!(!erc20_check_balance_of(&mut builder, &test_contract, fee_recipient).is_zero());
+}
+
+#[test]
+fn should_not_set_transfer_fee_above_max_basis_points() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_transfer_fee();
 
-    let spender_allowance_after = erc20_check_allowance_of(&mut builder, owner, spender);
-    assert_eq!(spender_allowance_after, spender_allowance_before);
+    let set_transfer_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_SET_TRANSFER_FEE,
+        runtime_args! {
+            ARG_BASIS_POINTS => 10_001u32,
+            ARG_MAX_FEE => U256::from(TRANSFER_FEE_MAX_FEE),
+            ARG_FEE_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+        },
+    )
+    .build();
+    builder.exec(set_transfer_fee_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_FEE),
+        "{:?}",
+        error
+    );
+}
+
+fn erc20_check_holder_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    index: u64,
+) -> Option<Key> {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let erc20_test_contract_hash = account
+        .named_keys()
+        .get(ERC20_TEST_CALL_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_holder_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+        ARG_INDEX => index,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_contract_hash,
+        None,
+        CHECK_HOLDER_AT_ENTRYPOINT,
+        check_holder_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_contract_hash)
+}
+
+fn erc20_check_holders(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+) -> Vec<Key> {
+    let holder_count: u64 = builder.get_value(*erc20_contract_hash, HOLDER_COUNT_KEY);
+    let mut holders = Vec::new();
+    for index in 0..holder_count {
+        let holder = erc20_check_holder_at(builder, erc20_contract_hash, index);
+        holders.push(holder.expect("should have holder"));
+    }
+    assert_eq!(
+        erc20_check_holder_at(builder, erc20_contract_hash, holder_count),
+        None
+    );
+    holders
+}
+
+fn setup_with_holder_tracking() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_test_contract_args(runtime_args! {
+        ARG_HOLDER_TRACKING => true,
+    })
+}
+
+#[test]
+fn should_track_holders() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_holder_tracking();
+
+    let installer = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    assert_eq!(
+        erc20_check_holders(&mut builder, &test_contract),
+        vec![installer, TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2]
+    );
+
+    // Burning the whole balance moves the last holder into the freed slot.
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(TOKEN_OWNER_AMOUNT_1),
+        },
+    )
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_holders(&mut builder, &test_contract),
+        vec![installer, TOKEN_OWNER_ADDRESS_2]
+    );
+
+    let transfer_request =
+        make_erc20_transfer_request(installer, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    // Receiving more tokens does not add the holder twice.
+    let transfer_request =
+        make_erc20_transfer_request(installer, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_holders(&mut builder, &test_contract),
+        vec![installer, TOKEN_OWNER_ADDRESS_2, recipient]
+    );
+
+    let transfer_request =
+        make_erc20_transfer_request(recipient, &test_contract, installer, transfer_amount * 2);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_holders(&mut builder, &test_contract),
+        vec![installer, TOKEN_OWNER_ADDRESS_2]
+    );
+}
+
+#[test]
+fn should_not_count_holders_when_disabled() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let holder_count_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_HOLDER_COUNT,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(holder_count_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_FEATURE_DISABLED),
+        "{:?}",
+        error
+    );
+}
+
+fn erc20_check_batch<T: FromBytes + CLTyped>(
+    builder: &mut InMemoryWasmTestBuilder,
+    entry_point: &str,
+    args: RuntimeArgs,
+) -> T {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let erc20_test_contract_hash = account
+        .named_keys()
+        .get(ERC20_TEST_CALL_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_contract_hash,
+        None,
+        entry_point,
+        args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_contract_hash)
+}
+
+#[test]
+fn should_query_balances_and_allowances_in_batch() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let approve_request =
+        make_erc20_approve_request(owner, &test_contract, spender, allowance_amount);
+    builder.exec(approve_request).expect_success().commit();
+
+    let balances: Vec<U256> = erc20_check_batch(
+        &mut builder,
+        CHECK_BALANCES_OF_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => test_contract,
+            ARG_ADDRESSES => vec![
+                owner,
+                TOKEN_OWNER_ADDRESS_1,
+                TOKEN_OWNER_ADDRESS_2,
+                spender,
+                owner,
+            ],
+        },
+    );
+    assert_eq!(
+        balances,
+        vec![
+            U256::from(TOKEN_TOTAL_SUPPLY),
+            U256::from(TOKEN_OWNER_AMOUNT_1),
+            U256::from(TOKEN_OWNER_AMOUNT_2),
+            U256::zero(),
+            U256::from(TOKEN_TOTAL_SUPPLY),
+        ]
+    );
+
+    let allowances: Vec<U256> = erc20_check_batch(
+        &mut builder,
+        CHECK_ALLOWANCES_OF_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => test_contract,
+            ARG_PAIRS => vec![(owner, spender), (spender, owner)],
+        },
+    );
+    assert_eq!(allowances, vec![allowance_amount, U256::zero()]);
+
+    let balances: Vec<U256> = erc20_check_batch(
+        &mut builder,
+        CHECK_BALANCES_OF_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => test_contract,
+            ARG_ADDRESSES => Vec::<Key>::new(),
+        },
+    );
+    assert!(balances.is_empty());
+}
+
+#[test]
+fn should_query_example_token_in_batch() {
+    let (mut builder, TestContext { erc20_token, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let approve_request =
+        make_erc20_approve_request(owner, &erc20_token, spender, allowance_amount);
+    builder.exec(approve_request).expect_success().commit();
+
+    let balances: Vec<U256> = erc20_check_batch(
+        &mut builder,
+        CHECK_BALANCES_OF_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => erc20_token,
+            ARG_ADDRESSES => vec![owner, spender],
+        },
+    );
+    assert_eq!(balances, vec![U256::from(TOKEN_TOTAL_SUPPLY), U256::zero()]);
+
+    let allowances: Vec<U256> = erc20_check_batch(
+        &mut builder,
+        CHECK_ALLOWANCES_OF_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => erc20_token,
+            ARG_PAIRS => vec![(owner, spender), (spender, owner)],
+        },
+    );
+    assert_eq!(allowances, vec![allowance_amount, U256::zero()]);
+}
+
+#[test]
+fn should_install_with_initial_owner() {
+    let (mut builder, TestContext { test_contract, .. }) =
+        setup_with_test_contract_args(runtime_args! {
+            ARG_INITIAL_OWNER => Key::Account(*ACCOUNT_1_ADDR),
+        });
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+    assert_eq!(
+        erc20_check_balance_of(
+            &mut builder,
+            &test_contract,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_install_with_genesis_distribution() {
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+    let (mut builder, TestContext { test_contract, .. }) =
+        setup_with_test_contract_args(runtime_args! {
+            ARG_GENESIS_DISTRIBUTION => vec![
+                (account_1, U256::from(GENESIS_AMOUNT_1)),
+                (account_2, U256::from(GENESIS_AMOUNT_2)),
+            ],
+        });
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, account_1),
+        U256::from(GENESIS_AMOUNT_1)
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, account_2),
+        U256::from(GENESIS_AMOUNT_2)
+    );
+    assert_eq!(
+        erc20_check_balance_of(
+            &mut builder,
+            &test_contract,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_install_with_genesis_distribution_not_matching_supply() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+    // Leaves one token of the initial supply undistributed.
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ERC20_TEST,
+        runtime_args! {
+            ARG_GENESIS_DISTRIBUTION => vec![
+                (Key::Account(*ACCOUNT_1_ADDR), U256::from(GENESIS_AMOUNT_1)),
+                (Key::Account(*ACCOUNT_2_ADDR), U256::from(GENESIS_AMOUNT_2 - 1)),
+            ],
+        },
+    )
+    .build();
+    builder.exec(install_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_DISTRIBUTION),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_install_with_invalid_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+    for (name, symbol, decimals, expected_error) in [
+        ("", TOKEN_SYMBOL, TOKEN_DECIMALS, ERROR_INVALID_NAME),
+        (
+            "Casper\nTest",
+            TOKEN_SYMBOL,
+            TOKEN_DECIMALS,
+            ERROR_INVALID_NAME,
+        ),
+        (
+            " CasperTest",
+            TOKEN_SYMBOL,
+            TOKEN_DECIMALS,
+            ERROR_INVALID_NAME,
+        ),
+        (TOKEN_NAME, "", TOKEN_DECIMALS, ERROR_INVALID_SYMBOL),
+        (TOKEN_NAME, "CSPR-T", TOKEN_DECIMALS, ERROR_INVALID_SYMBOL),
+        (
+            TOKEN_NAME,
+            "CSPRTCSPRTCSPRT",
+            TOKEN_DECIMALS,
+            ERROR_INVALID_SYMBOL,
+        ),
+        (TOKEN_NAME, TOKEN_SYMBOL, 19, ERROR_INVALID_DECIMALS),
+        (TOKEN_NAME, TOKEN_SYMBOL, u8::MAX, ERROR_INVALID_DECIMALS),
+    ] {
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            EXAMPLE_ERC20_TOKEN,
+            runtime_args! {
+                ARG_NAME => name,
+                ARG_SYMBOL => symbol,
+                ARG_DECIMALS => decimals,
+                ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            },
+        )
+        .build();
+        builder.exec(install_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+            "{:?} {:?} {} {:?}",
+            name,
+            symbol,
+            decimals,
+            error
+        );
+    }
+}
+
+#[test]
+fn should_install_without_metadata_validation() {
+    let (mut builder, TestContext { test_contract, .. }) =
+        setup_with_test_contract_args(runtime_args! {
+            ARG_DECIMALS => u8::MAX,
+            ARG_METADATA_VALIDATION => false,
+        });
+
+    let decimals: u8 = builder.get_value(test_contract, DECIMALS_KEY);
+    assert_eq!(decimals, u8::MAX);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ERC20_TEST,
+        runtime_args! {
+            ARG_DECIMALS => u8::MAX,
+        },
+    )
+    .build();
+    builder.exec(install_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_DECIMALS),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_install_with_extra_named_key_of_token() {
+    let (mut builder, TestContext { test_contract, .. }) =
+        setup_with_test_contract_args(runtime_args! {
+            ARG_EXTRA_NAMED_KEY => EXTRA_NAMED_KEY,
+        });
+
+    let contract = builder
+        .get_contract(test_contract)
+        .expect("should have contract");
+    assert!(contract.named_keys().contains_key(EXTRA_NAMED_KEY));
+
+    // A key named like the one of the transfer fee would otherwise enable fees.
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ERC20_TEST,
+        runtime_args! {
+            ARG_EXTRA_NAMED_KEY => TRANSFER_FEE_KEY,
+        },
+    )
+    .build();
+    builder.exec(install_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RESERVED_KEY_NAME),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_mint_above_max_supply() {
+    let (mut builder, TestContext { test_contract, .. }) =
+        setup_with_test_contract_args(runtime_args! {
+            ARG_MAX_SUPPLY => U256::from(TEST_CONTRACT_SUPPLY),
+        });
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_SUPPLY_CAP_EXCEEDED),
+        "{:?}",
+        error
+    );
+}
+
+fn make_emit_request(test_contract: &ContractHash, block_time: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        *test_contract,
+        METHOD_EMIT,
+        RuntimeArgs::default(),
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_emit_tokens_on_schedule() {
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let (mut builder, TestContext { test_contract, .. }) =
+        setup_with_test_contract_args(runtime_args! {
+            ARG_MAX_SUPPLY => U256::from(TEST_CONTRACT_SUPPLY + EMISSION_CAP),
+            ARG_EMISSION_RECIPIENT => recipient,
+            ARG_EMISSION_TIME_UNIT => EMISSION_TIME_UNIT,
+            ARG_EMISSION_PERIODS => vec![
+                (EMISSION_START, U256::from(EMISSION_RATE)),
+                (EMISSION_START + EMISSION_HALVING, U256::from(EMISSION_RATE / 2)),
+            ],
+        });
+
+    // Nothing accrues before the schedule starts.
+    let emit_request = make_emit_request(&test_contract, EMISSION_START - 1);
+    builder.exec(emit_request).expect_success().commit();
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        U256::zero()
+    );
+
+    let emit_request = make_emit_request(&test_contract, EMISSION_START + EMISSION_HALVING / 2);
+    builder.exec(emit_request).expect_success().commit();
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        U256::from(5_000)
+    );
+
+    // Accrual spans the halving: 5 time units at the full rate and 5 at half of it.
+    let emit_request = make_emit_request(&test_contract, EMISSION_START + EMISSION_HALVING * 3 / 2);
+    builder.exec(emit_request).expect_success().commit();
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        U256::from(12_500)
+    );
+
+    // Emission stops at the max supply.
+    let emit_request = make_emit_request(&test_contract, EMISSION_START + EMISSION_HALVING * 10);
+    builder.exec(emit_request).expect_success().commit();
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        U256::from(EMISSION_CAP)
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &test_contract),
+        U256::from(TEST_CONTRACT_SUPPLY + EMISSION_CAP)
+    );
+}
+
+fn setup_with_transfer_burn() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_test_contract_args(runtime_args! {
+        ARG_BURN_BASIS_POINTS => BURN_BASIS_POINTS,
+        ARG_MAX_BURN_BASIS_POINTS => MAX_BURN_BASIS_POINTS,
+        ARG_BURN_EXEMPT => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    })
+}
+
+#[test]
+fn should_burn_on_transfer() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_transfer_burn();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let exempt_recipient = Key::Account(*ACCOUNT_2_ADDR);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let burned = U256::from(TRANSFER_AMOUNT_1 * u64::from(BURN_BASIS_POINTS) / 10_000);
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY) - transfer_amount
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        transfer_amount - burned
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &test_contract),
+        U256::from(TEST_CONTRACT_SUPPLY) - burned
+    );
+    let total_burned: U256 = builder.get_value(test_contract, TOTAL_BURNED_KEY);
+    assert_eq!(total_burned, burned);
+
+    // Nothing is burned when either side is exempt.
+    let transfer_request =
+        make_erc20_transfer_request(sender, &test_contract, exempt_recipient, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, exempt_recipient),
+        transfer_amount
+    );
+    let total_burned: U256 = builder.get_value(test_contract, TOTAL_BURNED_KEY);
+    assert_eq!(total_burned, burned);
+}
+
+#[test]
+fn should_not_transfer_more_than_balance_with_transfer_burn() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_transfer_burn();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    let sender_balance = erc20_check_balance_of(&mut builder, &test_contract, sender);
+    let total_supply = erc20_check_total_supply(&mut builder, &test_contract);
+
+    let transfer_request = make_erc20_transfer_request(
+        sender,
+        &test_contract,
+        recipient,
+        sender_balance + U256::one(),
+    );
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_BALANCE),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, sender),
+        sender_balance
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &test_contract),
+        total_supply
+    );
+}
+
+#[test]
+fn should_not_set_burn_rate_above_max() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_transfer_burn();
+
+    let set_burn_basis_points_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_SET_BURN_BASIS_POINTS,
+        runtime_args! {
+            ARG_BASIS_POINTS => MAX_BURN_BASIS_POINTS,
+        },
+    )
+    .build();
+    builder
+        .exec(set_burn_basis_points_request)
+        .expect_success()
+        .commit();
+
+    let set_burn_basis_points_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_SET_BURN_BASIS_POINTS,
+        runtime_args! {
+            ARG_BASIS_POINTS => MAX_BURN_BASIS_POINTS + 1,
+        },
+    )
+    .build();
+    builder.exec(set_burn_basis_points_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_BURN_RATE),
+        "{:?}",
+        error
+    );
+}
+
+fn setup_with_rebasing() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_test_contract_args(runtime_args! {
+        ARG_REBASING => true,
+    })
+}
+
+fn make_rebase_request(
+    sender: AccountHash,
+    test_contract: &ContractHash,
+    total_supply: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *test_contract,
+        METHOD_REBASE,
+        runtime_args! {
+            ARG_TOTAL_SUPPLY => total_supply,
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_rebase_balances() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_rebasing();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    let rebase_request = make_rebase_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        U256::from(TEST_CONTRACT_SUPPLY * 2),
+    );
+    builder.exec(rebase_request).expect_success().commit();
+
+    // Shares are unchanged, while every balance doubles.
+    let total_shares: U256 = builder.get_value(test_contract, TOTAL_SHARES_KEY);
+    assert_eq!(total_shares, U256::from(TEST_CONTRACT_SUPPLY));
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY * 2)
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        U256::from(TOKEN_OWNER_AMOUNT_1 * 2)
+    );
+
+    // An odd amount is worth half a share more than a whole number of shares, so the sender is
+    // debited a share rounded up.
+    let transfer_request = make_erc20_transfer_request(
+        sender,
+        &test_contract,
+        recipient,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let transferred = U256::from(TRANSFER_AMOUNT_1 + 1);
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        transferred
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY * 2) - transferred
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &test_contract),
+        U256::from(TEST_CONTRACT_SUPPLY * 2)
+    );
+}
+
+#[test]
+fn should_not_rebase_without_permission_or_to_zero() {
+    let (mut builder, TestContext { test_contract, .. }) = setup_with_rebasing();
+
+    let rebase_request = make_rebase_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        U256::from(TEST_CONTRACT_SUPPLY * 2),
+    );
+    builder.exec(rebase_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PERMISSION_DENIED),
+        "{:?}",
+        error
+    );
+
+    let rebase_request = make_rebase_request(*DEFAULT_ACCOUNT_ADDR, &test_contract, U256::zero());
+    builder.exec(rebase_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_REBASE),
+        "{:?}",
+        error
+    );
 }
@@ -0,0 +1,393 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utils::{self, assert_user_error, check_balance_of, ACCOUNT_1_ADDR, TOKEN_TOTAL_SUPPLY};
+
+const EXAMPLE_ERC20_POOL: &str = "erc20_pool.wasm";
+const ERC20_POOL_KEY: &str = "erc20_pool";
+
+const ARG_NAME: &str = "name";
+const ARG_SYMBOL: &str = "symbol";
+const ARG_TOKEN_A: &str = "token_a";
+const ARG_TOKEN_B: &str = "token_b";
+const ARG_SPENDER: &str = "spender";
+const ARG_AMOUNT: &str = "amount";
+const ARG_AMOUNT_A_DESIRED: &str = "amount_a_desired";
+const ARG_AMOUNT_B_DESIRED: &str = "amount_b_desired";
+const ARG_AMOUNT_A_MIN: &str = "amount_a_min";
+const ARG_AMOUNT_B_MIN: &str = "amount_b_min";
+const ARG_LIQUIDITY: &str = "liquidity";
+const ARG_TOKEN_IN: &str = "token_in";
+const ARG_AMOUNT_IN: &str = "amount_in";
+const ARG_AMOUNT_OUT_MIN: &str = "amount_out_min";
+
+const METHOD_APPROVE: &str = "approve";
+const METHOD_ADD_LIQUIDITY: &str = "add_liquidity";
+const METHOD_REMOVE_LIQUIDITY: &str = "remove_liquidity";
+const METHOD_SWAP_EXACT_IN: &str = "swap_exact_in";
+
+const ERROR_INVALID_TOKEN: u16 = 2;
+const ERROR_INSUFFICIENT_A_AMOUNT: u16 = 3;
+const ERROR_INSUFFICIENT_OUTPUT_AMOUNT: u16 = 9;
+
+const TOKEN_A_NAME: &str = "CasperA";
+const TOKEN_A_SYMBOL: &str = "CSPRA";
+const TOKEN_B_NAME: &str = "CasperB";
+const TOKEN_B_SYMBOL: &str = "CSPRB";
+const POOL_NAME: &str = "CasperA-CasperB LP";
+const POOL_SYMBOL: &str = "CSPRLP";
+
+const LIQUIDITY_A: u64 = 1_000_000;
+const LIQUIDITY_B: u64 = 4_000_000;
+/// Geometric mean of both deposits, i.e. the square root of `LIQUIDITY_A * LIQUIDITY_B`.
+const INITIAL_LIQUIDITY: u64 = 2_000_000;
+const MINIMUM_LIQUIDITY: u64 = 1_000;
+const SWAP_AMOUNT_IN: u64 = 10_000;
+
+#[derive(Copy, Clone)]
+struct TestContext {
+    token_a: ContractHash,
+    token_b: ContractHash,
+    erc20_pool: ContractHash,
+    erc20_pool_package: ContractPackageHash,
+}
+
+fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_token_a(|builder| utils::install_token(builder, TOKEN_A_NAME, TOKEN_A_SYMBOL))
+}
+
+/// Installs token A with `install_token_a`, token B, and the pool trading them.
+fn setup_with_token_a(
+    install_token_a: fn(&mut InMemoryWasmTestBuilder) -> ContractHash,
+) -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut builder = utils::setup();
+
+    let token_a = install_token_a(&mut builder);
+    let token_b = utils::install_token(&mut builder, TOKEN_B_NAME, TOKEN_B_SYMBOL);
+
+    utils::install_contract(
+        &mut builder,
+        EXAMPLE_ERC20_POOL,
+        runtime_args! {
+            ARG_TOKEN_A => token_a,
+            ARG_TOKEN_B => token_b,
+            ARG_NAME => POOL_NAME,
+            ARG_SYMBOL => POOL_SYMBOL,
+        },
+    );
+
+    let erc20_pool = utils::get_contract_hash(&builder, ERC20_POOL_KEY);
+    let erc20_pool_package = builder
+        .get_contract(erc20_pool)
+        .expect("should have pool contract")
+        .contract_package_hash();
+
+    let test_context = TestContext {
+        token_a,
+        token_b,
+        erc20_pool,
+        erc20_pool_package,
+    };
+
+    (builder, test_context)
+}
+
+fn approve_pool(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    sender: AccountHash,
+    token_contract: ContractHash,
+    amount: u64,
+) {
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        token_contract,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => Key::Hash(test_context.erc20_pool_package.value()),
+            ARG_AMOUNT => U256::from(amount),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+}
+
+fn make_add_liquidity_request(
+    test_context: &TestContext,
+    amount_a: u64,
+    amount_b: u64,
+    amount_a_min: u64,
+    amount_b_min: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.erc20_pool,
+        METHOD_ADD_LIQUIDITY,
+        runtime_args! {
+            ARG_AMOUNT_A_DESIRED => U256::from(amount_a),
+            ARG_AMOUNT_B_DESIRED => U256::from(amount_b),
+            ARG_AMOUNT_A_MIN => U256::from(amount_a_min),
+            ARG_AMOUNT_B_MIN => U256::from(amount_b_min),
+        },
+    )
+    .build()
+}
+
+fn make_remove_liquidity_request(test_context: &TestContext, liquidity: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.erc20_pool,
+        METHOD_REMOVE_LIQUIDITY,
+        runtime_args! {
+            ARG_LIQUIDITY => U256::from(liquidity),
+            ARG_AMOUNT_A_MIN => U256::zero(),
+            ARG_AMOUNT_B_MIN => U256::zero(),
+        },
+    )
+    .build()
+}
+
+/// Has the default account seed the pool with `LIQUIDITY_A` and `LIQUIDITY_B`.
+fn seed_pool(builder: &mut InMemoryWasmTestBuilder, test_context: &TestContext) {
+    approve_pool(
+        builder,
+        test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.token_a,
+        LIQUIDITY_A,
+    );
+    approve_pool(
+        builder,
+        test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.token_b,
+        LIQUIDITY_B,
+    );
+    let add_liquidity_request =
+        make_add_liquidity_request(test_context, LIQUIDITY_A, LIQUIDITY_B, 0, 0);
+    builder
+        .exec(add_liquidity_request)
+        .expect_success()
+        .commit();
+}
+
+fn make_swap_request(
+    test_context: &TestContext,
+    token_in: ContractHash,
+    amount_out_min: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.erc20_pool,
+        METHOD_SWAP_EXACT_IN,
+        runtime_args! {
+            ARG_TOKEN_IN => token_in,
+            ARG_AMOUNT_IN => U256::from(SWAP_AMOUNT_IN),
+            ARG_AMOUNT_OUT_MIN => U256::from(amount_out_min),
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_add_and_remove_liquidity() {
+    let (mut builder, test_context) = setup();
+    seed_pool(&mut builder, &test_context);
+
+    let provider = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let pool_address = Key::Hash(test_context.erc20_pool_package.value());
+    let liquidity = INITIAL_LIQUIDITY - MINIMUM_LIQUIDITY;
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_pool, provider),
+        U256::from(liquidity)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_pool, pool_address),
+        U256::from(MINIMUM_LIQUIDITY)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, pool_address),
+        U256::from(LIQUIDITY_A)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_b, pool_address),
+        U256::from(LIQUIDITY_B)
+    );
+
+    let remove_liquidity_request = make_remove_liquidity_request(&test_context, liquidity);
+    builder
+        .exec(remove_liquidity_request)
+        .expect_success()
+        .commit();
+
+    // Only the locked minimum liquidity keeps its share of the reserves.
+    let locked_a = LIQUIDITY_A * MINIMUM_LIQUIDITY / INITIAL_LIQUIDITY;
+    let locked_b = LIQUIDITY_B * MINIMUM_LIQUIDITY / INITIAL_LIQUIDITY;
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_pool, provider),
+        U256::zero()
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, provider),
+        U256::from(TOKEN_TOTAL_SUPPLY - locked_a)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_b, pool_address),
+        U256::from(locked_b)
+    );
+}
+
+#[test]
+fn should_keep_to_reserve_ratio_when_adding_liquidity() {
+    let (mut builder, test_context) = setup();
+    seed_pool(&mut builder, &test_context);
+
+    // At one A for four B, depositing the same amount of both only takes a quarter of the A.
+    let amount = 1_000;
+    approve_pool(
+        &mut builder,
+        &test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.token_a,
+        amount,
+    );
+    approve_pool(
+        &mut builder,
+        &test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.token_b,
+        amount,
+    );
+    let add_liquidity_request =
+        make_add_liquidity_request(&test_context, amount, amount, amount, amount);
+    builder.exec(add_liquidity_request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_A_AMOUNT);
+
+    let add_liquidity_request =
+        make_add_liquidity_request(&test_context, amount, amount, amount / 4, amount);
+    builder
+        .exec(add_liquidity_request)
+        .expect_success()
+        .commit();
+
+    let pool_address = Key::Hash(test_context.erc20_pool_package.value());
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, pool_address),
+        U256::from(LIQUIDITY_A + amount / 4)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_b, pool_address),
+        U256::from(LIQUIDITY_B + amount)
+    );
+}
+
+#[test]
+fn should_swap_exact_in_within_slippage_bound() {
+    let (mut builder, test_context) = setup();
+    seed_pool(&mut builder, &test_context);
+
+    utils::transfer(
+        &mut builder,
+        test_context.token_a,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(SWAP_AMOUNT_IN),
+    );
+    approve_pool(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        test_context.token_a,
+        SWAP_AMOUNT_IN,
+    );
+
+    // A 0.3% fee is taken off the input before the constant product is applied.
+    let amount_in_with_fee = SWAP_AMOUNT_IN * 9_970;
+    let expected_amount_out =
+        amount_in_with_fee * LIQUIDITY_B / (LIQUIDITY_A * 10_000 + amount_in_with_fee);
+
+    let swap_request = make_swap_request(&test_context, test_context.erc20_pool, 0);
+    builder.exec(swap_request).commit();
+    assert_user_error(&builder, ERROR_INVALID_TOKEN);
+
+    let swap_request =
+        make_swap_request(&test_context, test_context.token_a, expected_amount_out + 1);
+    builder.exec(swap_request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_OUTPUT_AMOUNT);
+
+    let swap_request = make_swap_request(&test_context, test_context.token_a, expected_amount_out);
+    builder.exec(swap_request).expect_success().commit();
+
+    let trader = Key::Account(*ACCOUNT_1_ADDR);
+    let pool_address = Key::Hash(test_context.erc20_pool_package.value());
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, trader),
+        U256::zero()
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_b, trader),
+        U256::from(expected_amount_out)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, pool_address),
+        U256::from(LIQUIDITY_A + SWAP_AMOUNT_IN)
+    );
+}
+
+#[test]
+fn should_only_count_tokens_received_by_pool() {
+    let (mut builder, test_context) = setup_with_token_a(utils::install_fee_token);
+    seed_pool(&mut builder, &test_context);
+
+    utils::transfer(
+        &mut builder,
+        test_context.token_a,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(SWAP_AMOUNT_IN),
+    );
+    approve_pool(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        test_context.token_a,
+        SWAP_AMOUNT_IN,
+    );
+    let swap_request = make_swap_request(&test_context, test_context.token_a, 0);
+    builder.exec(swap_request).expect_success().commit();
+
+    // Paying A in is charged a fee, so the swap is priced on what the pool received.
+    let amount_in = SWAP_AMOUNT_IN - utils::transfer_fee(SWAP_AMOUNT_IN);
+    let amount_in_with_fee = amount_in * 9_970;
+    let expected_amount_out =
+        amount_in_with_fee * LIQUIDITY_B / (LIQUIDITY_A * 10_000 + amount_in_with_fee);
+    let pool_address = Key::Hash(test_context.erc20_pool_package.value());
+    let pool_balance_a = LIQUIDITY_A + amount_in;
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.token_b,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::from(expected_amount_out)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, pool_address),
+        U256::from(pool_balance_a)
+    );
+
+    let liquidity = INITIAL_LIQUIDITY - MINIMUM_LIQUIDITY;
+    let remove_liquidity_request = make_remove_liquidity_request(&test_context, liquidity);
+    builder
+        .exec(remove_liquidity_request)
+        .expect_success()
+        .commit();
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.token_a, pool_address),
+        U256::from(pool_balance_a - liquidity * pool_balance_a / INITIAL_LIQUIDITY)
+    );
+}
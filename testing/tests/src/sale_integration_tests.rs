@@ -0,0 +1,383 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+    U512,
+};
+
+use crate::utils::{
+    self, assert_user_error, check_balance_of, ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, TOKEN_NAME,
+    TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+};
+
+const EXAMPLE_ERC20_SALE: &str = "erc20_sale.wasm";
+const EXAMPLE_ERC20_SALE_BUY: &str = "erc20_sale_buy.wasm";
+const ERC20_SALE_KEY: &str = "erc20_sale";
+const ERC20_SALE_PACKAGE_KEY: &str = "erc20_sale_package";
+const PURSE_KEY: &str = "purse";
+
+const ARG_TOKEN_CONTRACT: &str = "token_contract";
+const ARG_AMOUNT: &str = "amount";
+const ARG_SALE_CONTRACT: &str = "sale_contract";
+const ARG_START_TIME: &str = "start_time";
+const ARG_END_TIME: &str = "end_time";
+const ARG_PRICE: &str = "price";
+const ARG_TIERS: &str = "tiers";
+const ARG_HARD_CAP: &str = "hard_cap";
+const ARG_SOFT_CAP: &str = "soft_cap";
+const ARG_MAX_PER_BUYER: &str = "max_per_buyer";
+const ARG_MINT: &str = "mint";
+
+const METHOD_CLAIM: &str = "claim";
+const METHOD_REFUND: &str = "refund";
+const METHOD_WITHDRAW: &str = "withdraw";
+
+const ERROR_PERMISSION_DENIED: u16 = u16::MAX - 4;
+const ERROR_SALE_NOT_ACTIVE: u16 = 2;
+const ERROR_SALE_NOT_ENDED: u16 = 3;
+const ERROR_HARD_CAP_EXCEEDED: u16 = 5;
+const ERROR_BUYER_LIMIT_EXCEEDED: u16 = 6;
+const ERROR_SOFT_CAP_NOT_MET: u16 = 8;
+const ERROR_SOFT_CAP_MET: u16 = 9;
+const ERROR_NOTHING_TO_CLAIM: u16 = 10;
+const ERROR_INSUFFICIENT_TOKENS: u16 = 13;
+
+/// One whole token, in the token's smallest unit.
+const TOKEN_UNIT: u64 = 100_000_000;
+
+/// Motes per whole token.
+const PRICE: u64 = 100_000_000;
+const HARD_CAP: u64 = 5 * TOKEN_UNIT;
+const START_TIME: u64 = 1_700_000_000_000;
+const END_TIME: u64 = START_TIME + 60_000;
+
+#[derive(Copy, Clone)]
+struct TestContext {
+    erc20_token: ContractHash,
+    erc20_sale: ContractHash,
+    erc20_sale_package: ContractPackageHash,
+}
+
+struct SaleConfig {
+    tiers: Vec<(U256, U512)>,
+    soft_cap: U512,
+    max_per_buyer: Option<U256>,
+    funded: U256,
+}
+
+impl Default for SaleConfig {
+    fn default() -> Self {
+        Self {
+            tiers: Vec::new(),
+            soft_cap: U512::from(PRICE),
+            max_per_buyer: None,
+            funded: U256::from(HARD_CAP),
+        }
+    }
+}
+
+/// Installs the token and a sale of up to `HARD_CAP` tokens, of which the default account funds
+/// `config.funded`, and gives accounts 1 and 2 some motes to buy with.
+fn setup(config: SaleConfig) -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut builder = utils::setup();
+    let erc20_token = utils::install_token(&mut builder, TOKEN_NAME, TOKEN_SYMBOL);
+
+    utils::install_contract(
+        &mut builder,
+        EXAMPLE_ERC20_SALE,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => erc20_token,
+            ARG_START_TIME => START_TIME,
+            ARG_END_TIME => END_TIME,
+            ARG_PRICE => U512::from(PRICE),
+            ARG_TIERS => config.tiers,
+            ARG_HARD_CAP => U256::from(HARD_CAP),
+            ARG_SOFT_CAP => config.soft_cap,
+            ARG_MAX_PER_BUYER => config.max_per_buyer,
+            ARG_MINT => false,
+        },
+    );
+
+    let test_context = TestContext {
+        erc20_token,
+        erc20_sale: utils::get_contract_hash(&builder, ERC20_SALE_KEY),
+        erc20_sale_package: utils::get_contract_package_hash(&builder, ERC20_SALE_PACKAGE_KEY),
+    };
+
+    utils::transfer(
+        &mut builder,
+        erc20_token,
+        Key::Hash(test_context.erc20_sale_package.value()),
+        config.funded,
+    );
+
+    (builder, test_context)
+}
+
+/// Returns the motes held by the sale contract.
+fn sale_purse_balance(builder: &InMemoryWasmTestBuilder, test_context: &TestContext) -> U512 {
+    let purse = builder
+        .get_contract(test_context.erc20_sale)
+        .expect("should have contract")
+        .named_keys()
+        .get(PURSE_KEY)
+        .and_then(|key| key.into_uref())
+        .expect("should have purse");
+    builder.get_purse_balance(purse)
+}
+
+fn make_buy_request(
+    buyer: AccountHash,
+    test_context: &TestContext,
+    amount: u64,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        buyer,
+        EXAMPLE_ERC20_SALE_BUY,
+        runtime_args! {
+            ARG_SALE_CONTRACT => test_context.erc20_sale,
+            ARG_AMOUNT => U256::from(amount),
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+fn make_sale_request(
+    sender: AccountHash,
+    test_context: &TestContext,
+    entry_point: &str,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        test_context.erc20_sale,
+        entry_point,
+        RuntimeArgs::default(),
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_deliver_tokens_and_proceeds_after_successful_sale() {
+    let (mut builder, test_context) = setup(SaleConfig::default());
+
+    let buy_request = make_buy_request(*ACCOUNT_1_ADDR, &test_context, TOKEN_UNIT, START_TIME - 1);
+    builder.exec(buy_request).commit();
+    assert_user_error(&builder, ERROR_SALE_NOT_ACTIVE);
+
+    let buy_request = make_buy_request(*ACCOUNT_1_ADDR, &test_context, TOKEN_UNIT, START_TIME);
+    builder.exec(buy_request).expect_success().commit();
+    let buy_request = make_buy_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        2 * TOKEN_UNIT,
+        START_TIME + 1,
+    );
+    builder.exec(buy_request).expect_success().commit();
+    assert_eq!(
+        sale_purse_balance(&builder, &test_context),
+        U512::from(3 * PRICE)
+    );
+
+    let claim_request =
+        make_sale_request(*ACCOUNT_1_ADDR, &test_context, METHOD_CLAIM, END_TIME - 1);
+    builder.exec(claim_request).commit();
+    assert_user_error(&builder, ERROR_SALE_NOT_ENDED);
+
+    let claim_request = make_sale_request(*ACCOUNT_1_ADDR, &test_context, METHOD_CLAIM, END_TIME);
+    builder.exec(claim_request).expect_success().commit();
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::from(TOKEN_UNIT)
+    );
+
+    let claim_request = make_sale_request(*ACCOUNT_1_ADDR, &test_context, METHOD_CLAIM, END_TIME);
+    builder.exec(claim_request).commit();
+    assert_user_error(&builder, ERROR_NOTHING_TO_CLAIM);
+
+    let refund_request = make_sale_request(*ACCOUNT_2_ADDR, &test_context, METHOD_REFUND, END_TIME);
+    builder.exec(refund_request).commit();
+    assert_user_error(&builder, ERROR_SOFT_CAP_MET);
+
+    let withdraw_request =
+        make_sale_request(*ACCOUNT_1_ADDR, &test_context, METHOD_WITHDRAW, END_TIME);
+    builder.exec(withdraw_request).commit();
+    assert_user_error(&builder, ERROR_PERMISSION_DENIED);
+
+    let withdraw_request = make_sale_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        METHOD_WITHDRAW,
+        END_TIME,
+    );
+    builder.exec(withdraw_request).expect_success().commit();
+    assert_eq!(sale_purse_balance(&builder, &test_context), U512::zero());
+
+    // Unsold tokens go back to the owner, while account 2 can still claim its purchase.
+    let sale = Key::Hash(test_context.erc20_sale_package.value());
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, sale),
+        U256::from(2 * TOKEN_UNIT)
+    );
+    let claim_request = make_sale_request(*ACCOUNT_2_ADDR, &test_context, METHOD_CLAIM, END_TIME);
+    builder.exec(claim_request).expect_success().commit();
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_2_ADDR)
+        ),
+        U256::from(2 * TOKEN_UNIT)
+    );
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY - 3 * TOKEN_UNIT)
+    );
+}
+
+#[test]
+fn should_price_purchase_across_tiers() {
+    let tiers = vec![(U256::from(2 * TOKEN_UNIT), U512::from(2 * PRICE))];
+    let (mut builder, test_context) = setup(SaleConfig {
+        tiers,
+        ..Default::default()
+    });
+
+    let buy_request = make_buy_request(*ACCOUNT_1_ADDR, &test_context, TOKEN_UNIT, START_TIME);
+    builder.exec(buy_request).expect_success().commit();
+    assert_eq!(
+        sale_purse_balance(&builder, &test_context),
+        U512::from(PRICE)
+    );
+
+    // One token at the base price, then half a token at the second tier's price.
+    let buy_request = make_buy_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        3 * TOKEN_UNIT / 2,
+        START_TIME,
+    );
+    builder.exec(buy_request).expect_success().commit();
+    assert_eq!(
+        sale_purse_balance(&builder, &test_context),
+        U512::from(3 * PRICE)
+    );
+}
+
+#[test]
+fn should_enforce_hard_cap_and_buyer_limit() {
+    let (mut builder, test_context) = setup(SaleConfig {
+        max_per_buyer: Some(U256::from(3 * TOKEN_UNIT)),
+        ..Default::default()
+    });
+
+    let buy_request = make_buy_request(*ACCOUNT_1_ADDR, &test_context, 2 * TOKEN_UNIT, START_TIME);
+    builder.exec(buy_request).expect_success().commit();
+    let buy_request = make_buy_request(*ACCOUNT_1_ADDR, &test_context, 2 * TOKEN_UNIT, START_TIME);
+    builder.exec(buy_request).commit();
+    assert_user_error(&builder, ERROR_BUYER_LIMIT_EXCEEDED);
+
+    let buy_request = make_buy_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        HARD_CAP - TOKEN_UNIT,
+        START_TIME,
+    );
+    builder.exec(buy_request).commit();
+    assert_user_error(&builder, ERROR_HARD_CAP_EXCEEDED);
+
+    // Selling out the hard cap ends the sale early.
+    let buy_request = make_buy_request(*ACCOUNT_2_ADDR, &test_context, 3 * TOKEN_UNIT, START_TIME);
+    builder.exec(buy_request).expect_success().commit();
+    let claim_request =
+        make_sale_request(*ACCOUNT_2_ADDR, &test_context, METHOD_CLAIM, START_TIME + 1);
+    builder.exec(claim_request).expect_success().commit();
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_2_ADDR)
+        ),
+        U256::from(3 * TOKEN_UNIT)
+    );
+}
+
+#[test]
+fn should_not_sell_more_tokens_than_funded() {
+    let (mut builder, test_context) = setup(SaleConfig {
+        funded: U256::from(2 * TOKEN_UNIT),
+        ..Default::default()
+    });
+
+    let buy_request = make_buy_request(*ACCOUNT_1_ADDR, &test_context, 3 * TOKEN_UNIT, START_TIME);
+    builder.exec(buy_request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_TOKENS);
+
+    let buy_request = make_buy_request(*ACCOUNT_1_ADDR, &test_context, 2 * TOKEN_UNIT, START_TIME);
+    builder.exec(buy_request).expect_success().commit();
+    let buy_request = make_buy_request(*ACCOUNT_2_ADDR, &test_context, TOKEN_UNIT, START_TIME);
+    builder.exec(buy_request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_TOKENS);
+
+    let claim_request =
+        make_sale_request(*ACCOUNT_1_ADDR, &test_context, METHOD_CLAIM, END_TIME + 1);
+    builder.exec(claim_request).expect_success().commit();
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::from(2 * TOKEN_UNIT)
+    );
+}
+
+#[test]
+fn should_refund_buyers_when_soft_cap_is_not_met() {
+    let (mut builder, test_context) = setup(SaleConfig {
+        soft_cap: U512::from(10 * PRICE),
+        ..Default::default()
+    });
+
+    let buy_request = make_buy_request(*ACCOUNT_1_ADDR, &test_context, TOKEN_UNIT, START_TIME);
+    builder.exec(buy_request).expect_success().commit();
+
+    let claim_request = make_sale_request(*ACCOUNT_1_ADDR, &test_context, METHOD_CLAIM, END_TIME);
+    builder.exec(claim_request).commit();
+    assert_user_error(&builder, ERROR_SOFT_CAP_NOT_MET);
+
+    let refund_request = make_sale_request(*ACCOUNT_1_ADDR, &test_context, METHOD_REFUND, END_TIME);
+    builder.exec(refund_request).expect_success().commit();
+    assert_eq!(sale_purse_balance(&builder, &test_context), U512::zero());
+
+    // Every funded token goes back to the owner, including the one bought before the refund.
+    let withdraw_request = make_sale_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        METHOD_WITHDRAW,
+        END_TIME,
+    );
+    builder.exec(withdraw_request).expect_success().commit();
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
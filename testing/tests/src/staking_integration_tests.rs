@@ -0,0 +1,289 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utils::{self, assert_user_error, check_balance_of, ACCOUNT_1_ADDR, ACCOUNT_2_ADDR};
+
+const EXAMPLE_ERC20_STAKING: &str = "erc20_staking.wasm";
+const ERC20_STAKING_KEY: &str = "erc20_staking";
+const ERC20_STAKING_PACKAGE_KEY: &str = "erc20_staking_package";
+
+const ARG_STAKING_TOKEN: &str = "staking_token";
+const ARG_REWARD_TOKEN: &str = "reward_token";
+const ARG_REWARDS_DURATION: &str = "rewards_duration";
+const ARG_SPENDER: &str = "spender";
+const ARG_AMOUNT: &str = "amount";
+
+const METHOD_APPROVE: &str = "approve";
+const METHOD_STAKE: &str = "stake";
+const METHOD_WITHDRAW: &str = "withdraw";
+const METHOD_GET_REWARD: &str = "get_reward";
+const METHOD_EXIT: &str = "exit";
+const METHOD_NOTIFY_REWARD_AMOUNT: &str = "notify_reward_amount";
+
+const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 1;
+const ERROR_PERMISSION_DENIED: u16 = u16::MAX - 4;
+
+const STAKING_TOKEN_NAME: &str = "CasperStake";
+const STAKING_TOKEN_SYMBOL: &str = "CSPRS";
+const REWARD_TOKEN_NAME: &str = "CasperReward";
+const REWARD_TOKEN_SYMBOL: &str = "CSPRR";
+
+const REWARD_START: u64 = 1_700_000_000_000;
+const REWARDS_DURATION: u64 = 10_000;
+const REWARD_AMOUNT: u64 = 1_000_000;
+const STAKE_AMOUNT_1: u64 = 100;
+const STAKE_AMOUNT_2: u64 = 300;
+
+#[derive(Copy, Clone)]
+struct TestContext {
+    staking_token: ContractHash,
+    reward_token: ContractHash,
+    erc20_staking: ContractHash,
+    erc20_staking_package: ContractPackageHash,
+}
+
+fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut builder = utils::setup();
+    let staking_token =
+        utils::install_token(&mut builder, STAKING_TOKEN_NAME, STAKING_TOKEN_SYMBOL);
+    let reward_token = utils::install_token(&mut builder, REWARD_TOKEN_NAME, REWARD_TOKEN_SYMBOL);
+    utils::install_contract(
+        &mut builder,
+        EXAMPLE_ERC20_STAKING,
+        runtime_args! {
+            ARG_STAKING_TOKEN => staking_token,
+            ARG_REWARD_TOKEN => reward_token,
+            ARG_REWARDS_DURATION => REWARDS_DURATION,
+        },
+    );
+
+    let test_context = TestContext {
+        staking_token,
+        reward_token,
+        erc20_staking: utils::get_contract_hash(&builder, ERC20_STAKING_KEY),
+        erc20_staking_package: utils::get_contract_package_hash(
+            &builder,
+            ERC20_STAKING_PACKAGE_KEY,
+        ),
+    };
+
+    (builder, test_context)
+}
+
+fn approve_staking(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    sender: AccountHash,
+    token_contract: ContractHash,
+    amount: u64,
+) {
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        token_contract,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => Key::Hash(test_context.erc20_staking_package.value()),
+            ARG_AMOUNT => U256::from(amount),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+}
+
+/// Funds the reward period with `REWARD_AMOUNT`, starting at `REWARD_START`.
+fn notify_reward_amount(builder: &mut InMemoryWasmTestBuilder, test_context: &TestContext) {
+    approve_staking(
+        builder,
+        test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.reward_token,
+        REWARD_AMOUNT,
+    );
+    let notify_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.erc20_staking,
+        METHOD_NOTIFY_REWARD_AMOUNT,
+        runtime_args! {
+            ARG_AMOUNT => U256::from(REWARD_AMOUNT),
+        },
+    )
+    .with_block_time(REWARD_START)
+    .build();
+    builder.exec(notify_request).expect_success().commit();
+}
+
+/// Sends `amount` of staking tokens to `staker`, who then stakes them at `block_time`.
+fn stake(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    staker: AccountHash,
+    amount: u64,
+    block_time: u64,
+) {
+    utils::transfer(
+        builder,
+        test_context.staking_token,
+        Key::Account(staker),
+        U256::from(amount),
+    );
+
+    approve_staking(
+        builder,
+        test_context,
+        staker,
+        test_context.staking_token,
+        amount,
+    );
+    let stake_request = ExecuteRequestBuilder::contract_call_by_hash(
+        staker,
+        test_context.erc20_staking,
+        METHOD_STAKE,
+        runtime_args! {
+            ARG_AMOUNT => U256::from(amount),
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(stake_request).expect_success().commit();
+}
+
+fn make_staker_request(
+    staker: AccountHash,
+    test_context: &TestContext,
+    entry_point: &str,
+    args: RuntimeArgs,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        staker,
+        test_context.erc20_staking,
+        entry_point,
+        args,
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_share_rewards_between_stakers() {
+    let (mut builder, test_context) = setup();
+    notify_reward_amount(&mut builder, &test_context);
+
+    // The first staker has the pool to itself for half of the period, and a quarter of it for
+    // the other half.
+    let half_time = REWARD_START + REWARDS_DURATION / 2;
+    stake(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        STAKE_AMOUNT_1,
+        REWARD_START,
+    );
+    stake(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        STAKE_AMOUNT_2,
+        half_time,
+    );
+
+    // Nothing accrues after the period finishes.
+    let claim_time = REWARD_START + REWARDS_DURATION * 2;
+    for staker in &[*ACCOUNT_1_ADDR, *ACCOUNT_2_ADDR] {
+        let get_reward_request = make_staker_request(
+            *staker,
+            &test_context,
+            METHOD_GET_REWARD,
+            RuntimeArgs::default(),
+            claim_time,
+        );
+        builder.exec(get_reward_request).expect_success().commit();
+    }
+
+    let half_reward = REWARD_AMOUNT / 2;
+    let reward_2 = half_reward * STAKE_AMOUNT_2 / (STAKE_AMOUNT_1 + STAKE_AMOUNT_2);
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.reward_token,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::from(REWARD_AMOUNT - reward_2)
+    );
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.reward_token,
+            Key::Account(*ACCOUNT_2_ADDR)
+        ),
+        U256::from(reward_2)
+    );
+}
+
+#[test]
+fn should_return_stake_and_reward_on_exit() {
+    let (mut builder, test_context) = setup();
+    notify_reward_amount(&mut builder, &test_context);
+    stake(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        STAKE_AMOUNT_1,
+        REWARD_START,
+    );
+
+    let exit_time = REWARD_START + REWARDS_DURATION / 4;
+    let withdraw_request = make_staker_request(
+        *ACCOUNT_1_ADDR,
+        &test_context,
+        METHOD_WITHDRAW,
+        runtime_args! {
+            ARG_AMOUNT => U256::from(STAKE_AMOUNT_1 + 1),
+        },
+        exit_time,
+    );
+    builder.exec(withdraw_request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_BALANCE);
+
+    let exit_request = make_staker_request(
+        *ACCOUNT_1_ADDR,
+        &test_context,
+        METHOD_EXIT,
+        RuntimeArgs::default(),
+        exit_time,
+    );
+    builder.exec(exit_request).expect_success().commit();
+
+    let staker = Key::Account(*ACCOUNT_1_ADDR);
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.staking_token, staker),
+        U256::from(STAKE_AMOUNT_1)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.reward_token, staker),
+        U256::from(REWARD_AMOUNT / 4)
+    );
+}
+
+#[test]
+fn should_only_allow_owner_to_notify_rewards() {
+    let (mut builder, test_context) = setup();
+
+    let notify_request = make_staker_request(
+        *ACCOUNT_1_ADDR,
+        &test_context,
+        METHOD_NOTIFY_REWARD_AMOUNT,
+        runtime_args! {
+            ARG_AMOUNT => U256::from(REWARD_AMOUNT),
+        },
+        REWARD_START,
+    );
+    builder.exec(notify_request).commit();
+    assert_user_error(&builder, ERROR_PERMISSION_DENIED);
+}
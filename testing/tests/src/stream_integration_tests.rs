@@ -0,0 +1,274 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utils::{
+    self, assert_user_error, check_balance_of, ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, TOKEN_NAME,
+    TOKEN_SYMBOL,
+};
+
+const EXAMPLE_ERC20_STREAM: &str = "erc20_stream.wasm";
+const ERC20_STREAM_KEY: &str = "erc20_stream";
+const ERC20_STREAM_PACKAGE_KEY: &str = "erc20_stream_package";
+
+const ARG_SPENDER: &str = "spender";
+const ARG_RECIPIENT: &str = "recipient";
+const ARG_AMOUNT: &str = "amount";
+const ARG_TOKEN: &str = "token";
+const ARG_START_TIME: &str = "start_time";
+const ARG_STOP_TIME: &str = "stop_time";
+const ARG_STREAM_ID: &str = "stream_id";
+
+const METHOD_APPROVE: &str = "approve";
+const METHOD_CREATE_STREAM: &str = "create_stream";
+const METHOD_WITHDRAW_FROM_STREAM: &str = "withdraw_from_stream";
+const METHOD_CANCEL_STREAM: &str = "cancel_stream";
+
+const ERROR_STREAM_CANCELLED: u16 = 3;
+const ERROR_NOT_RECIPIENT: u16 = 4;
+const ERROR_NOT_STREAM_PARTY: u16 = 5;
+const ERROR_NOTHING_TO_WITHDRAW: u16 = 6;
+
+const DEPOSIT: u64 = 1_000;
+const CREATE_TIME: u64 = 1_700_000_000_000;
+const START_TIME: u64 = CREATE_TIME + 1_000;
+/// One token accrues per millisecond.
+const STOP_TIME: u64 = START_TIME + DEPOSIT;
+/// Id of the first stream created on a fresh contract.
+const FIRST_STREAM_ID: u64 = 0;
+
+#[derive(Copy, Clone)]
+struct TestContext {
+    erc20_token: ContractHash,
+    erc20_stream: ContractHash,
+    erc20_stream_package: ContractPackageHash,
+}
+
+fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_token(|builder| utils::install_token(builder, TOKEN_NAME, TOKEN_SYMBOL))
+}
+
+/// Installs the token with `install_token` and the stream contract, and has the sender, which is
+/// account 1, stream `DEPOSIT` tokens to the recipient, which is account 2, as the first stream.
+fn setup_with_token(
+    install_token: fn(&mut InMemoryWasmTestBuilder) -> ContractHash,
+) -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut builder = utils::setup();
+    let erc20_token = install_token(&mut builder);
+
+    utils::install_contract(&mut builder, EXAMPLE_ERC20_STREAM, RuntimeArgs::default());
+
+    let test_context = TestContext {
+        erc20_token,
+        erc20_stream: utils::get_contract_hash(&builder, ERC20_STREAM_KEY),
+        erc20_stream_package: utils::get_contract_package_hash(&builder, ERC20_STREAM_PACKAGE_KEY),
+    };
+
+    utils::transfer(
+        &mut builder,
+        erc20_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(DEPOSIT),
+    );
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        erc20_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => Key::Hash(test_context.erc20_stream_package.value()),
+            ARG_AMOUNT => U256::from(DEPOSIT),
+        },
+    )
+    .build();
+    let create_stream_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.erc20_stream,
+        METHOD_CREATE_STREAM,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_TOKEN => erc20_token,
+            ARG_AMOUNT => U256::from(DEPOSIT),
+            ARG_START_TIME => START_TIME,
+            ARG_STOP_TIME => STOP_TIME,
+        },
+    )
+    .with_block_time(CREATE_TIME)
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+    builder
+        .exec(create_stream_request)
+        .expect_success()
+        .commit();
+
+    (builder, test_context)
+}
+
+fn make_stream_request(
+    sender: AccountHash,
+    test_context: &TestContext,
+    entry_point: &str,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        test_context.erc20_stream,
+        entry_point,
+        runtime_args! {
+            ARG_STREAM_ID => FIRST_STREAM_ID,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_withdraw_accrued_tokens_from_stream() {
+    let (mut builder, test_context) = setup();
+    let stream = Key::Hash(test_context.erc20_stream_package.value());
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, stream),
+        U256::from(DEPOSIT)
+    );
+
+    let withdraw_request = make_stream_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        METHOD_WITHDRAW_FROM_STREAM,
+        START_TIME,
+    );
+    builder.exec(withdraw_request).commit();
+    assert_user_error(&builder, ERROR_NOTHING_TO_WITHDRAW);
+
+    let withdraw_request = make_stream_request(
+        *ACCOUNT_1_ADDR,
+        &test_context,
+        METHOD_WITHDRAW_FROM_STREAM,
+        START_TIME + 250,
+    );
+    builder.exec(withdraw_request).commit();
+    assert_user_error(&builder, ERROR_NOT_RECIPIENT);
+
+    let withdraw_request = make_stream_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        METHOD_WITHDRAW_FROM_STREAM,
+        START_TIME + 250,
+    );
+    builder.exec(withdraw_request).expect_success().commit();
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, recipient),
+        U256::from(250)
+    );
+
+    // Whatever accrued past the stop time is capped at the deposit.
+    let withdraw_request = make_stream_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        METHOD_WITHDRAW_FROM_STREAM,
+        STOP_TIME + 1_000,
+    );
+    builder.exec(withdraw_request).expect_success().commit();
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, recipient),
+        U256::from(DEPOSIT)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, stream),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_settle_cancelled_stream_pro_rata() {
+    let (mut builder, test_context) = setup();
+
+    let withdraw_request = make_stream_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        METHOD_WITHDRAW_FROM_STREAM,
+        START_TIME + 100,
+    );
+    builder.exec(withdraw_request).expect_success().commit();
+
+    let cancel_request = make_stream_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        METHOD_CANCEL_STREAM,
+        START_TIME + 400,
+    );
+    builder.exec(cancel_request).commit();
+    assert_user_error(&builder, ERROR_NOT_STREAM_PARTY);
+
+    let cancel_request = make_stream_request(
+        *ACCOUNT_1_ADDR,
+        &test_context,
+        METHOD_CANCEL_STREAM,
+        START_TIME + 400,
+    );
+    builder.exec(cancel_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_2_ADDR)
+        ),
+        U256::from(400)
+    );
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::from(DEPOSIT - 400)
+    );
+
+    let withdraw_request = make_stream_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        METHOD_WITHDRAW_FROM_STREAM,
+        STOP_TIME,
+    );
+    builder.exec(withdraw_request).commit();
+    assert_user_error(&builder, ERROR_STREAM_CANCELLED);
+}
+
+#[test]
+fn should_only_stream_tokens_received_by_contract() {
+    let (mut builder, test_context) = setup_with_token(utils::install_fee_token);
+
+    // Depositing is charged a fee, so the stream releases less than the sender paid in.
+    let stream = Key::Hash(test_context.erc20_stream_package.value());
+    let deposited = DEPOSIT - utils::transfer_fee(DEPOSIT);
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, stream),
+        U256::from(deposited)
+    );
+
+    let withdraw_request = make_stream_request(
+        *ACCOUNT_2_ADDR,
+        &test_context,
+        METHOD_WITHDRAW_FROM_STREAM,
+        STOP_TIME,
+    );
+    builder.exec(withdraw_request).expect_success().commit();
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, stream),
+        U256::zero()
+    );
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_2_ADDR)
+        ),
+        U256::from(deposited - utils::transfer_fee(deposited))
+    );
+}
@@ -0,0 +1,203 @@
+use casper_engine_test_support::internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utils::{
+    self, assert_user_error, check_balance_of, ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, TOKEN_NAME,
+    TOKEN_SYMBOL,
+};
+
+const EXAMPLE_ERC20_SUBSCRIPTIONS: &str = "erc20_subscriptions.wasm";
+const ERC20_SUBSCRIPTIONS_KEY: &str = "erc20_subscriptions";
+const ERC20_SUBSCRIPTIONS_PACKAGE_KEY: &str = "erc20_subscriptions_package";
+
+const ARG_TOKEN_CONTRACT: &str = "token_contract";
+const ARG_SPENDER: &str = "spender";
+const ARG_AMOUNT: &str = "amount";
+const ARG_MERCHANT: &str = "merchant";
+const ARG_SUBSCRIBER: &str = "subscriber";
+const ARG_PERIOD: &str = "period";
+
+const METHOD_APPROVE: &str = "approve";
+const METHOD_SUBSCRIBE: &str = "subscribe";
+const METHOD_CANCEL: &str = "cancel";
+const METHOD_COLLECT: &str = "collect";
+
+const ERROR_ALREADY_SUBSCRIBED: u16 = 2;
+const ERROR_SUBSCRIPTION_NOT_FOUND: u16 = 3;
+const ERROR_PERIOD_NOT_ELAPSED: u16 = 4;
+
+const SUBSCRIBER_BALANCE: u64 = 10_000;
+const SUBSCRIPTION_AMOUNT: u64 = 1_000;
+/// Thirty days, in milliseconds.
+const PERIOD: u64 = 30 * 24 * 60 * 60 * 1000;
+const SUBSCRIBE_TIME: u64 = 1_700_000_000_000;
+
+#[derive(Copy, Clone)]
+struct TestContext {
+    erc20_token: ContractHash,
+    erc20_subscriptions: ContractHash,
+}
+
+/// Installs the token and the subscriptions contract, and has the subscriber, which is account 1,
+/// hold `SUBSCRIBER_BALANCE` tokens approved to the subscriptions contract. Account 2 is the
+/// merchant.
+fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut builder = utils::setup();
+    let erc20_token = utils::install_token(&mut builder, TOKEN_NAME, TOKEN_SYMBOL);
+
+    utils::install_contract(
+        &mut builder,
+        EXAMPLE_ERC20_SUBSCRIPTIONS,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => erc20_token,
+        },
+    );
+
+    let erc20_subscriptions_package =
+        utils::get_contract_package_hash(&builder, ERC20_SUBSCRIPTIONS_PACKAGE_KEY);
+    let test_context = TestContext {
+        erc20_token,
+        erc20_subscriptions: utils::get_contract_hash(&builder, ERC20_SUBSCRIPTIONS_KEY),
+    };
+
+    utils::transfer(
+        &mut builder,
+        erc20_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(SUBSCRIBER_BALANCE),
+    );
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        erc20_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => Key::Hash(erc20_subscriptions_package.value()),
+            ARG_AMOUNT => U256::from(SUBSCRIBER_BALANCE),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    (builder, test_context)
+}
+
+fn make_subscribe_request(test_context: &TestContext, block_time: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.erc20_subscriptions,
+        METHOD_SUBSCRIBE,
+        runtime_args! {
+            ARG_MERCHANT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::from(SUBSCRIPTION_AMOUNT),
+            ARG_PERIOD => PERIOD,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+fn make_cancel_request(test_context: &TestContext, block_time: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.erc20_subscriptions,
+        METHOD_CANCEL,
+        runtime_args! {
+            ARG_MERCHANT => Key::Account(*ACCOUNT_2_ADDR),
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+fn make_collect_request(test_context: &TestContext, block_time: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        test_context.erc20_subscriptions,
+        METHOD_COLLECT,
+        runtime_args! {
+            ARG_SUBSCRIBER => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_collect_once_per_elapsed_period() {
+    let (mut builder, test_context) = setup();
+    let subscribe_request = make_subscribe_request(&test_context, SUBSCRIBE_TIME);
+    builder.exec(subscribe_request).expect_success().commit();
+
+    let collect_request = make_collect_request(&test_context, SUBSCRIBE_TIME);
+    builder.exec(collect_request).expect_success().commit();
+
+    let collect_request = make_collect_request(&test_context, SUBSCRIBE_TIME + PERIOD - 1);
+    builder.exec(collect_request).commit();
+    assert_user_error(&builder, ERROR_PERIOD_NOT_ELAPSED);
+
+    // Collecting late still bills each elapsed period exactly once.
+    let late_time = SUBSCRIBE_TIME + 2 * PERIOD + 1;
+    for _ in 0..2 {
+        let collect_request = make_collect_request(&test_context, late_time);
+        builder.exec(collect_request).expect_success().commit();
+    }
+    let collect_request = make_collect_request(&test_context, late_time);
+    builder.exec(collect_request).commit();
+    assert_user_error(&builder, ERROR_PERIOD_NOT_ELAPSED);
+
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_2_ADDR)
+        ),
+        U256::from(3 * SUBSCRIPTION_AMOUNT)
+    );
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::from(SUBSCRIBER_BALANCE - 3 * SUBSCRIPTION_AMOUNT)
+    );
+}
+
+#[test]
+fn should_stop_collection_once_cancelled() {
+    let (mut builder, test_context) = setup();
+    let subscribe_request = make_subscribe_request(&test_context, SUBSCRIBE_TIME);
+    builder.exec(subscribe_request).expect_success().commit();
+
+    let subscribe_request = make_subscribe_request(&test_context, SUBSCRIBE_TIME);
+    builder.exec(subscribe_request).commit();
+    assert_user_error(&builder, ERROR_ALREADY_SUBSCRIBED);
+
+    let cancel_request = make_cancel_request(&test_context, SUBSCRIBE_TIME + 1);
+    builder.exec(cancel_request).expect_success().commit();
+
+    let collect_request = make_collect_request(&test_context, SUBSCRIBE_TIME + 2);
+    builder.exec(collect_request).commit();
+    assert_user_error(&builder, ERROR_SUBSCRIPTION_NOT_FOUND);
+
+    let cancel_request = make_cancel_request(&test_context, SUBSCRIBE_TIME + 3);
+    builder.exec(cancel_request).commit();
+    assert_user_error(&builder, ERROR_SUBSCRIPTION_NOT_FOUND);
+
+    // Subscribing again starts a new schedule from the new subscription time.
+    let resubscribe_time = SUBSCRIBE_TIME + PERIOD / 2;
+    let subscribe_request = make_subscribe_request(&test_context, resubscribe_time);
+    builder.exec(subscribe_request).expect_success().commit();
+    let collect_request = make_collect_request(&test_context, resubscribe_time);
+    builder.exec(collect_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_2_ADDR)
+        ),
+        U256::from(SUBSCRIPTION_AMOUNT)
+    );
+}
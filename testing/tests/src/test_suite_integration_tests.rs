@@ -0,0 +1,71 @@
+use casper_erc20_test_suite::Erc20TestSuite;
+use casper_types::{runtime_args, RuntimeArgs, U256};
+
+const EXAMPLE_ERC20_TOKEN: &str = "erc20_token.wasm";
+
+const ARG_NAME: &str = "name";
+const ARG_SYMBOL: &str = "symbol";
+const ARG_DECIMALS: &str = "decimals";
+const ARG_TOTAL_SUPPLY: &str = "total_supply";
+
+const TOKEN_NAME: &str = "CasperTest";
+const TOKEN_SYMBOL: &str = "CSPRT";
+const TOKEN_DECIMALS: u8 = 8;
+const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+
+fn suite() -> Erc20TestSuite {
+    Erc20TestSuite::new(
+        EXAMPLE_ERC20_TOKEN,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        },
+    )
+}
+
+#[test]
+fn should_install_with_installer_balance() {
+    suite().check_installer_balance();
+}
+
+#[test]
+fn should_install_with_named_keys() {
+    suite().check_installer_named_keys();
+}
+
+#[test]
+fn should_transfer() {
+    suite().check_transfers();
+}
+
+#[test]
+fn should_transfer_full_amount() {
+    suite().check_full_balance_transfers();
+}
+
+#[test]
+fn should_transfer_to_self() {
+    suite().check_self_transfers();
+}
+
+#[test]
+fn should_transfer_zero_amount() {
+    suite().check_zero_amount_transfers();
+}
+
+#[test]
+fn should_not_transfer_with_insufficient_balance() {
+    suite().check_insufficient_balance();
+}
+
+#[test]
+fn should_transfer_from() {
+    suite().check_allowances();
+}
+
+#[test]
+fn should_not_transfer_from_more_than_approved() {
+    suite().check_insufficient_allowance();
+}
@@ -0,0 +1,194 @@
+//! Setup and queries shared by the integration tests of the example contracts.
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_erc20_test_suite::fixture;
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{
+    runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+
+pub(crate) use casper_erc20_test_suite::fixture::{ACCOUNT_1_ADDR, ACCOUNT_2_ADDR};
+
+const EXAMPLE_ERC20_TOKEN: &str = "erc20_token.wasm";
+const CONTRACT_ERC20_TEST: &str = "erc20_test.wasm";
+const CONTRACT_ERC20_TEST_CALL: &str = "erc20_test_call.wasm";
+const ERC20_TOKEN_CONTRACT_KEY: &str = "erc20_token_contract";
+const ERC20_TEST_CONTRACT_KEY: &str = "test_contract";
+const ERC20_TEST_CALL_KEY: &str = "erc20_test_call";
+const RESULT_KEY: &str = "result";
+
+const ARG_NAME: &str = "name";
+const ARG_SYMBOL: &str = "symbol";
+const ARG_DECIMALS: &str = "decimals";
+const ARG_TOTAL_SUPPLY: &str = "total_supply";
+const ARG_TOKEN_CONTRACT: &str = "token_contract";
+const ARG_ADDRESS: &str = "address";
+const ARG_RECIPIENT: &str = "recipient";
+const ARG_AMOUNT: &str = "amount";
+const ARG_TRANSFER_FEE_BASIS_POINTS: &str = "transfer_fee_basis_points";
+const ARG_MAX_FEE: &str = "max_fee";
+const ARG_FEE_RECIPIENT: &str = "fee_recipient";
+const ARG_FEE_EXEMPT: &str = "fee_exempt";
+
+const METHOD_TRANSFER: &str = "transfer";
+const CHECK_BALANCE_OF_ENTRYPOINT: &str = "check_balance_of";
+
+pub(crate) const TOKEN_NAME: &str = "CasperTest";
+pub(crate) const TOKEN_SYMBOL: &str = "CSPRT";
+pub(crate) const TOKEN_DECIMALS: u8 = 8;
+pub(crate) const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+pub(crate) const TRANSFER_FEE_BASIS_POINTS: u32 = 250;
+
+/// Returns a builder past genesis with `ACCOUNT_1_ADDR` and `ACCOUNT_2_ADDR` funded, and the test
+/// call contract installed.
+pub(crate) fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = fixture::new_builder();
+    install_contract(
+        &mut builder,
+        CONTRACT_ERC20_TEST_CALL,
+        RuntimeArgs::default(),
+    );
+    builder
+}
+
+/// Installs `wasm` as the default account.
+pub(crate) fn install_contract(
+    builder: &mut InMemoryWasmTestBuilder,
+    wasm: &str,
+    args: RuntimeArgs,
+) {
+    let install_request =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, wasm, args).build();
+    builder.exec(install_request).expect_success().commit();
+}
+
+/// Installs the example token, with the whole supply held by the default account.
+pub(crate) fn install_token(
+    builder: &mut InMemoryWasmTestBuilder,
+    name: &str,
+    symbol: &str,
+) -> ContractHash {
+    install_contract(
+        builder,
+        EXAMPLE_ERC20_TOKEN,
+        runtime_args! {
+            ARG_NAME => name,
+            ARG_SYMBOL => symbol,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        },
+    );
+    // Every install overwrites the named key, so it is read right away.
+    get_contract_hash(builder, ERC20_TOKEN_CONTRACT_KEY)
+}
+
+/// Installs the test token charging a `TRANSFER_FEE_BASIS_POINTS` fee on transfers, with
+/// `TOKEN_TOTAL_SUPPLY` held by the default account. The default account collects the fees, and
+/// transfers from or to it are exempt.
+pub(crate) fn install_fee_token(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
+    install_contract(
+        builder,
+        CONTRACT_ERC20_TEST,
+        runtime_args! {
+            ARG_TRANSFER_FEE_BASIS_POINTS => TRANSFER_FEE_BASIS_POINTS,
+            ARG_MAX_FEE => U256::from(TOKEN_TOTAL_SUPPLY),
+            ARG_FEE_RECIPIENT => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_FEE_EXEMPT => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+        },
+    );
+    get_contract_hash(builder, ERC20_TEST_CONTRACT_KEY)
+}
+
+/// Returns the fee the token installed by `install_fee_token` charges on a transfer of `amount`.
+pub(crate) fn transfer_fee(amount: u64) -> u64 {
+    amount * u64::from(TRANSFER_FEE_BASIS_POINTS) / 10_000
+}
+
+fn get_named_key(builder: &InMemoryWasmTestBuilder, name: &str) -> [u8; 32] {
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(name)
+        .and_then(|key| key.into_hash())
+        .unwrap_or_else(|| panic!("should have {} named key", name))
+}
+
+/// Returns the contract hash stored under the default account's `name` named key.
+pub(crate) fn get_contract_hash(builder: &InMemoryWasmTestBuilder, name: &str) -> ContractHash {
+    ContractHash::new(get_named_key(builder, name))
+}
+
+/// Returns the contract package hash stored under the default account's `name` named key.
+pub(crate) fn get_contract_package_hash(
+    builder: &InMemoryWasmTestBuilder,
+    name: &str,
+) -> ContractPackageHash {
+    ContractPackageHash::new(get_named_key(builder, name))
+}
+
+/// Transfers `amount` of `token` from the default account to `recipient`.
+pub(crate) fn transfer(
+    builder: &mut InMemoryWasmTestBuilder,
+    token: ContractHash,
+    recipient: Key,
+    amount: U256,
+) {
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+}
+
+/// Queries the balance of `address` in `token` through the test call contract.
+pub(crate) fn check_balance_of(
+    builder: &mut InMemoryWasmTestBuilder,
+    token: ContractHash,
+    address: Key,
+) -> U256 {
+    let erc20_test_call = get_contract_package_hash(builder, ERC20_TEST_CALL_KEY);
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_call,
+        None,
+        CHECK_BALANCE_OF_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => token,
+            ARG_ADDRESS => address,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let contract_package = builder
+        .get_contract_package(erc20_test_call)
+        .expect("should have contract package");
+    let (_version, contract_hash) = contract_package
+        .enabled_versions()
+        .iter()
+        .rev()
+        .next()
+        .expect("should have latest version");
+    builder.get_value(*contract_hash, RESULT_KEY)
+}
+
+/// Asserts that the last request reverted with the user error `expected_error`.
+pub(crate) fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected_error: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+        "{:?}",
+        error
+    );
+}
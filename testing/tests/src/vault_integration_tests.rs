@@ -0,0 +1,276 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utils::{
+    self, assert_user_error, check_balance_of, ACCOUNT_1_ADDR, TOKEN_NAME, TOKEN_SYMBOL,
+};
+
+const EXAMPLE_ERC20_VAULT: &str = "erc20_vault.wasm";
+const ERC20_VAULT_KEY: &str = "erc20_vault";
+
+const ARG_NAME: &str = "name";
+const ARG_SYMBOL: &str = "symbol";
+const ARG_ASSET_CONTRACT: &str = "asset_contract";
+const ARG_SPENDER: &str = "spender";
+const ARG_AMOUNT: &str = "amount";
+const ARG_ASSETS: &str = "assets";
+const ARG_SHARES: &str = "shares";
+const ARG_RECEIVER: &str = "receiver";
+const ARG_OWNER: &str = "owner";
+
+const METHOD_APPROVE: &str = "approve";
+const METHOD_DEPOSIT: &str = "deposit";
+const METHOD_MINT: &str = "mint";
+const METHOD_WITHDRAW: &str = "withdraw";
+const METHOD_REDEEM: &str = "redeem";
+
+const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
+
+const VAULT_NAME: &str = "CasperTest Vault";
+const VAULT_SYMBOL: &str = "VCSPRT";
+
+const DEPOSIT_AMOUNT: u64 = 1_000_000;
+const YIELD_AMOUNT: u64 = 500_000;
+const WITHDRAW_AMOUNT: u64 = 1_000;
+
+#[derive(Copy, Clone)]
+struct TestContext {
+    erc20_token: ContractHash,
+    erc20_vault: ContractHash,
+    erc20_vault_package: ContractPackageHash,
+}
+
+fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut builder = utils::setup();
+    let erc20_token = utils::install_token(&mut builder, TOKEN_NAME, TOKEN_SYMBOL);
+    utils::install_contract(
+        &mut builder,
+        EXAMPLE_ERC20_VAULT,
+        runtime_args! {
+            ARG_ASSET_CONTRACT => erc20_token,
+            ARG_NAME => VAULT_NAME,
+            ARG_SYMBOL => VAULT_SYMBOL,
+        },
+    );
+
+    let erc20_vault = utils::get_contract_hash(&builder, ERC20_VAULT_KEY);
+    let erc20_vault_package = builder
+        .get_contract(erc20_vault)
+        .expect("should have vault contract")
+        .contract_package_hash();
+    let test_context = TestContext {
+        erc20_token,
+        erc20_vault,
+        erc20_vault_package,
+    };
+
+    (builder, test_context)
+}
+
+/// Approves the vault for `amount` of the default account's assets and deposits them for
+/// `receiver`.
+fn deposit(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    amount: u64,
+    receiver: Key,
+) {
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.erc20_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => Key::Hash(test_context.erc20_vault_package.value()),
+            ARG_AMOUNT => U256::from(amount),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let deposit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.erc20_vault,
+        METHOD_DEPOSIT,
+        runtime_args! {
+            ARG_ASSETS => U256::from(amount),
+            ARG_RECEIVER => receiver,
+        },
+    )
+    .build();
+    builder.exec(deposit_request).expect_success().commit();
+}
+
+/// Sends assets straight to the vault, raising the value of every share.
+fn add_yield(builder: &mut InMemoryWasmTestBuilder, test_context: &TestContext, amount: u64) {
+    utils::transfer(
+        builder,
+        test_context.erc20_token,
+        Key::Hash(test_context.erc20_vault_package.value()),
+        U256::from(amount),
+    );
+}
+
+fn make_withdraw_request(
+    sender: AccountHash,
+    test_context: &TestContext,
+    assets: u64,
+    owner: Key,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        test_context.erc20_vault,
+        METHOD_WITHDRAW,
+        runtime_args! {
+            ARG_ASSETS => U256::from(assets),
+            ARG_RECEIVER => Key::Account(sender),
+            ARG_OWNER => owner,
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_deposit_and_redeem_with_yield() {
+    let (mut builder, test_context) = setup();
+
+    let depositor = Key::Account(*ACCOUNT_1_ADDR);
+    let vault_address = Key::Hash(test_context.erc20_vault_package.value());
+    deposit(&mut builder, &test_context, DEPOSIT_AMOUNT, depositor);
+
+    // An empty vault issues one share per asset.
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_vault, depositor),
+        U256::from(DEPOSIT_AMOUNT)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, vault_address),
+        U256::from(DEPOSIT_AMOUNT)
+    );
+
+    add_yield(&mut builder, &test_context, YIELD_AMOUNT);
+
+    let redeem_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.erc20_vault,
+        METHOD_REDEEM,
+        runtime_args! {
+            ARG_SHARES => U256::from(DEPOSIT_AMOUNT),
+            ARG_RECEIVER => depositor,
+            ARG_OWNER => depositor,
+        },
+    )
+    .build();
+    builder.exec(redeem_request).expect_success().commit();
+
+    // The virtual share and asset take their cut, and the rest is rounded down.
+    let redeemed = DEPOSIT_AMOUNT * (DEPOSIT_AMOUNT + YIELD_AMOUNT + 1) / (DEPOSIT_AMOUNT + 1);
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, depositor),
+        U256::from(redeemed)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, vault_address),
+        U256::from(DEPOSIT_AMOUNT + YIELD_AMOUNT - redeemed)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_vault, depositor),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_round_assets_up_on_mint() {
+    let (mut builder, test_context) = setup();
+
+    let minter = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let vault_address = Key::Hash(test_context.erc20_vault_package.value());
+    deposit(
+        &mut builder,
+        &test_context,
+        DEPOSIT_AMOUNT,
+        Key::Account(*ACCOUNT_1_ADDR),
+    );
+    add_yield(&mut builder, &test_context, YIELD_AMOUNT);
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.erc20_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => vault_address,
+            ARG_AMOUNT => U256::from(DEPOSIT_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.erc20_vault,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_SHARES => U256::one(),
+            ARG_RECEIVER => minter,
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    // A share is worth one and a half assets, so minting it costs two.
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_vault, minter),
+        U256::one()
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, vault_address),
+        U256::from(DEPOSIT_AMOUNT + YIELD_AMOUNT + 2)
+    );
+}
+
+#[test]
+fn should_withdraw_on_behalf_of_owner_with_allowance() {
+    let (mut builder, test_context) = setup();
+
+    let owner = Key::Account(*ACCOUNT_1_ADDR);
+    let operator = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    deposit(&mut builder, &test_context, DEPOSIT_AMOUNT, owner);
+
+    let withdraw_request =
+        make_withdraw_request(*DEFAULT_ACCOUNT_ADDR, &test_context, WITHDRAW_AMOUNT, owner);
+    builder.exec(withdraw_request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_ALLOWANCE);
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.erc20_vault,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => operator,
+            ARG_AMOUNT => U256::from(WITHDRAW_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let operator_balance_before =
+        check_balance_of(&mut builder, test_context.erc20_token, operator);
+
+    let withdraw_request =
+        make_withdraw_request(*DEFAULT_ACCOUNT_ADDR, &test_context, WITHDRAW_AMOUNT, owner);
+    builder.exec(withdraw_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, operator),
+        operator_balance_before + U256::from(WITHDRAW_AMOUNT)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_vault, owner),
+        U256::from(DEPOSIT_AMOUNT - WITHDRAW_AMOUNT)
+    );
+}
@@ -0,0 +1,258 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utils::{
+    self, assert_user_error, check_balance_of, ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, TOKEN_NAME,
+    TOKEN_SYMBOL,
+};
+
+const EXAMPLE_ERC20_VESTING: &str = "erc20_vesting.wasm";
+const ERC20_VESTING_KEY: &str = "erc20_vesting";
+const ERC20_VESTING_PACKAGE_KEY: &str = "erc20_vesting_package";
+
+const ARG_TOKEN_CONTRACT: &str = "token_contract";
+const ARG_SPENDER: &str = "spender";
+const ARG_AMOUNT: &str = "amount";
+const ARG_FUNDER: &str = "funder";
+const ARG_BENEFICIARY: &str = "beneficiary";
+const ARG_TOTAL: &str = "total";
+const ARG_START: &str = "start";
+const ARG_CLIFF: &str = "cliff";
+const ARG_DURATION: &str = "duration";
+const ARG_REVOCABLE: &str = "revocable";
+
+const METHOD_APPROVE: &str = "approve";
+const METHOD_CREATE_SCHEDULE: &str = "create_schedule";
+const METHOD_RELEASE: &str = "release";
+const METHOD_REVOKE: &str = "revoke";
+
+const ERROR_NOTHING_TO_RELEASE: u16 = 4;
+const ERROR_NOT_REVOCABLE: u16 = 5;
+
+const VESTING_TOTAL: u64 = 1_000_000;
+const VESTING_START: u64 = 1_000_000;
+const VESTING_CLIFF: u64 = 1_000;
+const VESTING_DURATION: u64 = 10_000;
+
+#[derive(Copy, Clone)]
+struct TestContext {
+    erc20_token: ContractHash,
+    erc20_vesting: ContractHash,
+    erc20_vesting_package: ContractPackageHash,
+}
+
+fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut builder = utils::setup();
+    let erc20_token = utils::install_token(&mut builder, TOKEN_NAME, TOKEN_SYMBOL);
+    utils::install_contract(
+        &mut builder,
+        EXAMPLE_ERC20_VESTING,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => erc20_token,
+        },
+    );
+
+    let test_context = TestContext {
+        erc20_token,
+        erc20_vesting: utils::get_contract_hash(&builder, ERC20_VESTING_KEY),
+        erc20_vesting_package: utils::get_contract_package_hash(
+            &builder,
+            ERC20_VESTING_PACKAGE_KEY,
+        ),
+    };
+
+    (builder, test_context)
+}
+
+/// Approves the vesting contract and creates a schedule for `ACCOUNT_1` funded by the default
+/// account.
+fn create_schedule(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    revocable: bool,
+) {
+    create_schedule_as(builder, test_context, *DEFAULT_ACCOUNT_ADDR, revocable)
+}
+
+fn create_schedule_as(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    funder: AccountHash,
+    revocable: bool,
+) {
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        funder,
+        test_context.erc20_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => Key::Hash(test_context.erc20_vesting_package.value()),
+            ARG_AMOUNT => U256::from(VESTING_TOTAL),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let create_schedule_request = ExecuteRequestBuilder::contract_call_by_hash(
+        funder,
+        test_context.erc20_vesting,
+        METHOD_CREATE_SCHEDULE,
+        runtime_args! {
+            ARG_BENEFICIARY => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_TOTAL => U256::from(VESTING_TOTAL),
+            ARG_START => VESTING_START,
+            ARG_CLIFF => VESTING_CLIFF,
+            ARG_DURATION => VESTING_DURATION,
+            ARG_REVOCABLE => revocable,
+        },
+    )
+    .build();
+    builder
+        .exec(create_schedule_request)
+        .expect_success()
+        .commit();
+}
+
+fn make_release_request(test_context: &TestContext, block_time: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.erc20_vesting,
+        METHOD_RELEASE,
+        runtime_args! {
+            ARG_FUNDER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+fn make_revoke_request(test_context: &TestContext, block_time: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.erc20_vesting,
+        METHOD_REVOKE,
+        runtime_args! {
+            ARG_BENEFICIARY => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_release_vested_tokens_linearly() {
+    let (mut builder, test_context) = setup();
+    create_schedule(&mut builder, &test_context, false);
+
+    let vesting_address = Key::Hash(test_context.erc20_vesting_package.value());
+    let beneficiary = Key::Account(*ACCOUNT_1_ADDR);
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, vesting_address),
+        U256::from(VESTING_TOTAL)
+    );
+
+    let release_request = make_release_request(&test_context, VESTING_START + VESTING_CLIFF / 2);
+    builder.exec(release_request).commit();
+    assert_user_error(&builder, ERROR_NOTHING_TO_RELEASE);
+
+    let release_request = make_release_request(&test_context, VESTING_START + VESTING_DURATION / 4);
+    builder.exec(release_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, beneficiary),
+        U256::from(VESTING_TOTAL / 4)
+    );
+
+    let release_request = make_release_request(&test_context, VESTING_START + VESTING_DURATION);
+    builder.exec(release_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, beneficiary),
+        U256::from(VESTING_TOTAL)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, vesting_address),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_return_unvested_tokens_on_revoke() {
+    let (mut builder, test_context) = setup();
+    create_schedule(&mut builder, &test_context, true);
+
+    let funder = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let beneficiary = Key::Account(*ACCOUNT_1_ADDR);
+    let funder_balance_before = check_balance_of(&mut builder, test_context.erc20_token, funder);
+
+    let revoke_request = make_revoke_request(&test_context, VESTING_START + VESTING_DURATION / 4);
+    builder.exec(revoke_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, funder),
+        funder_balance_before + U256::from(VESTING_TOTAL / 4 * 3)
+    );
+
+    // Tokens vested before revocation can still be released.
+    let release_request = make_release_request(&test_context, VESTING_START + VESTING_DURATION);
+    builder.exec(release_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, beneficiary),
+        U256::from(VESTING_TOTAL / 4)
+    );
+}
+
+#[test]
+fn should_not_revoke_irrevocable_schedule() {
+    let (mut builder, test_context) = setup();
+    create_schedule(&mut builder, &test_context, false);
+
+    let revoke_request = make_revoke_request(&test_context, VESTING_START);
+    builder.exec(revoke_request).commit();
+    assert_user_error(&builder, ERROR_NOT_REVOCABLE);
+}
+
+#[test]
+fn should_not_block_schedule_by_front_running_funder() {
+    let (mut builder, test_context) = setup();
+
+    utils::transfer(
+        &mut builder,
+        test_context.erc20_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(VESTING_TOTAL),
+    );
+
+    // Another funder creating a schedule for the same beneficiary first doesn't prevent the
+    // default account from creating its own.
+    create_schedule_as(&mut builder, &test_context, *ACCOUNT_2_ADDR, false);
+    create_schedule(&mut builder, &test_context, false);
+
+    let vesting_address = Key::Hash(test_context.erc20_vesting_package.value());
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, vesting_address),
+        U256::from(VESTING_TOTAL * 2)
+    );
+
+    let release_request = make_release_request(&test_context, VESTING_START + VESTING_DURATION);
+    builder.exec(release_request).expect_success().commit();
+
+    assert_eq!(
+        check_balance_of(
+            &mut builder,
+            test_context.erc20_token,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::from(VESTING_TOTAL)
+    );
+    assert_eq!(
+        check_balance_of(&mut builder, test_context.erc20_token, vesting_address),
+        U256::from(VESTING_TOTAL)
+    );
+}